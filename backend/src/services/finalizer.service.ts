@@ -19,6 +19,9 @@ import {
   deriveGlobalStatePda,
   deriveHouseVaultPda,
   deriveSingularityVaultPda,
+  derivePlayerStatsPda,
+  loyaltyEscrowIfOpen,
+  protocolDestinationMetas,
   SKR_MINT,
 } from './solana.service';
 import { getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from '@solana/spl-token';
@@ -230,11 +233,21 @@ async function finalizeSingleBounty(pending: PendingFinalization): Promise<strin
   const [singularityVaultPda] = deriveSingularityVaultPda();
   const playerPubkey = new PublicKey(pending.playerWallet);
 
-  const playerTokenAccount = await getAssociatedTokenAddress(SKR_MINT, playerPubkey);
+  const playerAta = await getAssociatedTokenAddress(SKR_MINT, playerPubkey);
+  // Optional on-chain: a loss still finalizes if the player closed their ATA
+  // (a win will then fail with PlayerTokenAccountRequired and be retried).
+  const ataInfo = await withTimeout(
+    getConnection().getAccountInfo(playerAta),
+    RPC_TIMEOUT_MS,
+    'getAccountInfo(playerAta)',
+  );
+  const playerTokenAccount = ataInfo ? playerAta : null;
 
   // Get protocol treasury from global state
   const globalState = await (program.account as any).globalState.fetch(globalStatePda);
   const protocolTreasury = globalState.protocolTreasury as PublicKey;
+  // Only needed when a jackpot can route a slice to charity
+  const charityVault = globalState.charityJackpotBps > 0 ? (globalState.charityVault as PublicKey) : null;
 
   const signature = await withTimeout(
     program.methods
@@ -243,12 +256,17 @@ async function finalizeSingleBounty(pending: PendingFinalization): Promise<strin
         caller: caller.publicKey,
         globalState: globalStatePda,
         bounty: new PublicKey(pending.bountyPda),
+        playerStats: derivePlayerStatsPda(playerPubkey)[0],
+        loyaltyEscrow: await loyaltyEscrowIfOpen(playerPubkey),
         playerTokenAccount,
         houseVault: houseVaultPda,
         singularityVault: singularityVaultPda,
         protocolTreasury,
+        charityVault,
+        skrMint: SKR_MINT,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(protocolDestinationMetas(globalState))
       .rpc(),
     RPC_TIMEOUT_MS,
     'finalize_bounty',
@@ -2,9 +2,10 @@ import {
   Connection,
   Keypair,
   PublicKey,
+  SystemProgram,
 } from '@solana/web3.js';
 import { AnchorProvider, Program, Wallet } from '@coral-xyz/anchor';
-import { getAssociatedTokenAddress } from '@solana/spl-token';
+import { getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { config } from '../config';
 import { Tier, ENTRY_AMOUNTS, SKR_MULTIPLIER } from '../types';
 import bs58 from 'bs58';
@@ -123,6 +124,88 @@ export function deriveBountyPda(
   );
 }
 
+/**
+ * Derive a player's PlayerStats PDA (lifetime totals)
+ */
+export function derivePlayerStatsPda(playerWallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('player_stats'), playerWallet.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derive the player's LoyaltyEscrow PDA
+ */
+export function deriveLoyaltyEscrowPda(playerWallet: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('loyalty_escrow'), playerWallet.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * The player's LoyaltyEscrow if it exists, else null. Settlement takes it
+ * as optional: without it a win is paid in full with no rebate withheld.
+ */
+export async function loyaltyEscrowIfOpen(playerWallet: PublicKey): Promise<PublicKey | null> {
+  const [escrow] = deriveLoyaltyEscrowPda(playerWallet);
+  const info = await withTimeout(
+    getConnection().getAccountInfo(escrow),
+    30_000,
+    'getAccountInfo(loyaltyEscrow)'
+  );
+  return info ? escrow : null;
+}
+
+/**
+ * The player's SKR ATA if it exists, else null. Proposals take it as
+ * optional, so a practice player who never held SKR can still be settled.
+ */
+export async function playerTokenAccountIfOpen(playerWallet: PublicKey): Promise<PublicKey | null> {
+  const ata = await getAssociatedTokenAddress(SKR_MINT, playerWallet);
+  const info = await withTimeout(
+    getConnection().getAccountInfo(ata),
+    30_000,
+    'getAccountInfo(playerTokenAccount)'
+  );
+  return info ? ata : null;
+}
+
+/**
+ * Derive the MissionUsage PDA counting reveals of a mission_id
+ */
+export function deriveMissionUsagePda(missionIdBytes: Buffer): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('mission_usage'), missionIdBytes],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Derive the BlockedMission PDA for a mission_id (exists only if blocked)
+ */
+export function deriveBlockedMissionPda(missionIdBytes: Buffer): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('blocked_mission'), missionIdBytes],
+    PROGRAM_ID
+  );
+}
+
+/**
+ * Protocol fee destinations configured via set_protocol_split, as the
+ * remaining accounts finalize/propose must pass (in order) for a loss.
+ */
+export function protocolDestinationMetas(
+  globalState: any
+): { pubkey: PublicKey; isSigner: boolean; isWritable: boolean }[] {
+  const destinations = globalState.protocolDestinations as PublicKey[];
+  const weights = globalState.protocolWeights as number[];
+  return destinations
+    .filter((_, i) => weights[i] > 0)
+    .map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }));
+}
+
 /**
  * Get current slot and timestamp for bounty creation
  */
@@ -179,12 +262,17 @@ export async function revealMissionOnChain(
     program.methods
       .revealMission(
         Array.from(missionIdBytes) as any,
-        Array.from(salt) as any
+        Array.from(salt) as any,
+        null // no geofence committed at accept
       )
       .accounts({
         hotAuthority: getHotAuthorityKeypair().publicKey,
         globalState: globalStatePda,
         bounty: new PublicKey(bountyPda),
+        // Always counted, so a max_mission_reuse cap set later applies too
+        missionUsage: deriveMissionUsagePda(missionIdBytes)[0],
+        blockedMission: deriveBlockedMissionPda(missionIdBytes)[0],
+        systemProgram: SystemProgram.programId,
       })
       .rpc(),
     30_000,
@@ -197,14 +285,25 @@ export async function revealMissionOnChain(
 
 /**
  * Propose resolution on-chain (after AI validation)
- * Part 2 of resolution: proposes win/loss, starts challenge period
+ * Part 2 of resolution: proposes win/loss, starts challenge period.
+ * Tiers in fast_resolve_tiers settle in this same call, so the settlement
+ * accounts are always passed (the player's ATA whenever it exists).
  */
 export async function proposeResolutionOnChain(
   bountyPda: string,
+  playerWallet: string,
   success: boolean
 ): Promise<string> {
   const program = getProgram();
   const [globalStatePda] = deriveGlobalStatePda();
+  const [houseVaultPda] = deriveHouseVaultPda();
+  const [singularityVaultPda] = deriveSingularityVaultPda();
+  const playerTokenAccount = await playerTokenAccountIfOpen(new PublicKey(playerWallet));
+
+  const globalState = await (program.account as any).globalState.fetch(globalStatePda);
+  const protocolTreasury = globalState.protocolTreasury as PublicKey;
+  // Only needed when a jackpot can route a slice to charity
+  const charityVault = globalState.charityJackpotBps > 0 ? (globalState.charityVault as PublicKey) : null;
 
   const signature = await withTimeout(
     program.methods
@@ -213,7 +312,18 @@ export async function proposeResolutionOnChain(
         hotAuthority: getHotAuthorityKeypair().publicKey,
         globalState: globalStatePda,
         bounty: new PublicKey(bountyPda),
+        playerStats: derivePlayerStatsPda(new PublicKey(playerWallet))[0],
+        loyaltyEscrow: await loyaltyEscrowIfOpen(new PublicKey(playerWallet)),
+        lossOracle: null,
+        playerTokenAccount,
+        houseVault: houseVaultPda,
+        singularityVault: singularityVaultPda,
+        protocolTreasury,
+        charityVault,
+        skrMint: SKR_MINT,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
+      .remainingAccounts(protocolDestinationMetas(globalState))
       .rpc(),
     30_000,
     'propose_resolution'
@@ -245,7 +355,7 @@ export async function resolveBountyOnChain(
     }
 
     // Step 2: Propose resolution (starts challenge period)
-    const proposeSig = await proposeResolutionOnChain(bountyPda, success);
+    const proposeSig = await proposeResolutionOnChain(bountyPda, playerWallet, success);
 
     // Step 3: Queue finalization durably (awaited Redis persist) for after the
     // challenge period. Must mirror the on-chain CHALLENGE_PERIOD const
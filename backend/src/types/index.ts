@@ -3,7 +3,7 @@ import { config } from '../config';
 // Bounty tiers
 export type Tier = 1 | 2 | 3;
 
-// SKR base-unit multiplier — MUST match the on-chain SKR_DECIMALS const.
+// SKR base-unit multiplier — MUST match the mint decimals recorded on-chain (GlobalState.skr_decimals).
 // Mainnet SKR (official Solana Mobile token) uses 6 decimals; devnet test
 // mint uses 9. Read from `config` (dotenv-loaded) rather than `process.env`
 // directly so this module cannot be imported before env is parsed.
@@ -1,14 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token::{self, Burn, CloseAccount, Mint, Token, TokenAccount, Transfer};
 use anchor_spl::associated_token::get_associated_token_address;
 
 declare_id!("DqsCXFjgLp4UDZgMQE6nvEHe7yiRNJsVYFv21JSbd73v");
 
+/// Log remaining compute units at a labelled checkpoint. Compiled only with
+/// `--features profiling`; expands to nothing in production builds.
+macro_rules! log_compute_units {
+    ($label:expr) => {
+        #[cfg(feature = "profiling")]
+        {
+            msg!("CU checkpoint: {}", $label);
+            anchor_lang::solana_program::log::sol_log_compute_units();
+        }
+    };
+}
+
 // ─── Feature-gated cluster constants ─────────────────────────────────────────
 // Build mainnet (default): `anchor build`
 // Build devnet:           `anchor build --no-default-features --features devnet`
 //
-// The mainnet SKR mint has 6 decimals; the devnet test mint has 9.
+// The mainnet SKR mint has 6 decimals; the devnet test mint has 9. Decimals
+// are read from the mint at init, so nothing below depends on them.
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// The $SKR token mint (official Solana Mobile ecosystem token on mainnet).
@@ -17,14 +31,9 @@ pub const SKR_MINT: Pubkey = pubkey!("SKRbvo6Gf7GondiT3BbTfuRDPqLWei4j2Qy2NPGZhW
 #[cfg(feature = "devnet")]
 pub const SKR_MINT: Pubkey = pubkey!("u3BkoKjVYYPt24Dto1VPwAzqeQg9ffaxnCVhTAYbAFF");
 
-/// SKR decimals (mainnet token is 6, devnet test token is 9).
-#[cfg(feature = "mainnet")]
-pub const SKR_DECIMALS: u32 = 6;
-#[cfg(feature = "devnet")]
-pub const SKR_DECIMALS: u32 = 9;
-
-/// 10^SKR_DECIMALS - multiplier to convert whole SKR to base units.
-pub const DECIMALS_MULTIPLIER: u64 = 10u64.pow(SKR_DECIMALS);
+/// Most mint decimals accepted at init: keeps 10^decimals times the largest
+/// tier entry well inside u64.
+pub const MAX_SKR_DECIMALS: u8 = 12;
 
 /// Challenge period (300s on mainnet, 10s on devnet for demo).
 #[cfg(feature = "mainnet")]
@@ -32,10 +41,33 @@ pub const CHALLENGE_PERIOD: i64 = 300;
 #[cfg(feature = "devnet")]
 pub const CHALLENGE_PERIOD: i64 = 10;
 
-/// Entry amounts: 1000 / 2000 / 3000 SKR (in base units).
-pub const TIER_1_ENTRY: u64 = 1000 * DECIMALS_MULTIPLIER;
-pub const TIER_2_ENTRY: u64 = 2000 * DECIMALS_MULTIPLIER;
-pub const TIER_3_ENTRY: u64 = 3000 * DECIMALS_MULTIPLIER;
+/// Allowance for validator clock skew at bounty time boundaries. A hunt's
+/// timer counts as running until expires_at + CLOCK_SKEW_GRACE, and the
+/// dispute/finalize boundary moves CLOCK_SKEW_GRACE earlier. The tradeoff:
+/// players get a couple of seconds more to submit, and the challenge period
+/// is a couple of seconds shorter, which is far below anything a player or
+/// keeper can act on but absorbs unix_timestamp drift between validators.
+pub const CLOCK_SKEW_GRACE: i64 = 2;
+
+/// Floor on a proposed loss's dispute window, counted from the proposal,
+/// whatever the challenge period or fast-resolve config says. Never above
+/// CHALLENGE_PERIOD on any cluster.
+pub const MIN_CHALLENGE_PERIOD: i64 = 10;
+
+/// Entry amounts in whole SKR: 1000 / 2000 / 3000. Scaled to base units by
+/// GlobalState::decimals_multiplier().
+pub const TIER_1_ENTRY_SKR: u64 = 1000;
+pub const TIER_2_ENTRY_SKR: u64 = 2000;
+pub const TIER_3_ENTRY_SKR: u64 = 3000;
+
+/// Payout on a win as a multiple of entry (entry back + 2x profit).
+pub const PAYOUT_MULTIPLIER: u64 = 3;
+
+/// Default payout multiplier in basis points, derived from PAYOUT_MULTIPLIER.
+pub const DEFAULT_PAYOUT_MULTIPLIER_BPS: u16 = (PAYOUT_MULTIPLIER * 10000) as u16;
+
+/// Upper bound on the configurable payout multiplier (5x).
+pub const MAX_PAYOUT_MULTIPLIER_BPS: u16 = 50000;
 
 /// Distribution percentages on loss (basis points, 10000 = 100%).
 pub const HOUSE_SHARE_BPS: u64 = 7000;      // 70% stays in house
@@ -45,15 +77,121 @@ pub const PROTOCOL_SHARE_BPS: u64 = 1000;    // 10% to protocol treasury
 /// Jackpot odds: 1 in 500 chance on every win.
 pub const SINGULARITY_ODDS: u64 = 500;
 
-/// Per-tier hunt timer durations (seconds).
+/// Upper bound on the loss-streak odds boost: at most halves the odds.
+pub const MAX_LOSS_STREAK_BOOST_BPS: u16 = 5000;
+
+/// Ceiling on the jackpot slice routed to the charity vault (50%).
+pub const MAX_CHARITY_JACKPOT_BPS: u16 = 5000;
+
+/// Most wallets a loss's protocol share can be split across
+/// (set_protocol_split).
+pub const MAX_PROTOCOL_DESTINATIONS: usize = 4;
+
+/// Most players that can join another player's dispute (join_dispute).
+pub const MAX_CO_DISPUTERS: u8 = 4;
+
+/// Default per-tier hunt timer durations (seconds). Live values are in
+/// GlobalState.tier_durations and can be changed with set_tier_duration.
 pub const TIER_1_DURATION: i64 = 180;  // 3 minutes
 pub const TIER_2_DURATION: i64 = 120;  // 2 minutes
 pub const TIER_3_DURATION: i64 = 60;   // 1 minute
 
-/// Dispute parameters. (Window enforced via bounty.challenge_ends_at; no
-/// separate post-resolution dispute window.)
+/// Time the backend gets after a hunt timer runs out before a still-Pending
+/// bounty becomes refundable in full (cancel_bounty, keeper_sweep).
+pub const EXPIRED_REFUND_GRACE: i64 = 3600;
+
+/// Minimum length of an accounting epoch. advance_epoch can be cranked by
+/// anyone once this has passed since the epoch started (1 day).
+pub const EPOCH_DURATION: i64 = 86_400;
+
+/// How long a pause may block a bounty before its player can pull their
+/// funds out with reclaim_during_pause (3 days).
+pub const PAUSE_REFUND_TIMEOUT: i64 = 3 * 86_400;
+
+/// How long the backend has after a reveal to propose a result before the
+/// player can pull their entry back out with reclaim_unproposed (1 day).
+pub const PROPOSE_TIMEOUT: i64 = 86_400;
+
+/// How long a win left for its player to claim (player_claim_required) may
+/// sit unclaimed past its challenge period before the cold authority can
+/// escheat it with escheat_unclaimed_jackpot (90 days).
+pub const ESCHEAT_TIMEOUT: i64 = 90 * 86_400;
+
+/// How long after creation a terminal bounty account must stay open before
+/// close_bounty / close_bounties_batch can reclaim its rent (1 day).
+pub const CLOSE_COOLDOWN: i64 = 86_400;
+
+/// Hard floor for a configured tier timer. No human can find and photograph
+/// a target in less, so a shorter timer would just take the entry.
+pub const MIN_TIER_DURATION: i64 = 30;
+
+/// Default ceiling on a single hunt's lifetime (1 hour). Live value is
+/// GlobalState.max_duration, changed with set_max_duration.
+pub const DEFAULT_MAX_DURATION: i64 = 3_600;
+
+/// Default lifetime of a mission commitment (24 hours), measured from
+/// bounty creation. A commitment that has gone unrevealed this long is
+/// treated as stale: reveal is rejected and the player takes the refund
+/// path instead. Live value is GlobalState.commitment_ttl, changed with
+/// set_commitment_ttl.
+pub const COMMITMENT_TTL: i64 = 86_400;
+
+/// Most commitments one CommitmentPool holds (one bit each in `consumed`).
+pub const MAX_POOL_COMMITMENTS: usize = 64;
+
+/// Dispute parameters. (Window enforced via bounty.challenge_ends_at plus the
+/// configurable finalize grace; no separate post-resolution dispute window.)
+/// DISPUTE_STAKE_BPS is the default; GlobalState.tier_dispute_stake_bps can
+/// override it per tier.
 pub const DISPUTE_STAKE_BPS: u64 = 5000;     // 50% of original entry to dispute
 
+/// GlobalState and Bounty account sizes as first deployed, before either
+/// layout grew. Fields have only ever been appended, so a legacy account is
+/// a prefix of the current layout: migrate_global_state and migrate_bounty
+/// realloc it to the current SIZE with the new fields zeroed.
+pub const LEGACY_GLOBAL_STATE_SIZE: usize = 257;
+pub const LEGACY_BOUNTY_SIZE: usize = 216;
+
+/// Upper bound on the configurable finalize grace (1 hour).
+pub const MAX_FINALIZE_GRACE: i64 = 3600;
+
+/// Upper bound on the configurable per-player jackpot cooldown (1 epoch).
+pub const MAX_JACKPOT_COOLDOWN: i64 = EPOCH_DURATION;
+
+/// Upper bound on the configurable minimum hunt length in slots (~3 min at
+/// 400ms slots, the default tier-1 timer), so a hunt can always be revealed
+/// before it expires.
+pub const MAX_MIN_HUNT_SLOTS: u64 = 450;
+
+/// Number of fields in a multi-field mission commitment (target, location
+/// band, time window). Field 0 is the mission_id.
+pub const MISSION_FIELD_COUNT: usize = 3;
+
+/// Upper bound on the configurable win burn (5% of payout), so a
+/// tokenomics experiment can never eat a meaningful share of a win.
+pub const MAX_WIN_BURN_BPS: u16 = 500;
+
+/// Upper bound on the configurable win jackpot contribution (5% of payout),
+/// mirroring MAX_WIN_BURN_BPS.
+pub const MAX_WIN_JACKPOT_BPS: u16 = 500;
+
+/// Upper bound on the configurable loyalty rebate (10% of payout).
+pub const MAX_LOYALTY_REBATE_BPS: u16 = 1000;
+
+/// Upper bound on the configurable dispute-win compensation: at most one
+/// extra entry on top of the refund.
+pub const MAX_DISPUTE_WIN_BONUS_BPS: u16 = 10000;
+
+/// Length of the per-tier daily cap window (seconds).
+pub const TIER_CAP_WINDOW: i64 = 86_400;
+
+/// Length of the per-player daily wager window (seconds).
+pub const WAGER_CAP_WINDOW: i64 = 86_400;
+
+/// How long after cancel_bounty the player may undo it with reopen_bounty
+/// (seconds).
+pub const REOPEN_GRACE: i64 = 300;
+
 /// Mainnet `initialize` is restricted to this pubkey to prevent front-running
 /// of the deploy → initialize gap by an MEV bot. Replace the placeholder with
 /// the cold-authority Ledger pubkey BEFORE running `anchor build` for mainnet.
@@ -67,18 +205,18 @@ pub const EXPECTED_INITIAL_AUTHORITY: Pubkey =
 // rejects this default, so a forgotten edit will fail-fast at init time
 // rather than silently allowing any caller.
 
-/// Validate entry amount and return tier
-pub fn validate_entry_amount(entry_amount: u64) -> Result<u8> {
-    match entry_amount {
-        TIER_1_ENTRY => Ok(1),
-        TIER_2_ENTRY => Ok(2),
-        TIER_3_ENTRY => Ok(3),
-        _ => Err(SeekError::InvalidEntryAmount.into()),
+/// Validate entry amount (base units at `multiplier` per SKR) and return tier
+pub fn validate_entry_amount(entry_amount: u64, multiplier: u64) -> Result<u8> {
+    for tier in 1..=3 {
+        if get_tier_entry(tier, multiplier)? == entry_amount {
+            return Ok(tier);
+        }
     }
+    Err(SeekError::InvalidEntryAmount.into())
 }
 
-/// Get timer duration for a tier. Error if tier is not 1/2/3 (unreachable in
-/// practice because validate_entry_amount filters first, but defensive).
+/// Default timer duration for a tier, used to seed GlobalState.tier_durations.
+/// Error if tier is not 1/2/3.
 pub fn get_tier_duration(tier: u8) -> Result<i64> {
     match tier {
         1 => Ok(TIER_1_DURATION),
@@ -88,6 +226,668 @@ pub fn get_tier_duration(tier: u8) -> Result<i64> {
     }
 }
 
+/// Get entry amount for a tier in base units at `multiplier` per SKR.
+/// Error if tier is not 1/2/3.
+pub fn get_tier_entry(tier: u8, multiplier: u64) -> Result<u64> {
+    let whole = match tier {
+        1 => TIER_1_ENTRY_SKR,
+        2 => TIER_2_ENTRY_SKR,
+        3 => TIER_3_ENTRY_SKR,
+        _ => return Err(SeekError::InvalidEntryAmount.into()),
+    };
+    whole
+        .checked_mul(multiplier)
+        .ok_or(SeekError::MathOverflow.into())
+}
+
+/// Index into the per-tier arrays on GlobalState. Error if tier is not 1/2/3.
+fn tier_index(tier: u8) -> Result<usize> {
+    match tier {
+        1..=3 => Ok(usize::from(tier - 1)),
+        _ => Err(SeekError::InvalidEntryAmount.into()),
+    }
+}
+
+/// Split of a lost entry. `remainder` is the rounding dust left after the
+/// basis-point shares; it stays in the house alongside `house_share`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LossShares {
+    pub house_share: u64,
+    pub singularity_share: u64,
+    pub protocol_share: u64,
+    pub remainder: u64,
+}
+
+/// `amount * numerator / denominator`, rounded down. The product is taken in
+/// u128 so it can't overflow; only a result that doesn't fit back in u64
+/// (or a zero denominator) is an error.
+pub fn mul_div(amount: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    let scaled = u128::from(amount)
+        .checked_mul(u128::from(numerator))
+        .ok_or(SeekError::MathOverflow)?
+        .checked_div(u128::from(denominator))
+        .ok_or(SeekError::MathOverflow)?;
+    u64::try_from(scaled).map_err(|_| SeekError::MathOverflow.into())
+}
+
+/// `bps` basis points of `amount`, rounded down.
+pub fn bps_of(amount: u64, bps: u64) -> Result<u64> {
+    mul_div(amount, bps, 10000)
+}
+
+/// Win payout for an entry at `multiplier_bps` (30000 = 3x). Shared by
+/// accept_bounty and can_accept_tier. A fractional result (e.g. 2.5x of an
+/// odd entry) is rounded down, the dust staying with the house; the exact
+/// amount is stored as Bounty.payout_amount and is what finalize pays.
+pub fn compute_payout(entry: u64, multiplier_bps: u16) -> Result<u64> {
+    bps_of(entry, u64::from(multiplier_bps))
+}
+
+/// What a player gets back when they win a dispute: the entry plus the
+/// dispute stake, plus `bonus_bps` of the entry as explicit compensation
+/// (0 by default; there is no implicit second entry). Shared by
+/// dispute_bounty's solvency check and settlement.
+pub fn dispute_win_refund(entry: u64, dispute_stake: u64, bonus_bps: u16) -> Result<u64> {
+    let bonus = bps_of(entry, u64::from(bonus_bps))?;
+    entry
+        .checked_add(dispute_stake)
+        .and_then(|r| r.checked_add(bonus))
+        .ok_or(SeekError::MathOverflow.into())
+}
+
+/// What one disputer gets back from a won dispute, entry aside: their stake
+/// plus their slice of the `bonus_bps` bonus, pro-rata to `stake` out of
+/// `total_stake` (the player's and every co-disputer's), rounded down with
+/// the dust left in the house. With no co-disputers the player's slice is
+/// the whole bonus, matching dispute_win_refund.
+pub fn dispute_stake_return(entry: u64, bonus_bps: u16, stake: u64, total_stake: u64) -> Result<u64> {
+    let bonus = bps_of(entry, u64::from(bonus_bps))?;
+    let share = if total_stake == 0 { 0 } else { mul_div(bonus, stake, total_stake)? };
+    stake.checked_add(share).ok_or(SeekError::MathOverflow.into())
+}
+
+/// Loss distribution math shared by finalize_bounty, resolve_dispute and
+/// simulate_loss: 70% house, 20% singularity, 10% protocol, rounded down.
+pub fn compute_loss_shares(entry: u64) -> Result<LossShares> {
+    let share = |bps: u64| bps_of(entry, bps);
+
+    let house_share = share(HOUSE_SHARE_BPS)?;
+    let singularity_share = share(SINGULARITY_SHARE_BPS)?;
+    let protocol_share = share(PROTOCOL_SHARE_BPS)?;
+    let remainder = entry
+        .checked_sub(house_share)
+        .and_then(|r| r.checked_sub(singularity_share))
+        .and_then(|r| r.checked_sub(protocol_share))
+        .ok_or(SeekError::MathOverflow)?;
+
+    Ok(LossShares {
+        house_share,
+        singularity_share,
+        protocol_share,
+        remainder,
+    })
+}
+
+/// Drop a settling bounty's payout reservation, if it has one. Must run
+/// before any solvency check that nets out outstanding_liability, so a
+/// prefunded win can draw on its own reservation.
+fn release_payout_reservation(global_state: &mut GlobalState, bounty: &mut Bounty) -> Result<()> {
+    if bounty.prefunded {
+        global_state.outstanding_liability = global_state
+            .outstanding_liability
+            .checked_sub(bounty.payout_amount)
+            .ok_or(SeekError::MathOverflow)?;
+        bounty.prefunded = false;
+    }
+    Ok(())
+}
+
+/// Take a bounty that's reaching Won/Lost/Cancelled out of the books: drop
+/// its payout reservation and its count in active_bounties. Practice
+/// bounties were never counted. Saturating, since bounties still in flight
+/// when migrate_global_state started the counter at 0 were never counted
+/// either.
+fn retire_bounty(global_state: &mut GlobalState, bounty: &mut Bounty) -> Result<()> {
+    release_payout_reservation(global_state, bounty)?;
+    if !bounty.practice {
+        global_state.active_bounties = global_state.active_bounties.saturating_sub(1);
+    }
+    Ok(())
+}
+
+/// Whether the deployment can be wound down: shut down, no bounty still
+/// counted in active_bounties, and no loyalty rebate left for a player to
+/// claim out of the house vault.
+fn require_wound_down(global_state: &GlobalState) -> Result<()> {
+    require!(global_state.shutdown, SeekError::ProtocolNotShutdown);
+    require!(global_state.active_bounties == 0, SeekError::BountiesStillOpen);
+    require!(global_state.loyalty_escrowed == 0, SeekError::LoyaltyStillEscrowed);
+    Ok(())
+}
+
+/// Move whatever is left in both vaults to `destination`, then close the
+/// two vault token accounts with their rent going to `rent_to`. Moves the
+/// live amounts, so untracked dust goes too, and zeroes the tracked
+/// balances to match.
+fn sweep_vaults_out<'info>(
+    global_state: &mut GlobalState,
+    vaults: &VaultAccounts<'info>,
+    house_amount: u64,
+    singularity_amount: u64,
+    destination: &AccountInfo<'info>,
+    rent_to: &AccountInfo<'info>,
+) -> Result<()> {
+    if house_amount > 0 {
+        vaults.transfer(&vaults.house_vault, destination, house_amount)?;
+    }
+    if singularity_amount > 0 {
+        vaults.transfer(&vaults.singularity_vault, destination, singularity_amount)?;
+    }
+    vaults.close(&vaults.house_vault, rent_to)?;
+    vaults.close(&vaults.singularity_vault, rent_to)?;
+
+    global_state.house_fund_balance = 0;
+    global_state.singularity_balance = 0;
+    Ok(())
+}
+
+/// Whether both vault token accounts are gone: closed by sweep_vaults,
+/// which leaves them no lamports and, once the transaction ends, no
+/// longer owned by the token program.
+fn require_vaults_closed(house_vault: &AccountInfo, singularity_vault: &AccountInfo) -> Result<()> {
+    let closed = |vault: &AccountInfo| vault.lamports() == 0 || *vault.owner != token::ID;
+    require!(
+        closed(house_vault) && closed(singularity_vault),
+        SeekError::VaultsNotEmpty
+    );
+    Ok(())
+}
+
+/// Count a loss-path protocol share toward lifetime revenue. Returns true if
+/// it compounds into the house (caller keeps it in the vault and credits
+/// house_fund_balance) rather than being sent to protocol_treasury.
+fn record_protocol_share(global_state: &mut GlobalState, protocol_share: u64) -> Result<bool> {
+    global_state.total_protocol_revenue = global_state
+        .total_protocol_revenue
+        .checked_add(protocol_share)
+        .ok_or(SeekError::MathOverflow)?;
+    Ok(global_state.auto_compound_protocol)
+}
+
+/// Split a forfeited dispute stake: `forfeit_stake_treasury_bps` of it goes
+/// from the house vault to protocol_treasury (counted as protocol revenue),
+/// the rest stays in the house, where the stake already sits.
+fn route_forfeited_stake<'info>(
+    global_state: &mut GlobalState,
+    vaults: &VaultAccounts<'info>,
+    forfeited: u64,
+) -> Result<()> {
+    let to_treasury = bps_of(forfeited, u64::from(global_state.forfeit_stake_treasury_bps))?;
+    if to_treasury > 0 {
+        vaults.transfer(&vaults.house_vault, &vaults.protocol_treasury, to_treasury)?;
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .saturating_sub(to_treasury);
+        global_state.total_protocol_revenue = global_state
+            .total_protocol_revenue
+            .checked_add(to_treasury)
+            .ok_or(SeekError::MathOverflow)?;
+    }
+    Ok(())
+}
+
+/// Top up the house from protocol_treasury when auto-fund is on and
+/// house_fund_balance is below auto_fund_threshold. Moves auto_fund_amount
+/// as the treasury's delegate; skipped (returns 0) if the treasury hasn't
+/// delegated or can't cover it, so a win never fails on the top-up.
+/// Returns the amount moved.
+fn auto_fund_house<'info>(
+    global_state: &mut GlobalState,
+    vaults: &VaultAccounts<'info>,
+) -> Result<u64> {
+    let amount = global_state.auto_fund_amount;
+    if !global_state.auto_fund_house
+        || amount == 0
+        || global_state.house_fund_balance >= global_state.auto_fund_threshold
+    {
+        return Ok(0);
+    }
+
+    let treasury = {
+        let data = vaults.protocol_treasury.try_borrow_data()?;
+        TokenAccount::try_deserialize(&mut &data[..])?
+    };
+    let delegated = treasury.delegate.contains(vaults.global_state.key);
+    if !delegated || treasury.delegated_amount < amount || treasury.amount < amount {
+        msg!("Auto-fund skipped: treasury allowance or balance too low");
+        return Ok(0);
+    }
+
+    vaults.transfer(&vaults.protocol_treasury, &vaults.house_vault, amount)?;
+    global_state.house_fund_balance = global_state
+        .house_fund_balance
+        .checked_add(amount)
+        .ok_or(SeekError::MathOverflow)?;
+
+    emit!(HouseAutoFunded {
+        amount,
+        new_balance: global_state.house_fund_balance,
+    });
+    msg!("House auto-funded with {} SKR", global_state.whole_skr(amount));
+    Ok(amount)
+}
+
+/// Standard reasons an authority records when ruling on a dispute.
+/// Passed to `resolve_dispute` as a raw `u8` and echoed in `DisputeResolved`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum DisputeReason {
+    /// No reason given
+    Unspecified = 0,
+    /// Photo does not show the mission target
+    InvalidPhoto = 1,
+    /// Backend or AI failure; the original result can't be trusted
+    TechnicalError = 2,
+    /// AI rejected a photo that does show the target
+    FalsePositive = 3,
+    /// Photo was manipulated, reused, or otherwise not genuine
+    Fraud = 4,
+}
+
+impl DisputeReason {
+    /// Parse a raw reason code, rejecting unknown values.
+    pub fn from_code(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(Self::Unspecified),
+            1 => Ok(Self::InvalidPhoto),
+            2 => Ok(Self::TechnicalError),
+            3 => Ok(Self::FalsePositive),
+            4 => Ok(Self::Fraud),
+            _ => Err(SeekError::InvalidReasonCode.into()),
+        }
+    }
+}
+
+/// Who decided a bounty's result. Stored on the bounty as a raw `u8` and
+/// echoed in `BountyFinalized` / `DisputeResolved`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ResolutionSource {
+    /// Not resolved yet
+    None = 0,
+    /// A loss attested by the loss oracle's co-signature
+    Proof = 1,
+    /// The hot authority's proposal alone
+    Authority = 2,
+    /// The cold authority's ruling on a dispute
+    DisputeArbiter = 3,
+    /// mark_timeout: no on-time photo
+    Timeout = 4,
+}
+
+/// Why `can_accept_tier` would refuse a new bounty. Returned as a raw `u8`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AcceptBlockReason {
+    /// Nothing blocks the accept
+    None = 0,
+    /// Tier is not 1/2/3
+    InvalidTier = 1,
+    /// Protocol-wide rate limit reached for the current window
+    GlobalRateLimited = 2,
+    /// Daily cap for this tier reached
+    TierDailyCapReached = 3,
+    /// House can't back the payout on top of outstanding reservations
+    InsufficientHouseFunds = 4,
+    /// House hasn't been funded to the launch threshold yet
+    AcceptingDisabled = 5,
+    /// Protocol is paused
+    Paused = 6,
+    /// Protocol is shut down
+    Shutdown = 7,
+    /// House loss breaker tripped for the current epoch
+    EpochLossCapReached = 8,
+    /// Protocol-wide active bounty cap reached
+    ProtocolAtCapacity = 9,
+}
+
+/// Result of `can_accept_tier`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TierAvailability {
+    pub can_accept: bool,
+    /// `AcceptBlockReason` code (0 when `can_accept`)
+    pub reason_code: u8,
+}
+
+/// What `accept_bounty` created, set as the transaction's return data so
+/// clients don't have to re-derive the PDA and re-fetch the account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AcceptReceipt {
+    pub bounty: Pubkey,
+    pub expires_at: i64,
+    pub tier: u8,
+    pub payout_amount: u64,
+}
+
+/// Lifetime protocol statistics, returned by `get_protocol_stats`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtocolStats {
+    pub house_fund_balance: u64,
+    pub singularity_balance: u64,
+    pub total_burned: u64,
+    pub total_bounties_created: u64,
+    pub total_bounties_won: u64,
+    pub total_bounties_lost: u64,
+    pub total_singularity_wins: u64,
+    pub total_protocol_revenue: u64,
+    pub total_disputes_filed: u64,
+    pub total_disputes_won_by_player: u64,
+    pub total_dispute_stake_forfeited: u64,
+}
+
+/// Settlement counters over some span: lifetime values on GlobalState, or
+/// one epoch's share of them in `EpochAdvanced`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EpochTotals {
+    pub bounties_created: u64,
+    pub bounties_won: u64,
+    pub bounties_lost: u64,
+    pub burned: u64,
+    pub singularity_wins: u64,
+    pub protocol_revenue: u64,
+}
+
+impl EpochTotals {
+    /// What was added to these totals since `opening`.
+    pub fn since(&self, opening: &EpochTotals) -> Result<EpochTotals> {
+        let sub = |now: u64, then: u64| now.checked_sub(then).ok_or(SeekError::MathOverflow);
+        Ok(EpochTotals {
+            bounties_created: sub(self.bounties_created, opening.bounties_created)?,
+            bounties_won: sub(self.bounties_won, opening.bounties_won)?,
+            bounties_lost: sub(self.bounties_lost, opening.bounties_lost)?,
+            burned: sub(self.burned, opening.burned)?,
+            singularity_wins: sub(self.singularity_wins, opening.singularity_wins)?,
+            protocol_revenue: sub(self.protocol_revenue, opening.protocol_revenue)?,
+        })
+    }
+}
+
+/// Every configurable GlobalState value, returned by `get_config`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProtocolConfig {
+    // Authorities and recipients
+    pub authority: Pubkey,
+    pub hot_authority: Pubkey,
+    pub protocol_treasury: Pubkey,
+    pub charity_vault: Pubkey,
+    pub loss_oracle: Pubkey,
+    pub protocol_destinations: [Pubkey; MAX_PROTOCOL_DESTINATIONS],
+    pub protocol_weights: [u16; MAX_PROTOCOL_DESTINATIONS],
+
+    // Switches
+    pub paused: bool,
+    pub shutdown: bool,
+    pub accepting_enabled: bool,
+    pub burn_enabled: bool,
+    pub auto_compound_protocol: bool,
+    pub prefund_payout: bool,
+    pub player_claim_required: bool,
+    pub require_dispute_evidence: bool,
+    pub dispute_wins_roll_jackpot: bool,
+    pub co_disputes_enabled: bool,
+    pub accept_ordering: bool,
+    pub shutdown_jackpot_policy: u8,
+    pub escheat_policy: u8,
+    pub fast_resolve_tiers: u8,
+
+    // Payouts and splits
+    pub payout_multiplier_bps: u16,
+    pub min_payout: u64,
+    pub win_burn_bps: u16,
+    pub win_jackpot_bps: u16,
+    pub loyalty_rebate_bps: u16,
+    pub charity_jackpot_bps: u16,
+    pub loss_streak_boost_bps: u16,
+    pub max_loss_streak_boost_bps: u16,
+    pub min_cancel_fee_bps: u16,
+    pub max_cancel_fee_bps: u16,
+
+    // Disputes
+    pub dispute_fee: u64,
+    pub dispute_win_bonus_bps: u16,
+    pub dispute_loss_refund_bps: u16,
+    pub forfeit_stake_treasury_bps: u16,
+    pub tier_dispute_stake_bps: [u16; 3],
+
+    // Timing
+    pub tier_durations: [i64; 3],
+    pub max_duration: i64,
+    pub finalize_grace: i64,
+    pub commitment_ttl: i64,
+
+    // Limits
+    pub max_mission_reuse: u32,
+    pub global_rate_limit: u32,
+    pub rate_limit_window: i64,
+    pub tier_daily_caps: [u32; 3],
+    pub daily_wager_cap: u64,
+    pub min_launch_balance: u64,
+    pub max_epoch_house_loss: u64,
+    pub max_jackpot_per_epoch: u64,
+    pub min_jackpot_roll_balance: u64,
+    pub jackpot_cooldown: i64,
+    pub min_hunt_slots: u64,
+    pub auto_fund_house: bool,
+    pub auto_fund_threshold: u64,
+    pub auto_fund_amount: u64,
+    pub max_active_global: u64,
+}
+
+/// Where the singularity pool goes once the protocol is shut down.
+/// Stored on GlobalState as a raw `u8`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ShutdownJackpotPolicy {
+    /// Move the pool into the house vault
+    ReturnToHouse = 0,
+    /// Send the pool to the protocol treasury
+    SendToTreasury = 1,
+}
+
+impl ShutdownJackpotPolicy {
+    /// Parse a raw policy code, rejecting unknown values.
+    pub fn from_code(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(Self::ReturnToHouse),
+            1 => Ok(Self::SendToTreasury),
+            _ => Err(SeekError::InvalidConfig.into()),
+        }
+    }
+}
+
+/// Where escheat_unclaimed_jackpot sends an unclaimed win's payout. Stored
+/// as a raw `u8` in GlobalState.escheat_policy.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EscheatPolicy {
+    /// Move the payout into the singularity pool
+    ReturnToPool = 0,
+    /// Send the payout to the protocol treasury
+    SendToTreasury = 1,
+}
+
+impl EscheatPolicy {
+    /// Parse a raw policy code, rejecting unknown values.
+    pub fn from_code(code: u8) -> Result<Self> {
+        match code {
+            0 => Ok(Self::ReturnToPool),
+            1 => Ok(Self::SendToTreasury),
+            _ => Err(SeekError::InvalidConfig.into()),
+        }
+    }
+}
+
+/// Player's funds at stake on one bounty, returned by `get_player_at_risk`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlayerAtRisk {
+    /// Original entry held by the house
+    pub bet: u64,
+    /// Dispute stake held by the house (0 if not disputed)
+    pub dispute_stake: u64,
+    /// What the player gets back if the dispute goes their way, as
+    /// settle_dispute would pay it now: bet + dispute_stake + the player's
+    /// pro-rata slice of the win bonus while disputed, otherwise 0
+    pub potential_refund: u64,
+}
+
+/// A bounty's settled result, returned by `verify_win` so third parties
+/// can confirm a reported win against chain state.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WinProof {
+    /// Settled as a real (non-practice) win
+    pub is_won: bool,
+    /// Payout recorded at accept (0 unless is_won)
+    pub payout: u64,
+    /// The win also hit the singularity jackpot
+    pub singularity_won: bool,
+    /// When the bounty settled
+    pub finalized_at: i64,
+}
+
+/// A player's lifetime result, returned by `get_player_pnl`. The signed
+/// net (`total_won - total_wagered`) is split into a magnitude and a sign.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlayerPnl {
+    pub total_wagered: u64,
+    pub total_won: u64,
+    /// |total_won - total_wagered|
+    pub net_pnl: u64,
+    /// true when the player is down overall
+    pub net_negative: bool,
+    /// wins / (wins + losses) in basis points (0 before any settlement)
+    pub win_rate_bps: u16,
+}
+
+/// House funding needed for an exposure target, returned by
+/// `required_house_funding`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HouseFunding {
+    /// Payout liability of the target bounties (count * tier payout)
+    pub target_liability: u64,
+    /// Payouts already reserved for open bounties plus the target
+    pub total_liability: u64,
+    /// SKR still to deposit: total_liability - house_fund_balance, or 0
+    pub shortfall: u64,
+}
+
+/// A mission's target area, opened against Bounty.geo_commitment at reveal.
+/// The commitment is hash(lat_e6 || lon_e6 || radius_m || salt), each
+/// number little-endian.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Geofence {
+    /// Centre latitude in microdegrees
+    pub lat_e6: i32,
+    /// Centre longitude in microdegrees
+    pub lon_e6: i32,
+    /// Radius in metres
+    pub radius_m: u32,
+    pub salt: [u8; 32],
+}
+
+/// The authority's decision on one disputed bounty.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DisputeRuling {
+    pub player_wins: bool,
+    /// `DisputeReason` code
+    pub reason_code: u8,
+}
+
+/// Canonical protocol PDAs and their bumps, returned by `get_pda_info`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PdaInfo {
+    pub global_state: Pubkey,
+    pub global_state_bump: u8,
+    pub house_vault: Pubkey,
+    pub house_vault_bump: u8,
+    pub singularity_vault: Pubkey,
+    pub singularity_vault_bump: u8,
+}
+
+/// Early-cancel fee in basis points for a hunt that has run `elapsed` of its
+/// `duration` seconds. Scales linearly from `min_bps` at accept time to
+/// `max_bps` at expiry; elapsed is clamped to [0, duration].
+pub fn cancel_fee_bps(min_bps: u16, max_bps: u16, elapsed: i64, duration: i64) -> Result<u64> {
+    if duration <= 0 {
+        return Ok(u64::from(max_bps));
+    }
+    let elapsed = elapsed.clamp(0, duration) as u128;
+    let span = u128::from(max_bps.saturating_sub(min_bps));
+    let scaled = span
+        .checked_mul(elapsed)
+        .ok_or(SeekError::MathOverflow)?
+        .checked_div(duration as u128)
+        .ok_or(SeekError::MathOverflow)?;
+    let scaled = u64::try_from(scaled).map_err(|_| SeekError::MathOverflow)?;
+    Ok(u64::from(min_bps) + scaled)
+}
+
+/// Earliest time a bounty in its challenge period can be finalized. Proposed
+/// losses get the configured `finalize_grace` on top of `challenge_ends_at`
+/// (disputes stay open until then), and never less than
+/// MIN_CHALLENGE_PERIOD after the proposal, so a same-block finalize can't
+/// front-run the player's dispute; proposed wins have nothing to dispute,
+/// so they finalize at `challenge_ends_at`. Both are pulled in by
+/// CLOCK_SKEW_GRACE; the MIN_CHALLENGE_PERIOD floor is not.
+pub fn finalize_deadline(bounty: &Bounty, global_state: &GlobalState) -> Result<i64> {
+    if bounty.status == BountyStatus::ChallengeWon {
+        return bounty
+            .challenge_ends_at
+            .checked_sub(CLOCK_SKEW_GRACE)
+            .ok_or(SeekError::MathOverflow.into());
+    }
+    let configured = bounty
+        .challenge_ends_at
+        .checked_add(global_state.finalize_grace)
+        .and_then(|t| t.checked_sub(CLOCK_SKEW_GRACE))
+        .ok_or(SeekError::MathOverflow)?;
+    let floor = bounty
+        .resolved_at
+        .checked_add(MIN_CHALLENGE_PERIOD)
+        .ok_or(SeekError::MathOverflow)?;
+    Ok(configured.max(floor))
+}
+
+/// What keeper_sweep does with one bounty at `now`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SweepAction {
+    /// Finalize the proposed result, as finalize_bounty would
+    Settle,
+    /// Refund an expired, never-resolved hunt in full
+    Refund,
+    /// Not actionable yet (or ever, for terminal and disputed bounties)
+    Skip,
+}
+
+/// Route one bounty for keeper_sweep: proposed results past their finalize
+/// deadline settle (unless disputed, or a win the player must claim),
+/// Pending hunts past expiry + EXPIRED_REFUND_GRACE are refunded, and
+/// everything else is skipped.
+fn sweep_action(bounty: &Bounty, global_state: &GlobalState, now: i64) -> Result<SweepAction> {
+    let awaits_claim = bounty.proposed_win && global_state.player_claim_required;
+    Ok(match bounty.status {
+        BountyStatus::ChallengeWon | BountyStatus::ChallengeLost
+            if !(bounty.is_disputed || awaits_claim)
+                && now >= finalize_deadline(bounty, global_state)? =>
+        {
+            SweepAction::Settle
+        }
+        BountyStatus::Pending if now > bounty.expires_at + EXPIRED_REFUND_GRACE => {
+            SweepAction::Refund
+        }
+        _ => SweepAction::Skip,
+    })
+}
+
 /// Custom error codes for the Seek protocol
 #[error_code]
 pub enum SeekError {
@@ -145,6 +945,217 @@ pub enum SeekError {
 
     #[msg("Bounty close cooldown has not elapsed (24h after creation)")]
     BountyCooldown,
+
+    #[msg("Remaining accounts do not match the batch arguments")]
+    BatchAccountsMismatch,
+
+    #[msg("Mission has been revealed the maximum number of times")]
+    MissionReuseExceeded,
+
+    #[msg("Account is already at the current layout")]
+    AlreadyMigrated,
+
+    #[msg("Only a proposed win in its challenge period can be waived")]
+    ChallengeNotWaivable,
+
+    #[msg("Invalid config value")]
+    InvalidConfig,
+
+    #[msg("Photo submission already recorded")]
+    AlreadySubmitted,
+
+    #[msg("Reveal after expiry requires an on-time player submission")]
+    LateReveal,
+
+    #[msg("Bounty has already been finalized")]
+    BountyAlreadyFinalized,
+
+    #[msg("Protocol-wide bounty rate limit reached, try again shortly")]
+    GlobalRateLimited,
+
+    #[msg("Commitment salt or mission_id is all zeros")]
+    WeakCommitment,
+
+    #[msg("Unknown dispute reason code")]
+    InvalidReasonCode,
+
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+
+    #[msg("Daily cap for this tier reached")]
+    TierDailyCapReached,
+
+    #[msg("Disputes are not allowed on this bounty")]
+    DisputeNotAllowed,
+
+    #[msg("Tier duration is below MIN_TIER_DURATION")]
+    TierDurationTooShort,
+
+    #[msg("Protocol is shut down")]
+    ProtocolShutdown,
+
+    #[msg("Protocol is not shut down")]
+    ProtocolNotShutdown,
+
+    #[msg("Bounties are still open; settle or cancel them first")]
+    BountiesStillOpen,
+
+    #[msg("House and singularity vaults must be swept and closed first")]
+    VaultsNotEmpty,
+
+    #[msg("Daily wager limit reached for this player")]
+    DailyLimitReached,
+
+    #[msg("The bounty's player can't join their own dispute")]
+    CannotJoinOwnDispute,
+
+    #[msg("Dispute already has the maximum number of co-disputers")]
+    TooManyCoDisputers,
+
+    #[msg("Co-disputer accounts missing, duplicated or not for this bounty")]
+    CoDisputersMismatch,
+
+    #[msg("Bounty can't be reopened: not cancelled by the player, or the grace window or hunt timer has passed")]
+    ReopenNotAllowed,
+
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+
+    #[msg("Protocol is not paused")]
+    ProtocolNotPaused,
+
+    #[msg("Pause has not lasted long enough to reclaim")]
+    PauseRefundNotReady,
+
+    #[msg("Dispute resolution must attest the recorded photo hash")]
+    EvidenceRequired,
+
+    #[msg("Photo hash does not match the recorded submission")]
+    PhotoHashMismatch,
+
+    #[msg("Accepting is disabled until the house reaches the launch balance")]
+    AcceptingDisabled,
+
+    #[msg("Burning is disabled")]
+    BurnDisabled,
+
+    #[msg("Bounty belongs to a different global state")]
+    GlobalStateMismatch,
+
+    #[msg("Hunt duration exceeds max_duration")]
+    DurationTooLong,
+
+    #[msg("Resolution already proposed; the result can't change")]
+    ResolutionAlreadyProposed,
+
+    #[msg("House loss cap for this epoch reached; accepts reopen next epoch")]
+    EpochLossCapReached,
+
+    #[msg("Current epoch has not run for EPOCH_DURATION yet")]
+    EpochNotOver,
+
+    #[msg("Charity vault account required for this jackpot payout")]
+    CharityVaultMissing,
+
+    #[msg("Player token account is required to pay out a win")]
+    PlayerTokenAccountRequired,
+
+    #[msg("Payout would fall below min_payout")]
+    PayoutBelowMinimum,
+
+    #[msg("Mission has been blocked by the authority")]
+    MissionBlocked,
+
+    #[msg("This win must be claimed by the player")]
+    PlayerClaimRequired,
+
+    #[msg("Mission commitment expired; the bounty can only be refunded")]
+    CommitmentExpired,
+
+    #[msg("Protocol fee destinations missing or out of order in remaining_accounts")]
+    ProtocolDestinationsMismatch,
+
+    #[msg("The player recorded an on-time submission; the bounty can't be timed out")]
+    SubmittedInTime,
+
+    #[msg("Timeout window closed; the bounty is refundable instead")]
+    TimeoutWindowClosed,
+
+    #[msg("SlotHashes sysvar data unavailable")]
+    SlotHashesUnavailable,
+
+    #[msg("Bounty has no geofence commitment")]
+    GeofenceNotCommitted,
+
+    #[msg("Geofence commitment must be revealed with the mission")]
+    GeofenceRequired,
+
+    #[msg("Invalid geofence hash - does not match commitment")]
+    InvalidGeofenceHash,
+
+    // Wrong-state errors, one per state an instruction expects
+    #[msg("Bounty is not open (expected Pending)")]
+    ExpectedPending,
+
+    #[msg("Bounty has no revealed submission awaiting a result (expected Submitted)")]
+    ExpectedSubmitted,
+
+    #[msg("Bounty is not in its challenge period (expected ChallengeWon/ChallengeLost)")]
+    ExpectedChallenge,
+
+    #[msg("Bounty is not under dispute (expected Disputed)")]
+    ExpectedDisputed,
+
+    #[msg("Payout destination can't be a protocol vault")]
+    InvalidTokenAccount,
+
+    #[msg("Nothing to claim")]
+    NothingToClaim,
+
+    #[msg("Joining disputes is disabled")]
+    CoDisputesDisabled,
+
+    #[msg("Reveal too soon after accept (min_hunt_slots)")]
+    RevealTooEarly,
+
+    #[msg("Propose deadline passed: the player can reclaim this bounty")]
+    ProposeDeadlinePassed,
+
+    #[msg("Propose deadline not reached yet")]
+    ProposeDeadlineNotReached,
+
+    #[msg("Bounty has not reached a final result")]
+    BountyNotFinalized,
+
+    #[msg("Protocol is at its active bounty capacity")]
+    ProtocolAtCapacity,
+
+    #[msg("Commitment pool must hold 1 to 64 commitments")]
+    InvalidCommitmentPool,
+
+    #[msg("Mission commitment is not in this commitment pool")]
+    CommitmentNotInPool,
+
+    #[msg("Commitment has already been drawn from the pool")]
+    CommitmentAlreadyDrawn,
+
+    #[msg("Vault or treasury does not belong to this bounty's deployment")]
+    InvalidVault,
+
+    #[msg("Bounty must be Won, Lost or Cancelled")]
+    ExpectedTerminal,
+
+    #[msg("A reuse cap is set: the mission's usage counter must be passed")]
+    MissionUsageRequired,
+
+    #[msg("Unclaimed loyalty rebates are still escrowed in the house vault")]
+    LoyaltyStillEscrowed,
+
+    #[msg("Win is still within its claim window")]
+    EscheatTooEarly,
+
+    #[msg("Wins don't wait on their player: finalize_bounty settles this one")]
+    ClaimNotRequired,
 }
 
 /// Global protocol state - tracks all protocol-wide metrics
@@ -196,32 +1207,570 @@ pub struct GlobalState {
 
     /// Bump seed for PDA derivation
     pub bump: u8,
-}
 
-impl GlobalState {
-    /// Account size: 8 (discriminator) + 32*6 (authority, hot_authority,
-    /// pending_authority, house_vault, singularity_vault, protocol_treasury)
-    /// + 8*7 (house_fund_balance, singularity_balance, total_burned,
-    /// total_bounties_created, total_bounties_won, total_bounties_lost,
-    /// total_singularity_wins) + 1 (bump) = 257.
-    pub const SIZE: usize = 8 + 32 * 6 + 8 * 7 + 1;
-}
+    /// Max times a single mission_id may be revealed (0 = unlimited).
+    /// Enforced through the per-mission `MissionUsage` PDA.
+    pub max_mission_reuse: u32,
 
-/// Bounty status enum
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
-pub enum BountyStatus {
-    /// Bounty accepted, player is hunting
-    Pending,
-    /// Photo submitted, awaiting resolution
-    Submitted,
-    /// Resolved as win, in challenge period (optimistic)
-    ChallengeWon,
-    /// Resolved as loss, in challenge period (optimistic)
-    ChallengeLost,
-    /// Player disputed the loss result
-    Disputed,
-    /// Final: Player won (after challenge period)
-    Won,
+    /// Early-cancel fee at the start of the hunt (basis points of entry)
+    pub min_cancel_fee_bps: u16,
+
+    /// Early-cancel fee at expiry (basis points of entry). 0 = early cancel disabled.
+    pub max_cancel_fee_bps: u16,
+
+    /// Extra seconds after `challenge_ends_at` before a proposed loss can be
+    /// finalized. Disputes stay open through the grace window, so a keeper
+    /// can't snipe finalization the instant the challenge period ends.
+    pub finalize_grace: i64,
+
+    /// Max bounties accepted protocol-wide per `rate_limit_window` (0 = no limit).
+    /// Fixed windows: up to twice this many can land around a window boundary.
+    pub global_rate_limit: u32,
+
+    /// Length of the global rate-limit window (seconds)
+    pub rate_limit_window: i64,
+
+    /// Start of the current rate-limit window
+    pub rate_window_start: i64,
+
+    /// Bounties accepted in the current rate-limit window
+    pub rate_window_count: u64,
+
+    /// Whether a loss overturned by resolve_dispute also rolls the singularity
+    /// jackpot like a normal win (default false: dispute wins are refunds only)
+    pub dispute_wins_roll_jackpot: bool,
+
+    /// Basis points of each win payout burned from the house (0 = no burn)
+    pub win_burn_bps: u16,
+
+    /// Canonical house_vault PDA bump, stored at vault init and pinned in
+    /// every account constraint that re-derives the vault
+    pub house_vault_bump: u8,
+
+    /// Canonical singularity_vault PDA bump (see house_vault_bump)
+    pub singularity_vault_bump: u8,
+
+    /// When set, the loss-path protocol share stays in the house vault
+    /// (growing payout capacity) instead of going to protocol_treasury
+    pub auto_compound_protocol: bool,
+
+    /// Lifetime protocol share from losses, whether paid out or compounded
+    pub total_protocol_revenue: u64,
+
+    /// Max bounties accepted per tier (index tier - 1) per TIER_CAP_WINDOW.
+    /// 0 = unlimited for that tier.
+    pub tier_daily_caps: [u32; 3],
+
+    /// Bounties accepted per tier in the current daily window
+    pub tier_daily_counts: [u32; 3],
+
+    /// Start of the current per-tier daily window
+    pub tier_day_start: i64,
+
+    /// When set, accept_bounty reserves each new bounty's full payout against
+    /// the house (tracked in outstanding_liability) so its win is always payable
+    pub prefund_payout: bool,
+
+    /// Sum of payouts reserved for prefunded bounties still in flight.
+    /// Non-prefunded wins may only draw on the vault balance above this.
+    pub outstanding_liability: u64,
+
+    /// Oracle whose co-signature on propose_resolution attests a loss
+    /// cryptographically; such losses can't be disputed. Default = none.
+    pub loss_oracle: Pubkey,
+
+    /// Hunt timer per tier (index tier - 1), seconds. Never below MIN_TIER_DURATION.
+    pub tier_durations: [i64; 3],
+
+    /// Kill switch. Once set (via trigger_shutdown) no new bounties can be
+    /// accepted; bounties in flight still settle normally. Irreversible.
+    pub shutdown: bool,
+
+    /// `ShutdownJackpotPolicy` code applied by distribute_jackpot_on_shutdown
+    pub shutdown_jackpot_policy: u8,
+
+    /// Emergency pause. Blocks the bounty lifecycle (accept through
+    /// finalize/cancel) until lifted; see reclaim_during_pause.
+    pub paused: bool,
+
+    /// When the current pause began (0 = not paused)
+    pub paused_at: i64,
+
+    /// When set, disputes can only be resolved through
+    /// resolve_dispute_with_evidence, against the bounty's recorded photo_hash
+    pub require_dispute_evidence: bool,
+
+    /// Compensation paid on top of entry + stake when a player wins a
+    /// dispute, in basis points of the entry (0 = refund only)
+    pub dispute_win_bonus_bps: u16,
+
+    /// Disputes filed via dispute_bounty
+    pub total_disputes_filed: u64,
+
+    /// Disputes resolved in the player's favour
+    pub total_disputes_won_by_player: u64,
+
+    /// Lifetime dispute stake kept by the house on lost disputes
+    pub total_dispute_stake_forfeited: u64,
+
+    /// House balance fund_house must reach before accepting opens at launch
+    pub min_launch_balance: u64,
+
+    /// Latched on by fund_house once house_fund_balance >= min_launch_balance.
+    /// accept_bounty is rejected until then.
+    pub accepting_enabled: bool,
+
+    /// Master switch for token burns. When false, finalize keeps the
+    /// win-burn slice in the house instead of burning it, and burn_treasury is
+    /// rejected; total_burned is left untouched.
+    pub burn_enabled: bool,
+
+    /// Bitmask of tiers (bit 0 = tier 1) that skip the challenge period.
+    /// propose_resolution settles these immediately. 0 = none.
+    pub fast_resolve_tiers: u8,
+
+    /// Longest hunt accept_bounty will open, seconds (expires_at - created_at).
+    /// Bounds how long one bounty can lock funds whatever the tier timers say.
+    pub max_duration: i64,
+
+    /// Flat, non-refundable fee (SKR lamports) dispute_bounty sends to the
+    /// protocol treasury on top of the stake. Funds arbitration. 0 = none.
+    pub dispute_fee: u64,
+
+    /// Current accounting epoch, bumped by advance_epoch.
+    pub epoch: u64,
+
+    /// When the current epoch started (unix seconds).
+    pub epoch_started_at: i64,
+
+    /// House P&L from settled bounties this epoch (negative = net loss).
+    /// Wins count -(payout - entry); losses count the share the house keeps.
+    pub house_net_this_epoch: i64,
+
+    /// Circuit breaker: once the house's net loss this epoch exceeds this,
+    /// accept_bounty is rejected until advance_epoch. 0 = no cap.
+    pub max_epoch_house_loss: u64,
+
+    /// SKR token account receiving charity_jackpot_bps of each jackpot.
+    /// Pubkey::default() when no charity is configured.
+    pub charity_vault: Pubkey,
+
+    /// Share of a won jackpot sent to charity_vault instead of the player.
+    /// Capped at MAX_CHARITY_JACKPOT_BPS. 0 disables.
+    pub charity_jackpot_bps: u16,
+
+    /// Smallest win payout accept_bounty will open a bounty for (SKR
+    /// lamports). Keeps dust payouts off-chain. 0 = no minimum.
+    pub min_payout: u64,
+
+    /// Wins only pay when the player claims them: finalize_bounty on a win
+    /// must be signed by bounty.player. Losses stay permissionless.
+    pub player_claim_required: bool,
+
+    /// Seconds after bounty creation during which the mission commitment
+    /// may still be revealed (see Bounty::commitment_valid_until).
+    pub commitment_ttl: i64,
+
+    /// Optional split of every loss's protocol share: SKR token accounts and
+    /// their weights in bps (summing to 10000), packed from index 0. All-zero
+    /// weights send the whole share to protocol_treasury.
+    pub protocol_destinations: [Pubkey; MAX_PROTOCOL_DESTINATIONS],
+    pub protocol_weights: [u16; MAX_PROTOCOL_DESTINATIONS],
+
+    /// Share of the dispute stake handed back to a player who loses a
+    /// dispute, in bps (0 = the whole stake is forfeited)
+    pub dispute_loss_refund_bps: u16,
+
+    /// Stamp each accept with a slot-hash ordering key (Bounty.accept_order_key)
+    pub accept_ordering: bool,
+
+    /// Jackpot SKR paid out (player + charity) in the current epoch, and the
+    /// cap past which wins stop rolling the jackpot until advance_epoch (0 = no cap)
+    pub singularity_paid_this_epoch: u64,
+    pub max_jackpot_per_epoch: u64,
+
+    /// Basis points of each win payout routed into the singularity pool
+    /// before the player is paid (0 = only losses feed the jackpot)
+    pub win_jackpot_bps: u16,
+
+    /// Win payout as a multiple of entry, in basis points (30000 = 3x)
+    pub payout_multiplier_bps: u16,
+
+    /// Bounties accepted and not yet Won/Lost/Cancelled (practice excluded);
+    /// close_protocol requires 0
+    pub active_bounties: u64,
+
+    /// Max entries a single player may wager per WAGER_CAP_WINDOW (0 = no cap)
+    pub daily_wager_cap: u64,
+
+    /// Share of each win payout escrowed into the player's LoyaltyEscrow
+    /// (0 = disabled), claimable via claim_loyalty
+    pub loyalty_rebate_bps: u16,
+
+    /// Rebates escrowed for players but not yet claimed. The SKR stays in
+    /// the house vault, so wins may only draw on the balance above this.
+    pub loyalty_escrowed: u64,
+
+    /// When set, other players may back an open dispute with join_dispute.
+    /// The dispute itself can only ever be opened by the bounty's player.
+    pub co_disputes_enabled: bool,
+
+    /// Minimum time between a player's singularity rolls (0 = none). Wins
+    /// inside it still pay out but skip the roll.
+    pub jackpot_cooldown: i64,
+
+    /// Minimum slots between accept and reveal (0 = none), so a hunt
+    /// can't be revealed and resolved the moment it starts
+    pub min_hunt_slots: u64,
+
+    /// Share of a forfeited dispute stake sent to protocol_treasury to fund
+    /// arbitration, in bps; the rest stays in the house (0 = all house)
+    pub forfeit_stake_treasury_bps: u16,
+
+    /// Loss-streak consolation: each consecutive loss tightens the player's
+    /// next jackpot odds by loss_streak_boost_bps, up to max_loss_streak_boost_bps
+    /// in total (0 = off)
+    pub loss_streak_boost_bps: u16,
+
+    /// Cap on the total loss-streak boost, in bps of the base odds
+    pub max_loss_streak_boost_bps: u16,
+
+    /// Decimals of the SKR mint, read from the mint at initialize_house_vault.
+    /// Entry amounts and logged SKR figures scale by 10^skr_decimals
+    pub skr_decimals: u8,
+
+    /// Auto-fund: when a win finds house_fund_balance below auto_fund_threshold,
+    /// auto_fund_amount moves from protocol_treasury into the house first. The
+    /// treasury owner must approve the global_state PDA as delegate
+    pub auto_fund_house: bool,
+
+    /// House balance below which a win triggers the auto-fund
+    pub auto_fund_threshold: u64,
+
+    /// SKR moved from the treasury per auto-fund
+    pub auto_fund_amount: u64,
+
+    /// Most bounties that may be active at once protocol-wide, checked
+    /// against active_bounties at accept (0 = uncapped)
+    pub max_active_global: u64,
+
+    /// Lifetime counters as they stood when the current epoch started;
+    /// advance_epoch reports the difference as the closing epoch's figures
+    pub epoch_opening_totals: EpochTotals,
+
+    /// Dispute stake per tier (index tier - 1), in bps of the entry.
+    /// 0 = DISPUTE_STAKE_BPS
+    pub tier_dispute_stake_bps: [u16; 3],
+
+    /// Singularity pool below which a win skips the jackpot roll entirely
+    /// (0 = always roll)
+    pub min_jackpot_roll_balance: u64,
+
+    /// `EscheatPolicy` code: where an escheated win's payout goes
+    pub escheat_policy: u8,
+}
+
+impl GlobalState {
+    /// Account size: 8 (discriminator) + 32*6 (authority, hot_authority,
+    /// pending_authority, house_vault, singularity_vault, protocol_treasury)
+    /// + 8*7 (house_fund_balance, singularity_balance, total_burned,
+    ///   total_bounties_created, total_bounties_won, total_bounties_lost,
+    ///   total_singularity_wins) + 1 (bump) + 4 (max_mission_reuse)
+    ///   + 2*2 (min_cancel_fee_bps, max_cancel_fee_bps)
+    ///   + 8 (finalize_grace) + 4 (global_rate_limit)
+    ///   + 8*3 (rate_limit_window, rate_window_start, rate_window_count)
+    ///   + 1 (dispute_wins_roll_jackpot)
+    ///   + 2 (win_burn_bps)
+    ///   + 1 (house_vault_bump)
+    ///   + 1 (singularity_vault_bump)
+    ///   + 1 (auto_compound_protocol)
+    ///   + 8 (total_protocol_revenue)
+    ///   + 4*3 (tier_daily_caps)
+    ///   + 4*3 (tier_daily_counts)
+    ///   + 8 (tier_day_start)
+    ///   + 1 (prefund_payout)
+    ///   + 8 (outstanding_liability)
+    ///   + 32 (loss_oracle)
+    ///   + 8*3 (tier_durations)
+    ///   + 1 (shutdown)
+    ///   + 1 (shutdown_jackpot_policy)
+    ///   + 1 (paused)
+    ///   + 8 (paused_at)
+    ///   + 1 (require_dispute_evidence)
+    ///   + 2 (dispute_win_bonus_bps)
+    ///   + 8*3 (total_disputes_filed, total_disputes_won_by_player,
+    ///     total_dispute_stake_forfeited)
+    ///   + 8 (min_launch_balance)
+    ///   + 1 (accepting_enabled)
+    ///   + 1 (burn_enabled)
+    ///   + 1 (fast_resolve_tiers)
+    ///   + 8 (max_duration)
+    ///   + 8 (dispute_fee)
+    ///   + 8*4 (epoch, epoch_started_at, house_net_this_epoch,
+    ///     max_epoch_house_loss)
+    ///   + 32 (charity_vault) + 2 (charity_jackpot_bps)
+    ///   + 8 (min_payout)
+    ///   + 1 (player_claim_required)
+    ///   + 8 (commitment_ttl)
+    ///   + 32*4 (protocol_destinations) + 2*4 (protocol_weights)
+    ///   + 2 (dispute_loss_refund_bps)
+    ///   + 1 (accept_ordering)
+    ///   + 8*2 (singularity_paid_this_epoch, max_jackpot_per_epoch)
+    ///   + 2 (win_jackpot_bps)
+    ///   + 2 (payout_multiplier_bps)
+    ///   + 8 (active_bounties)
+    ///   + 8 (daily_wager_cap)
+    ///   + 2 (loyalty_rebate_bps)
+    ///   + 8 (loyalty_escrowed)
+    ///   + 1 (co_disputes_enabled)
+    ///   + 8 (jackpot_cooldown)
+    ///   + 8 (min_hunt_slots)
+    ///   + 2 (forfeit_stake_treasury_bps)
+    ///   + 2 (loss_streak_boost_bps)
+    ///   + 2 (max_loss_streak_boost_bps)
+    ///   + 1 (skr_decimals)
+    ///   + 1 (auto_fund_house)
+    ///   + 8 (auto_fund_threshold)
+    ///   + 8 (auto_fund_amount)
+    ///   + 8 (max_active_global)
+    ///   + 8*6 (epoch_opening_totals)
+    ///   + 2*3 (tier_dispute_stake_bps)
+    ///   + 8 (min_jackpot_roll_balance)
+    ///   + 1 (escheat_policy) = 857.
+    pub const SIZE: usize = 8 + 32 * 6 + 8 * 7 + 1 + 4 + 2 * 2 + 8 + 4 + 8 * 3 + 1 + 2 + 1 + 1 + 1 + 8 + 4 * 3 + 4 * 3 + 8 + 1 + 8 + 32 + 8 * 3 + 1 + 1 + 1 + 8 + 1 + 2 + 8 * 3 + 8 + 1 + 1 + 1 + 8 + 8 + 8 * 4 + 32 + 2 + 8 + 1 + 8 + 32 * 4 + 2 * 4 + 2 + 1 + 8 * 2 + 2 + 2 + 8 + 8 + 2 + 8 + 1 + 8 + 8 + 2 + 2 + 2 + 1 + 1 + 8 + 8 + 8 + 8 * 6 + 2 * 3 + 8 + 1;
+
+    /// Count one accept against the global rate limit, rolling the window
+    /// over once `rate_limit_window` seconds have passed. No-op when the
+    /// limit is 0.
+    ///
+    /// The window is fixed, not sliding: a burst at the end of one window
+    /// and another at the start of the next can admit up to
+    /// `2 * global_rate_limit` accepts within one window's length.
+    pub fn record_accept_for_rate_limit(&mut self, now: i64) -> Result<()> {
+        if self.global_rate_limit == 0 {
+            return Ok(());
+        }
+
+        if self.rate_window_expired(now)? {
+            self.rate_window_start = now;
+            self.rate_window_count = 0;
+        }
+
+        require!(self.rate_limit_open(now)?, SeekError::GlobalRateLimited);
+        self.rate_window_count = self
+            .rate_window_count
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Count one accept against the tier's daily cap. All tier counters
+    /// reset together once TIER_CAP_WINDOW has passed since the window
+    /// opened. A cap of 0 leaves the tier unlimited (still counted).
+    pub fn record_accept_for_tier_cap(&mut self, tier: u8, now: i64) -> Result<()> {
+        let idx = tier_index(tier)?;
+
+        if self.tier_window_expired(now)? {
+            self.tier_day_start = now;
+            self.tier_daily_counts = [0; 3];
+        }
+
+        require!(self.tier_cap_open(tier, now)?, SeekError::TierDailyCapReached);
+        self.tier_daily_counts[idx] = self.tier_daily_counts[idx]
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
+        Ok(())
+    }
+
+    fn rate_window_expired(&self, now: i64) -> Result<bool> {
+        let window_end = self
+            .rate_window_start
+            .checked_add(self.rate_limit_window)
+            .ok_or(SeekError::MathOverflow)?;
+        Ok(now >= window_end)
+    }
+
+    fn tier_window_expired(&self, now: i64) -> Result<bool> {
+        let window_end = self
+            .tier_day_start
+            .checked_add(TIER_CAP_WINDOW)
+            .ok_or(SeekError::MathOverflow)?;
+        Ok(now >= window_end)
+    }
+
+    /// Whether one more accept at `now` fits under the global rate limit.
+    pub fn rate_limit_open(&self, now: i64) -> Result<bool> {
+        Ok(self.global_rate_limit == 0
+            || self.rate_window_expired(now)?
+            || self.rate_window_count < u64::from(self.global_rate_limit))
+    }
+
+    /// Whether one more accept of `tier` at `now` fits under its daily cap.
+    pub fn tier_cap_open(&self, tier: u8, now: i64) -> Result<bool> {
+        let idx = tier_index(tier)?;
+        let cap = self.tier_daily_caps[idx];
+        Ok(cap == 0 || self.tier_window_expired(now)? || self.tier_daily_counts[idx] < cap)
+    }
+
+    /// Add `delta` to this epoch's house P&L. Returns true if this call
+    /// tripped the epoch loss breaker.
+    pub fn record_house_net(&mut self, delta: i64) -> Result<bool> {
+        let was_tripped = self.epoch_loss_cap_reached();
+        self.house_net_this_epoch = self
+            .house_net_this_epoch
+            .checked_add(delta)
+            .ok_or(SeekError::MathOverflow)?;
+        Ok(!was_tripped && self.epoch_loss_cap_reached())
+    }
+
+    /// Number of configured protocol fee destinations (0 = protocol_treasury).
+    pub fn protocol_destination_count(&self) -> usize {
+        self.protocol_weights.iter().take_while(|w| **w > 0).count()
+    }
+
+    /// Whether this epoch's jackpot payouts have reached max_jackpot_per_epoch.
+    pub fn jackpot_cap_reached(&self) -> bool {
+        self.max_jackpot_per_epoch != 0
+            && self.singularity_paid_this_epoch >= self.max_jackpot_per_epoch
+    }
+
+    /// Base units per whole SKR: 10^skr_decimals.
+    pub fn decimals_multiplier(&self) -> u64 {
+        10u64.saturating_pow(u32::from(self.skr_decimals))
+    }
+
+    /// `amount` in whole SKR (rounded down), for logs.
+    pub fn whole_skr(&self, amount: u64) -> u64 {
+        amount / self.decimals_multiplier()
+    }
+
+    /// Jackpot odds (1 in N) for a win after `loss_streak` straight losses:
+    /// SINGULARITY_ODDS tightened by loss_streak_boost_bps per loss, capped
+    /// at max_loss_streak_boost_bps. Never below 1 in 1.
+    pub fn jackpot_odds(&self, loss_streak: u16) -> Result<u64> {
+        let boost_bps = u64::from(loss_streak)
+            .saturating_mul(u64::from(self.loss_streak_boost_bps))
+            .min(u64::from(self.max_loss_streak_boost_bps));
+        let odds = SINGULARITY_ODDS
+            .checked_mul(10000u64.saturating_sub(boost_bps))
+            .ok_or(SeekError::MathOverflow)?
+            / 10000;
+        Ok(odds.max(1))
+    }
+
+    /// Dispute stake for `tier`, in bps of the entry: the tier's override,
+    /// or DISPUTE_STAKE_BPS where none is set.
+    pub fn dispute_stake_bps(&self, tier: u8) -> Result<u64> {
+        match self.tier_dispute_stake_bps[tier_index(tier)?] {
+            0 => Ok(DISPUTE_STAKE_BPS),
+            bps => Ok(u64::from(bps)),
+        }
+    }
+
+    /// Lifetime settlement counters, in the shape advance_epoch diffs.
+    pub fn lifetime_totals(&self) -> EpochTotals {
+        EpochTotals {
+            bounties_created: self.total_bounties_created,
+            bounties_won: self.total_bounties_won,
+            bounties_lost: self.total_bounties_lost,
+            burned: self.total_burned,
+            singularity_wins: self.total_singularity_wins,
+            protocol_revenue: self.total_protocol_revenue,
+        }
+    }
+
+    /// Whether active_bounties has reached max_active_global.
+    pub fn at_capacity(&self) -> bool {
+        self.max_active_global != 0 && self.active_bounties >= self.max_active_global
+    }
+
+    /// Whether the house's net loss this epoch is past max_epoch_house_loss.
+    pub fn epoch_loss_cap_reached(&self) -> bool {
+        self.max_epoch_house_loss != 0
+            && self.house_net_this_epoch < 0
+            && self.house_net_this_epoch.unsigned_abs() > self.max_epoch_house_loss
+    }
+
+    /// Whether `tier` settles at proposal time, skipping the challenge period.
+    pub fn is_fast_resolve(&self, tier: u8) -> Result<bool> {
+        Ok(self.fast_resolve_tiers & (1 << tier_index(tier)?) != 0)
+    }
+
+    /// Whether a proposal settles on the spot. Needs a fast-resolve tier,
+    /// and never applies to a win the player must claim or to a loss the
+    /// player could still dispute: a disputable loss always gets its
+    /// challenge window, so only oracle-attested losses fast-finalize.
+    pub fn settles_at_proposal(&self, tier: u8, success: bool, dispute_disabled: bool) -> Result<bool> {
+        Ok(self.is_fast_resolve(tier)?
+            && if success { !self.player_claim_required } else { dispute_disabled })
+    }
+
+    /// Whether the house can reserve `payout` for a bounty whose `entry`
+    /// has just been deposited, on top of outstanding reservations. This is
+    /// the check accept_bounty applies when prefund_payout is set.
+    pub fn can_back_payout(&self, entry: u64, payout: u64) -> Result<bool> {
+        let reserved = self
+            .outstanding_liability
+            .checked_add(payout)
+            .ok_or(SeekError::MathOverflow)?;
+        let balance = self
+            .house_fund_balance
+            .checked_add(entry)
+            .ok_or(SeekError::MathOverflow)?;
+        Ok(balance >= reserved)
+    }
+
+    /// Give a legacy GlobalState, just grown by migrate_global_state, the
+    /// values initialize would have stored in the fields it lacked. Fields
+    /// whose initialize default is zero stay zeroed. `skr_decimals` comes
+    /// from the SKR mint, as at init; `now` starts the epoch, which opens at
+    /// the current lifetime totals.
+    pub fn apply_migration_defaults(&mut self, skr_decimals: u8, now: i64) -> Result<()> {
+        self.house_vault_bump = Pubkey::find_program_address(&[b"house_vault"], &crate::ID).1;
+        self.singularity_vault_bump =
+            Pubkey::find_program_address(&[b"singularity_vault"], &crate::ID).1;
+        self.tier_durations = [
+            get_tier_duration(1)?,
+            get_tier_duration(2)?,
+            get_tier_duration(3)?,
+        ];
+        self.burn_enabled = true;
+        // A deployment being migrated is already live: keep accepting open
+        self.accepting_enabled = true;
+        self.max_duration = DEFAULT_MAX_DURATION;
+        self.commitment_ttl = COMMITMENT_TTL;
+        self.payout_multiplier_bps = DEFAULT_PAYOUT_MULTIPLIER_BPS;
+        self.epoch_started_at = now;
+        self.epoch_opening_totals = self.lifetime_totals();
+        self.skr_decimals = skr_decimals;
+        Ok(())
+    }
+
+    /// What's left of `balance` once other bounties' reserved payouts and
+    /// players' escrowed loyalty rebates are set aside: the most a single
+    /// settlement may draw from the house.
+    pub fn unreserved(&self, balance: u64) -> u64 {
+        balance
+            .saturating_sub(self.outstanding_liability)
+            .saturating_sub(self.loyalty_escrowed)
+    }
+}
+
+/// Bounty status enum
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BountyStatus {
+    /// Bounty accepted, player is hunting
+    Pending,
+    /// Photo submitted, awaiting resolution
+    Submitted,
+    /// Resolved as win, in challenge period (optimistic)
+    ChallengeWon,
+    /// Resolved as loss, in challenge period (optimistic)
+    ChallengeLost,
+    /// Player disputed the loss result
+    Disputed,
+    /// Final: Player won (after challenge period)
+    Won,
     /// Final: Player lost (after challenge period)
     Lost,
     /// Bounty was cancelled
@@ -272,7 +1821,8 @@ pub struct Bounty {
     pub mission_revealed: bool,
 
     // === OPTIMISTIC RESOLUTION FIELDS ===
-    /// Timestamp when resolution was submitted (challenge period starts)
+    /// Timestamp when resolution was submitted (challenge period starts).
+    /// Non-zero = the proposed result is locked in.
     pub resolved_at: i64,
 
     /// Timestamp when challenge period ends
@@ -290,6 +1840,85 @@ pub struct Bounty {
 
     /// Timestamp when dispute was filed
     pub disputed_at: i64,
+
+    // === SUBMISSION FIELDS ===
+    /// Timestamp when the player recorded their photo submission on-chain
+    /// (0 = not recorded). Always <= expires_at when set.
+    pub submitted_at: i64,
+
+    // === PREFUND FIELDS ===
+    /// Payout reserved in GlobalState.outstanding_liability at accept time.
+    /// Cleared when the bounty settles (won, lost or cancelled).
+    pub prefunded: bool,
+
+    /// Set by propose_resolution when a loss is co-signed by the loss
+    /// oracle. dispute_bounty rejects such bounties.
+    pub dispute_disabled: bool,
+
+    /// SHA-256 of the submitted photo, recorded by submit_photo
+    /// ([0; 32] = not recorded). Evidence for resolve_dispute_with_evidence.
+    pub photo_hash: [u8; 32],
+
+    /// Practice hunt from accept_practice_bounty: no entry, no payout, and
+    /// settlement never touches the vaults or protocol stats
+    pub practice: bool,
+
+    /// Settled as a loss by mark_timeout: the hunt expired without an
+    /// on-time photo, so no result was proposed or challengeable
+    pub timeout: bool,
+
+    // === ORDERING FIELDS ===
+    /// Slot whose hash seeded accept_order_key (0 = ordering off)
+    pub accept_slot: u64,
+
+    /// Tie-break among accepts in the same slot: first 8 bytes of
+    /// SHA-256(slot hash || bounty address). Order by (accept_slot, accept_order_key).
+    pub accept_order_key: u64,
+
+    // === GEOFENCE FIELDS ===
+    /// Optional hash(lat_e6 || lon_e6 || radius_m || salt) of the target area,
+    /// fixed at accept ([0; 32] = no geofence). Revealed with the mission.
+    pub geo_commitment: [u8; 32],
+
+    /// Revealed geofence centre (microdegrees) and radius (metres); valid
+    /// once geo_revealed is set
+    pub geo_lat_e6: i32,
+    pub geo_lon_e6: i32,
+    pub geo_radius_m: u32,
+
+    /// Whether the geofence has been revealed and verified
+    pub geo_revealed: bool,
+
+    // === CO-DISPUTE FIELDS ===
+    /// Total stake added by other players through join_dispute, and how
+    /// many of them there are (each holds a DisputeShare)
+    pub co_dispute_stake: u64,
+    pub co_disputers: u8,
+
+    // === REOPEN FIELDS ===
+    /// When the player cancelled a running hunt (0 = not cancelled by
+    /// cancel_bounty) and the fee kept; reopen_bounty re-deposits the rest
+    pub cancelled_at: i64,
+    pub cancel_fee: u64,
+
+    /// Slot the bounty was accepted in, checked against
+    /// GlobalState.min_hunt_slots at reveal (0 on bounties carried over by
+    /// migrate_bounty, so the check never holds their reveal back)
+    pub accepted_slot: u64,
+
+    /// When the mission was revealed (0 = not yet); propose_resolution must
+    /// follow within PROPOSE_TIMEOUT
+    pub revealed_at: i64,
+
+    /// `ResolutionSource` code of whoever decided the result (0 = none yet)
+    pub resolution_source: u8,
+
+    /// When the bounty settled as Won or Lost (0 = not yet)
+    pub finalized_at: i64,
+
+    /// Who paid the bounty account's rent at accept (the player, or a
+    /// relayer); close_bounty refunds the rent here
+    pub rent_payer: Pubkey,
 }
 
 impl Bounty {
@@ -297,1591 +1926,12305 @@ impl Bounty {
     /// created_at, expires_at) + 1*4 (status, tier, singularity_won, bump) +
     /// 32*2 (commitment + mission_id) + 1 (mission_revealed) + 8*2 (resolved_at,
     /// challenge_ends_at) + 1 (proposed_win) + 1 (is_disputed) + 8 (dispute_stake)
-    /// + 8 (disputed_at) = 207, padded to 216.
-    pub const SIZE: usize = 216;
-}
+    /// + 8 (disputed_at) + 8 (submitted_at) + 1 (prefunded)
+    /// + 1 (dispute_disabled) + 32 (photo_hash) + 1 (practice)
+    /// + 1 (timeout) + 8*2 (accept_slot, accept_order_key)
+    /// + 32 (geo_commitment) + 4*3 (geo_lat_e6, geo_lon_e6, geo_radius_m)
+    /// + 1 (geo_revealed) + 8 (co_dispute_stake) + 1 (co_disputers)
+    /// + 8*2 (cancelled_at, cancel_fee) + 8 (accepted_slot)
+    /// + 8 (revealed_at) + 1 (resolution_source) + 8 (finalized_at)
+    /// + 32 (rent_payer) = 394.
+    pub const SIZE: usize = 394;
+
+    /// Account that gets the rent back on close: rent_payer, or the player
+    /// on bounties carried over by migrate_bounty, whose rent_payer is zero.
+    pub fn rent_recipient(&self) -> Pubkey {
+        if self.rent_payer == Pubkey::default() {
+            self.player
+        } else {
+            self.rent_payer
+        }
+    }
 
-// ============================================================================
-// EVENTS - Emitted for frontend and indexer tracking
-// ============================================================================
+    /// Whether the hunt timer still counts as running at `now`: up to
+    /// expires_at plus CLOCK_SKEW_GRACE.
+    pub fn timer_running(&self, now: i64) -> bool {
+        now <= self.expires_at.saturating_add(CLOCK_SKEW_GRACE)
+    }
 
-/// Emitted when a player accepts a bounty
-#[event]
-pub struct BountyAccepted {
-    pub player: Pubkey,
-    pub bounty: Pubkey,
-    pub entry_amount: u64,
-    pub tier: u8,
-    pub expires_at: i64,
-}
+    /// Won, Lost or Cancelled: nothing can happen to the bounty any more.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self.status,
+            BountyStatus::Won | BountyStatus::Lost | BountyStatus::Cancelled
+        )
+    }
 
-/// Emitted when a bounty is won
-#[event]
-pub struct BountyWon {
-    pub player: Pubkey,
-    pub bounty: Pubkey,
-    pub payout: u64,
-    pub singularity_won: bool,
-    pub singularity_amount: u64,
+    /// Whether the bounty has entered the dispute flow. Such a bounty only
+    /// reaches Won/Lost through settle_dispute (resolve_dispute and the batch
+    /// resolver), never through settle_resolution.
+    pub fn in_dispute(&self) -> bool {
+        self.is_disputed || self.status == BountyStatus::Disputed
+    }
+
+    /// First timestamp at which a revealed, unproposed bounty can be
+    /// reclaimed (revealed_at + PROPOSE_TIMEOUT). Unbounded before a reveal
+    /// and on bounties migrate_bounty carried over after their reveal, which
+    /// have no revealed_at to count from.
+    pub fn propose_deadline(&self) -> Result<i64> {
+        if self.revealed_at == 0 {
+            return Ok(i64::MAX);
+        }
+        Ok(self
+            .revealed_at
+            .checked_add(PROPOSE_TIMEOUT)
+            .ok_or(SeekError::MathOverflow)?)
+    }
+
+    /// Last timestamp at which the mission commitment may be revealed.
+    pub fn commitment_valid_until(&self, ttl: i64) -> i64 {
+        self.created_at.saturating_add(ttl)
+    }
 }
 
-/// Emitted when a bounty is lost
-#[event]
-pub struct BountyLost {
-    pub player: Pubkey,
-    pub bounty: Pubkey,
-    pub entry_amount: u64,
-    pub house_share: u64,
-    pub singularity_share: u64,
-    pub protocol_share: u64,
+/// Per-mission reveal counter, keyed by mission_id. Lets anyone see how often
+/// the backend reuses a target, and backs the `max_mission_reuse` cap.
+#[account]
+pub struct MissionUsage {
+    /// The mission_id this counter tracks
+    pub mission_id: [u8; 32],
+
+    /// Number of bounties that have revealed this mission
+    pub reveal_count: u32,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 }
 
-/// Emitted when house is funded
-#[event]
-pub struct HouseFunded {
-    pub authority: Pubkey,
-    pub amount: u64,
-    pub new_balance: u64,
+impl MissionUsage {
+    /// Account size: 8 (discriminator) + 32 (mission_id) + 4 (reveal_count)
+    /// + 1 (bump) = 45.
+    pub const SIZE: usize = 8 + 32 + 4 + 1;
+
+    /// Count one more reveal of `mission_id`, refusing it once `max_reuse`
+    /// reveals (0 = uncapped) have been recorded.
+    pub fn record_reveal(&mut self, mission_id: [u8; 32], bump: u8, max_reuse: u32) -> Result<()> {
+        require!(
+            max_reuse == 0 || self.reveal_count < max_reuse,
+            SeekError::MissionReuseExceeded
+        );
+        self.mission_id = mission_id;
+        self.reveal_count = self
+            .reveal_count
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
+        self.bump = bump;
+        Ok(())
+    }
 }
 
-/// Emitted when mission is revealed (commit-reveal)
-#[event]
-pub struct MissionRevealed {
-    pub bounty: Pubkey,
+/// Marks a retired mission, keyed by mission_id. Its existence blocks every
+/// reveal of that mission and lets affected bounties be refunded through
+/// reclaim_unrevealed.
+#[account]
+pub struct BlockedMission {
+    /// The mission_id that is blocked
     pub mission_id: [u8; 32],
-    pub commitment_verified: bool,
+
+    /// When the authority blocked it
+    pub blocked_at: i64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 }
 
-/// Emitted when bounty enters challenge period (optimistic resolution)
-#[event]
-pub struct BountyResolutionProposed {
-    pub bounty: Pubkey,
-    pub player: Pubkey,
-    pub proposed_win: bool,
-    pub challenge_ends_at: i64,
+impl BlockedMission {
+    /// Account size: 8 (discriminator) + 32 (mission_id) + 8 (blocked_at)
+    /// + 1 (bump) = 49.
+    pub const SIZE: usize = 8 + 32 + 8 + 1;
 }
 
-/// Emitted when a bounty is disputed
-#[event]
-pub struct BountyDisputed {
+/// One co-disputer's stake in another player's dispute, keyed by bounty and
+/// disputer. Created by join_dispute; its stake is returned with a pro-rata
+/// share of the bonus, or forfeited, when the dispute is resolved (refunded
+/// in full by reclaim_during_pause), and the account is then closed back
+/// to the disputer.
+#[account]
+pub struct DisputeShare {
+    /// The disputed bounty
     pub bounty: Pubkey,
-    pub player: Pubkey,
-    pub dispute_stake: u64,
+
+    /// Player who joined the dispute (paid out to their canonical ATA)
+    pub disputer: Pubkey,
+
+    /// SKR staked into the house vault
+    pub stake: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 }
 
-/// Emitted when a dispute is resolved
-#[event]
-pub struct DisputeResolved {
-    pub bounty: Pubkey,
-    pub player: Pubkey,
-    pub player_won_dispute: bool,
-    pub stake_returned: bool,
+impl DisputeShare {
+    /// Account size: 8 (discriminator) + 32*2 (bounty, disputer) + 8 (stake)
+    /// + 1 (bump) = 81.
+    pub const SIZE: usize = 8 + 32 * 2 + 8 + 1;
 }
 
-/// Emitted when bounty is finalized after challenge period
-#[event]
-pub struct BountyFinalized {
-    pub bounty: Pubkey,
+/// Per-player loyalty rebates, keyed by player. Created on the player's
+/// first accept_bounty; wins settled with it credit `loyalty_rebate_bps` of
+/// the payout here instead of paying it out, and claim_loyalty pays the
+/// balance from the house vault.
+#[account]
+pub struct LoyaltyEscrow {
+    /// Player the rebates belong to
     pub player: Pubkey,
-    pub final_status: u8, // 0 = lost, 1 = won
-}
 
-/// Emitted when the protocol treasury recipient is rotated by the cold authority.
-#[event]
-pub struct TreasuryRotated {
-    pub authority: Pubkey,
-    pub old_treasury: Pubkey,
-    pub new_treasury: Pubkey,
-}
+    /// Rebates accrued and not yet claimed
+    pub balance: u64,
 
-/// Emitted when a bounty is cancelled by the player after expiry
-#[event]
-pub struct BountyCancelled {
-    pub player: Pubkey,
-    pub bounty: Pubkey,
-    pub refund_amount: u64,
-}
+    /// Lifetime rebates accrued
+    pub total_accrued: u64,
 
-/// Emitted when authority is transferred
-#[event]
-pub struct AuthorityTransferred {
-    pub old_authority: Pubkey,
-    pub new_authority: Pubkey,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 }
 
-#[program]
-pub mod seek_protocol {
-    use super::*;
-
-    /// Initialize the Seek protocol - Step 1: Create global state
-    /// Call initialize_vaults after this to set up token vaults
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
-        let global_state = &mut ctx.accounts.global_state;
-
-        // Set authority (vault addresses set in initialize_vaults)
-        global_state.authority = ctx.accounts.authority.key();
-
-        // Default hot_authority to the same key; rotate later via set_hot_authority.
-        global_state.hot_authority = ctx.accounts.authority.key();
-
-        // No pending authority transfer initially.
-        global_state.pending_authority = Pubkey::default();
-
-        // Initialize counters to zero
-        global_state.house_fund_balance = 0;
-        global_state.singularity_balance = 0;
-        global_state.total_burned = 0;
-        global_state.total_bounties_created = 0;
-        global_state.total_bounties_won = 0;
-        global_state.total_bounties_lost = 0;
-        global_state.total_singularity_wins = 0;
-
-        // Store bump for future PDA derivations
-        global_state.bump = ctx.bumps.global_state;
-
-        msg!("Seek Protocol global state initialized!");
-        msg!("Authority: {}", global_state.authority);
-        msg!("Hot authority: {} (rotate via set_hot_authority)", global_state.hot_authority);
+impl LoyaltyEscrow {
+    /// Account size: 8 (discriminator) + 32 (player) + 8*2 (balance,
+    /// total_accrued) + 1 (bump) = 57.
+    pub const SIZE: usize = 8 + 32 + 8 * 2 + 1;
 
+    /// Credit a rebate to the claimable balance.
+    pub fn accrue(&mut self, amount: u64) -> Result<()> {
+        self.balance = self.balance.checked_add(amount).ok_or(SeekError::MathOverflow)?;
+        self.total_accrued = self
+            .total_accrued
+            .checked_add(amount)
+            .ok_or(SeekError::MathOverflow)?;
         Ok(())
     }
+}
 
-    /// Initialize the Seek protocol - Step 2: Create house vault
-    /// Must be called after initialize()
-    pub fn initialize_house_vault(ctx: Context<InitializeHouseVault>) -> Result<()> {
-        let global_state = &mut ctx.accounts.global_state;
-        global_state.house_vault = ctx.accounts.house_vault.key();
+/// Mission commitments the backend published ahead of play, keyed by
+/// pool_id. accept_bounty may draw its commitment from a pool, proving the
+/// mission was fixed before the player accepted; each is drawn at most once.
+#[account]
+pub struct CommitmentPool {
+    /// Caller-chosen pool identifier (PDA seed)
+    pub pool_id: u64,
 
-        msg!("House vault initialized: {}", global_state.house_vault);
-        Ok(())
-    }
+    /// When the pool was published
+    pub published_at: i64,
 
-    /// Initialize the Seek protocol - Step 3: Create singularity vault + set treasury
-    /// Must be called after initialize_house_vault()
-    pub fn initialize_singularity_vault(ctx: Context<InitializeSingularityVault>) -> Result<()> {
-        let global_state = &mut ctx.accounts.global_state;
-        global_state.singularity_vault = ctx.accounts.singularity_vault.key();
-        global_state.protocol_treasury = ctx.accounts.protocol_treasury.key();
+    /// The pre-published commitments, hash(mission_id || salt) each
+    pub commitments: Vec<[u8; 32]>,
 
-        msg!("Singularity vault initialized: {}", global_state.singularity_vault);
-        msg!("Protocol treasury set: {}", global_state.protocol_treasury);
-        Ok(())
+    /// Bit i set = commitments[i] has been drawn by a bounty
+    pub consumed: u64,
+
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl CommitmentPool {
+    /// Account size: 8 (discriminator) + 8 (pool_id) + 8 (published_at)
+    /// + 4 + 32*MAX_POOL_COMMITMENTS (commitments) + 8 (consumed)
+    /// + 1 (bump) = 2085.
+    pub const SIZE: usize = 8 + 8 + 8 + 4 + 32 * MAX_POOL_COMMITMENTS + 8 + 1;
+
+    /// Mark `commitment` drawn and return its index. Fails if it was never
+    /// published here or was already drawn.
+    pub fn draw(&mut self, commitment: &[u8; 32]) -> Result<u8> {
+        let index = self
+            .commitments
+            .iter()
+            .position(|c| c == commitment)
+            .ok_or(SeekError::CommitmentNotInPool)?;
+        let bit = 1u64 << index;
+        require!(self.consumed & bit == 0, SeekError::CommitmentAlreadyDrawn);
+        self.consumed |= bit;
+        u8::try_from(index).map_err(|_| SeekError::MathOverflow.into())
     }
+}
 
-    /// Accept a bounty - player submits their entry and starts the hunt.
-    /// entry_amount must be exactly TIER_1_ENTRY / TIER_2_ENTRY / TIER_3_ENTRY
-    /// (1000 / 2000 / 3000 SKR in base units — multiplier depends on SKR_DECIMALS).
-    /// mission_commitment is hash(mission_id || salt) for commit-reveal.
-    /// timestamp must be within 60 seconds of current time (for PDA derivation).
-    pub fn accept_bounty(
-        ctx: Context<AcceptBounty>,
-        entry_amount: u64,
-        timestamp: i64,
-        mission_commitment: [u8; 32],
-    ) -> Result<()> {
-        // Validate entry amount and get tier
-        let tier = validate_entry_amount(entry_amount)?;
+/// Per-player lifetime totals, keyed by player. Created on the player's
+/// first accept_bounty and updated wherever SKR moves between the player and
+/// the house, so `total_won - total_wagered` is the player's net result.
+#[account]
+pub struct PlayerStats {
+    /// Player these totals belong to
+    pub player: Pubkey,
 
-        // Get current timestamp and validate provided timestamp is recent
-        let clock = Clock::get()?;
-        let current_time = clock.unix_timestamp;
+    /// Entries, dispute stakes and dispute fees paid in, net of refunds
+    pub total_wagered: u64,
 
-        // Timestamp must be within 60 seconds of current time
-        require!(
-            (current_time - timestamp).abs() <= 60,
-            SeekError::InvalidTimestamp
-        );
+    /// Payouts, jackpots and dispute refunds paid back to the player
+    pub total_won: u64,
 
-        // Calculate expiration based on tier
-        let duration = get_tier_duration(tier)?;
-        let expires_at = current_time
-            .checked_add(duration)
-            .ok_or(SeekError::MathOverflow)?;
+    /// Bounties settled as wins (including won disputes)
+    pub wins: u32,
 
-        // Calculate 3x payout (entry back + 2x profit)
-        let payout_amount = entry_amount
-            .checked_mul(3)
-            .ok_or(SeekError::MathOverflow)?;
+    /// Bounties settled as losses
+    pub losses: u32,
 
-        // Initialize bounty account
-        let bounty = &mut ctx.accounts.bounty;
-        bounty.player = ctx.accounts.player.key();
-        bounty.global_state = ctx.accounts.global_state.key();
-        bounty.entry_amount = entry_amount;
-        bounty.payout_amount = payout_amount;
-        bounty.created_at = current_time;
-        bounty.expires_at = expires_at;
-        bounty.status = BountyStatus::Pending;
-        bounty.tier = tier;
-        bounty.singularity_won = false;
-        bounty.bump = ctx.bumps.bounty;
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 
-        // Commit-reveal: store mission commitment hash
-        bounty.mission_commitment = mission_commitment;
-        bounty.mission_id = [0u8; 32];
-        bounty.mission_revealed = false;
+    /// Entries wagered in the current daily window, and when it opened
+    /// (checked against GlobalState.daily_wager_cap)
+    pub wagered_today: u64,
+    pub day_start: i64,
 
-        // Optimistic resolution: initialize to zero
-        bounty.resolved_at = 0;
-        bounty.challenge_ends_at = 0;
-        bounty.proposed_win = false;
+    /// When this player's last singularity roll happened (0 = never),
+    /// checked against GlobalState.jackpot_cooldown
+    pub last_jackpot_roll_at: i64,
 
-        // Dispute: initialize to false
-        bounty.is_disputed = false;
-        bounty.dispute_stake = 0;
-        bounty.disputed_at = 0;
+    /// Consecutive losses since the last win, read by
+    /// GlobalState::jackpot_odds for the loss-streak boost
+    pub loss_streak: u16,
+}
 
-        // Transfer entry from player to house vault
-        let transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.player_token_account.to_account_info(),
-                to: ctx.accounts.house_vault.to_account_info(),
-                authority: ctx.accounts.player.to_account_info(),
-            },
-        );
-        token::transfer(transfer_ctx, entry_amount)?;
+impl PlayerStats {
+    /// Account size: 8 (discriminator) + 32 (player) + 8*2 (total_wagered,
+    /// total_won) + 4*2 (wins, losses) + 1 (bump) + 8*2 (wagered_today,
+    /// day_start) + 8 (last_jackpot_roll_at) + 2 (loss_streak) = 91.
+    pub const SIZE: usize = 8 + 32 + 8 * 2 + 4 * 2 + 1 + 8 * 2 + 8 + 2;
+
+    /// Whether a win at `now` may roll the jackpot under `cooldown`; if so,
+    /// stamps the roll time. `cooldown = 0` always allows.
+    pub fn take_jackpot_roll(&mut self, cooldown: i64, now: i64) -> Result<bool> {
+        if cooldown > 0 && self.last_jackpot_roll_at != 0 {
+            let ready_at = self
+                .last_jackpot_roll_at
+                .checked_add(cooldown)
+                .ok_or(SeekError::MathOverflow)?;
+            if now < ready_at {
+                return Ok(false);
+            }
+        }
+        self.last_jackpot_roll_at = now;
+        Ok(true)
+    }
 
-        // Update global state
-        let global_state = &mut ctx.accounts.global_state;
-        global_state.house_fund_balance = global_state
-            .house_fund_balance
-            .checked_add(entry_amount)
+    /// Count SKR the player put at stake.
+    pub fn record_wager(&mut self, amount: u64) -> Result<()> {
+        self.total_wagered = self
+            .total_wagered
+            .checked_add(amount)
             .ok_or(SeekError::MathOverflow)?;
-        global_state.total_bounties_created = global_state
-            .total_bounties_created
-            .checked_add(1)
+        Ok(())
+    }
+
+    /// Count an entry against the daily wager cap, rolling the window over
+    /// once WAGER_CAP_WINDOW has passed. `cap = 0` only tracks.
+    pub fn record_daily_wager(&mut self, amount: u64, cap: u64, now: i64) -> Result<()> {
+        let window_end = self
+            .day_start
+            .checked_add(WAGER_CAP_WINDOW)
             .ok_or(SeekError::MathOverflow)?;
+        if now >= window_end {
+            self.day_start = now;
+            self.wagered_today = 0;
+        }
 
-        // Emit event
-        emit!(BountyAccepted {
-            player: bounty.player,
-            bounty: bounty.key(),
-            entry_amount,
-            tier,
-            expires_at,
-        });
+        let wagered = self
+            .wagered_today
+            .checked_add(amount)
+            .ok_or(SeekError::MathOverflow)?;
+        require!(cap == 0 || wagered <= cap, SeekError::DailyLimitReached);
+        self.wagered_today = wagered;
+        Ok(())
+    }
 
-        msg!("Bounty accepted!");
-        msg!("Player: {}", bounty.player);
-        msg!("Entry: {} SKR (Tier {})", entry_amount / DECIMALS_MULTIPLIER, tier);
-        msg!("Expires at: {}", expires_at);
+    /// Take a refunded stake back out of the wagered total.
+    pub fn record_refund(&mut self, amount: u64) {
+        self.total_wagered = self.total_wagered.saturating_sub(amount);
+    }
 
+    /// Count a settled bounty and what it paid back to the player.
+    pub fn record_result(&mut self, won: bool, paid: u64) -> Result<()> {
+        if won {
+            self.wins = self.wins.checked_add(1).ok_or(SeekError::MathOverflow)?;
+            self.loss_streak = 0;
+        } else {
+            self.losses = self.losses.checked_add(1).ok_or(SeekError::MathOverflow)?;
+            self.loss_streak = self.loss_streak.saturating_add(1);
+        }
+        self.total_won = self
+            .total_won
+            .checked_add(paid)
+            .ok_or(SeekError::MathOverflow)?;
         Ok(())
     }
+}
 
-    /// Reveal the mission - backend reveals mission_id and salt after player submits photo
-    /// Verifies hash(mission_id || salt) matches the original commitment
-    pub fn reveal_mission(
-        ctx: Context<RevealMission>,
-        mission_id: [u8; 32],
-        salt: [u8; 32],
-    ) -> Result<()> {
-        let bounty = &mut ctx.accounts.bounty;
+// ============================================================================
+// EVENTS - Emitted for frontend and indexer tracking
+// ============================================================================
 
-        // Verify bounty is pending (photo submitted but not resolved)
-        require!(
-            bounty.status == BountyStatus::Pending || bounty.status == BountyStatus::Submitted,
-            SeekError::BountyAlreadyResolved
-        );
+/// Emitted when a player accepts a bounty
+#[event]
+pub struct BountyAccepted {
+    pub player: Pubkey,
+    pub bounty: Pubkey,
+    pub entry_amount: u64,
+    pub tier: u8,
+    pub expires_at: i64,
+    /// Practice hunt (no tokens at stake)
+    pub practice: bool,
+}
 
-        // Verify mission hasn't already been revealed
-        require!(!bounty.mission_revealed, SeekError::MissionAlreadyRevealed);
+/// Emitted when a bounty is won
+#[event]
+pub struct BountyWon {
+    pub player: Pubkey,
+    pub bounty: Pubkey,
+    pub payout: u64,
+    pub singularity_won: bool,
+    pub singularity_amount: u64,
+    /// Portion of the payout burned under `win_burn_bps` (0 when disabled)
+    pub burned: u64,
+    /// Portion of the payout routed into the singularity pool under
+    /// `win_jackpot_bps` (0 when disabled)
+    pub jackpot_contribution: u64,
+    /// Portion of the payout escrowed under `loyalty_rebate_bps` (0 when
+    /// disabled or settled without the player's escrow)
+    pub loyalty_rebate: u64,
+}
 
-        // Compute hash(mission_id || salt) and verify against commitment
-        // Concatenate mission_id and salt, then hash
-        let mut input = [0u8; 64];
-        input[..32].copy_from_slice(&mission_id);
-        input[32..].copy_from_slice(&salt);
+/// Emitted at the end of every instruction that moves SKR in or out of the
+/// protocol vaults, with the tracked balances after it
+#[event]
+pub struct BalancesSnapshot {
+    pub house_fund_balance: u64,
+    pub singularity_balance: u64,
+    pub total_burned: u64,
+    pub at: i64,
+}
 
-        // Use Solana's SHA256 hash function
-        let computed_hash = anchor_lang::solana_program::hash::hash(&input);
+/// Emitted when a player claims their escrowed loyalty rebates
+#[event]
+pub struct LoyaltyClaimed {
+    pub player: Pubkey,
+    pub amount: u64,
+}
 
-        require!(
-            computed_hash.to_bytes() == bounty.mission_commitment,
-            SeekError::InvalidMissionHash
-        );
+/// Emitted for every singularity jackpot roll on the win path. `won` is
+/// true only if `roll_value == 0` and the pool was non-empty.
+#[event]
+pub struct SingularityRoll {
+    pub bounty: Pubkey,
+    pub roll_value: u64,
+    pub odds: u64,
+    pub won: bool,
+    /// Jackpot paid to the player (0 unless `won`)
+    pub player_amount: u64,
+    /// Jackpot slice routed to the charity vault
+    pub charity_amount: u64,
+}
 
-        // Store revealed mission
-        bounty.mission_id = mission_id;
-        bounty.mission_revealed = true;
+/// Emitted when a bounty is lost
+#[event]
+pub struct BountyLost {
+    pub player: Pubkey,
+    pub bounty: Pubkey,
+    pub entry_amount: u64,
+    pub house_share: u64,
+    pub singularity_share: u64,
+    pub protocol_share: u64,
+    /// Recorded by mark_timeout rather than a proposed resolution
+    pub timeout: bool,
+}
 
-        // Update status to Submitted
-        bounty.status = BountyStatus::Submitted;
+/// Emitted when house is funded
+#[event]
+pub struct HouseFunded {
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub new_balance: u64,
+}
 
-        emit!(MissionRevealed {
-            bounty: bounty.key(),
-            mission_id,
-            commitment_verified: true,
-        });
+/// Emitted when a win tops up the house from the treasury (auto-fund)
+#[event]
+pub struct HouseAutoFunded {
+    pub amount: u64,
+    pub new_balance: u64,
+}
 
-        msg!("Mission revealed and verified!");
+/// Emitted when the authority tops up the singularity pool directly
+#[event]
+pub struct SingularityFunded {
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub new_balance: u64,
+}
 
-        Ok(())
-    }
+/// Emitted when a player records an on-time photo submission
+#[event]
+pub struct PhotoSubmitted {
+    pub bounty: Pubkey,
+    pub player: Pubkey,
+    pub submitted_at: i64,
+    pub photo_hash: [u8; 32],
+}
 
-    /// Propose bounty resolution (OPTIMISTIC) - starts challenge period
-    /// Result is NOT final until challenge period ends
-    /// success = true: proposes win
-    /// success = false: proposes loss
-    pub fn propose_resolution(ctx: Context<ProposeResolution>, success: bool) -> Result<()> {
-        let bounty = &mut ctx.accounts.bounty;
+/// Emitted when SKR is burned from the protocol treasury
+#[event]
+pub struct TreasuryBurned {
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub total_burned: u64,
+}
 
-        // Verify mission was revealed (commit-reveal completed)
-        require!(bounty.mission_revealed, SeekError::MissionNotRevealed);
+/// Emitted when the cold authority triggers the protocol kill switch
+#[event]
+pub struct ProtocolShutdown {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
 
-        // Verify bounty is in Submitted state
-        require!(
-            bounty.status == BountyStatus::Submitted,
-            SeekError::BountyAlreadyResolved
-        );
+/// Emitted when a wound-down deployment's GlobalState is closed
+#[event]
+pub struct ProtocolClosed {
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
 
-        // Get current time
-        let clock = Clock::get()?;
-        let current_time = clock.unix_timestamp;
+/// Emitted when a wound-down deployment's vaults are swept and closed
+#[event]
+pub struct VaultsSwept {
+    pub authority: Pubkey,
+    pub house_amount: u64,
+    pub singularity_amount: u64,
+    pub destination: Pubkey,
+}
 
-        // Calculate challenge period end
-        let challenge_ends_at = current_time
-            .checked_add(CHALLENGE_PERIOD)
-            .ok_or(SeekError::MathOverflow)?;
+/// Emitted when the authority corrects outstanding_liability by hand
+#[event]
+pub struct LiabilityAdjusted {
+    pub authority: Pubkey,
+    pub previous: u64,
+    pub new: u64,
+    pub delta: u64,
+    /// true = delta added, false = delta removed
+    pub increase: bool,
+}
 
-        // Set optimistic resolution fields
-        bounty.resolved_at = current_time;
-        bounty.challenge_ends_at = challenge_ends_at;
-        bounty.proposed_win = success;
+/// Emitted when the singularity pool is moved out after shutdown
+#[event]
+pub struct JackpotDistributedOnShutdown {
+    /// `ShutdownJackpotPolicy` code applied
+    pub policy: u8,
+    pub amount: u64,
+    pub destination: Pubkey,
+}
 
-        // Update status to challenge period
-        bounty.status = if success {
-            BountyStatus::ChallengeWon
-        } else {
-            BountyStatus::ChallengeLost
-        };
+/// Emitted when an unclaimed win's payout is escheated
+#[event]
+pub struct UnclaimedJackpotEscheated {
+    pub bounty: Pubkey,
+    pub player: Pubkey,
+    /// `EscheatPolicy` code applied
+    pub policy: u8,
+    pub amount: u64,
+    pub destination: Pubkey,
+}
 
-        emit!(BountyResolutionProposed {
-            bounty: bounty.key(),
-            player: bounty.player,
-            proposed_win: success,
-            challenge_ends_at,
-        });
+/// Emitted when advance_epoch closes an epoch and starts the next
+#[event]
+pub struct EpochAdvanced {
+    /// The new epoch
+    pub epoch: u64,
+    pub started_at: i64,
+    /// Closing house P&L of the epoch that just ended
+    pub house_net_last_epoch: i64,
+    /// Bounties, burns, jackpots and protocol revenue of the epoch that
+    /// just ended
+    pub last_epoch: EpochTotals,
+}
 
-        msg!("Resolution proposed: {} | Challenge ends: {}",
-            if success { "WIN" } else { "LOSS" },
-            challenge_ends_at
-        );
+/// Emitted when the house's net loss for the epoch passes the breaker cap
+#[event]
+pub struct EpochLossCapReached {
+    pub epoch: u64,
+    pub house_net_this_epoch: i64,
+    pub max_epoch_house_loss: u64,
+}
 
-        Ok(())
-    }
+/// Emitted when the backend publishes a batch of mission commitments
+#[event]
+pub struct CommitmentPoolPublished {
+    pub pool: Pubkey,
+    pub pool_id: u64,
+    pub count: u8,
+    pub published_at: i64,
+}
 
-    /// Finalize bounty - called after challenge period ends (if no dispute)
-    /// Actually executes the payout or distribution
-    pub fn finalize_bounty(ctx: Context<FinalizeBounty>) -> Result<()> {
-        let bounty = &mut ctx.accounts.bounty;
-        let global_state = &mut ctx.accounts.global_state;
+/// Emitted when accept_bounty draws its commitment from a pool
+#[event]
+pub struct CommitmentDrawn {
+    pub pool: Pubkey,
+    pub bounty: Pubkey,
+    pub index: u8,
+}
 
-        // Verify bounty is in challenge period
-        require!(
-            bounty.status == BountyStatus::ChallengeWon || bounty.status == BountyStatus::ChallengeLost,
-            SeekError::BountyNotPending
-        );
+/// Emitted when mission is revealed (commit-reveal)
+#[event]
+pub struct MissionRevealed {
+    pub bounty: Pubkey,
+    pub player: Pubkey,
+    pub tier: u8,
+    pub mission_id: [u8; 32],
+    pub commitment_verified: bool,
+}
 
-        // Verify challenge period has ended
-        let clock = Clock::get()?;
-        let current_time = clock.unix_timestamp;
-        require!(
-            current_time >= bounty.challenge_ends_at,
-            SeekError::ChallengePeriodActive
-        );
+/// Emitted when a bounty's geofence is revealed and matches its commitment
+#[event]
+pub struct GeofenceRevealed {
+    pub bounty: Pubkey,
+    pub lat_e6: i32,
+    pub lon_e6: i32,
+    pub radius_m: u32,
+}
 
-        // Verify not disputed
-        require!(!bounty.is_disputed, SeekError::AlreadyDisputed);
+/// Emitted when bounty enters challenge period (optimistic resolution)
+#[event]
+pub struct BountyResolutionProposed {
+    pub bounty: Pubkey,
+    pub player: Pubkey,
+    pub proposed_win: bool,
+    pub challenge_ends_at: i64,
+    /// True when the loss is oracle-attested and can't be disputed
+    pub dispute_disabled: bool,
+}
 
-        let success = bounty.proposed_win;
+/// Emitted when a player waives the challenge period on a proposed win
+#[event]
+pub struct ChallengeWaived {
+    pub bounty: Pubkey,
+    pub player: Pubkey,
+    pub challenge_ends_at: i64,
+}
 
-        if success {
-            // === WIN PATH ===
-            // Check house vault has enough actual tokens for 3x payout
-            // Use actual vault balance (not tracked) to avoid divergence issues
-            require!(
-                ctx.accounts.house_vault.amount >= bounty.payout_amount,
-                SeekError::InsufficientHouseFunds
-            );
+/// Emitted when a bounty is disputed
+#[event]
+pub struct BountyDisputed {
+    pub bounty: Pubkey,
+    pub player: Pubkey,
+    /// Refundable stake held in the house
+    pub dispute_stake: u64,
+    /// Non-refundable fee sent to the protocol treasury
+    pub dispute_fee: u64,
+}
 
-            // Transfer 3x entry to player (entry back + 2x profit)
-            let seeds = &[b"global_state".as_ref(), &[global_state.bump]];
-            let signer_seeds = &[&seeds[..]];
+/// Emitted when another player adds stake to an open dispute
+#[event]
+pub struct DisputeJoined {
+    pub bounty: Pubkey,
+    pub disputer: Pubkey,
+    pub stake: u64,
+    /// Stake across the player and every co-disputer so far
+    pub total_stake: u64,
+}
 
-            let transfer_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.house_vault.to_account_info(),
-                    to: ctx.accounts.player_token_account.to_account_info(),
-                    authority: global_state.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(transfer_ctx, bounty.payout_amount)?;
+/// Emitted for each co-disputer when a joined dispute is resolved
+#[event]
+pub struct DisputeShareSettled {
+    pub bounty: Pubkey,
+    pub disputer: Pubkey,
+    /// Stake + bonus slice on a win; the stake refund share on a loss
+    pub payout: u64,
+}
 
-            // Update house balance (subtract 3x, but we received 1x, so net -2x)
-            // Use saturating_sub: tracked balance may be lower than actual vault balance
-            global_state.house_fund_balance = global_state
-                .house_fund_balance
-                .saturating_sub(bounty.payout_amount);
-
-            // === SINGULARITY JACKPOT ROLL ===
-            // Entropy sources (stacked by hardness for a grinding attacker):
-            //   1. bounty.mission_commitment  - 32-byte hash(mission_id || salt) fixed at accept_bounty
-            //   2. bounty.key()               - PDA derived from player + timestamp
-            //   3. clock.slot                 - current slot (manipulable by slot leader)
-            //   4. clock.unix_timestamp       - best-effort wall clock
-            //
-            // A slot leader at finalize time can still grind by choosing which finalize_bounty
-            // transactions to include in their slot, but they must match both a specific
-            // mission_commitment AND a specific bounty PDA, which sharply limits the attack's
-            // expected value unless the jackpot pool dwarfs a slot's block production revenue.
-            //
-            // TODO (post-launch): migrate to Switchboard On-Demand VRF once the Singularity
-            // jackpot pool exceeds ~$50k USD equivalent — grinding ROI threshold. See
-            // tasks/audit-2026-04-22.md section C-2 and task #3.
-            let slot_bytes = clock.slot.to_le_bytes();
-            let ts_bytes = (clock.unix_timestamp as u64).to_le_bytes();
-            let bounty_key_bytes = bounty.key().to_bytes();
-
-            let mut seed = Vec::with_capacity(32 + 32 + 8 + 8);
-            seed.extend_from_slice(&bounty.mission_commitment);
-            seed.extend_from_slice(&bounty_key_bytes);
-            seed.extend_from_slice(&slot_bytes);
-            seed.extend_from_slice(&ts_bytes);
-
-            let digest = anchor_lang::solana_program::hash::hash(&seed);
-            let mut rng_u64 = [0u8; 8];
-            rng_u64.copy_from_slice(&digest.to_bytes()[..8]);
-            let roll = u64::from_le_bytes(rng_u64)
-                .checked_rem(SINGULARITY_ODDS)
-                .ok_or(SeekError::MathOverflow)?;
+/// Emitted when a dispute is resolved
+#[event]
+pub struct DisputeResolved {
+    pub bounty: Pubkey,
+    pub player: Pubkey,
+    pub player_won_dispute: bool,
+    pub stake_returned: bool,
+    /// Stake handed back to the player (all of it on a win, the
+    /// dispute_loss_refund_bps slice on a loss)
+    pub stake_refund: u64,
+    /// `DisputeReason` code recorded by the authority
+    pub reason_code: u8,
+    /// `ResolutionSource` code (always DisputeArbiter)
+    pub resolution_source: u8,
+}
 
-            // Track jackpot amount for event
-            let mut jackpot_won: u64 = 0;
-
-            if roll == 0 && global_state.singularity_balance > 0 {
-                // JACKPOT! Transfer entire singularity pool to player
-                jackpot_won = global_state.singularity_balance;
-
-                let jackpot_ctx = CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    Transfer {
-                        from: ctx.accounts.singularity_vault.to_account_info(),
-                        to: ctx.accounts.player_token_account.to_account_info(),
-                        authority: global_state.to_account_info(),
-                    },
-                    signer_seeds,
-                );
-                token::transfer(jackpot_ctx, jackpot_won)?;
+/// Emitted when bounty is finalized after challenge period
+#[event]
+pub struct BountyFinalized {
+    pub bounty: Pubkey,
+    pub player: Pubkey,
+    pub final_status: u8, // 0 = lost, 1 = won
+    /// Practice hunt: nothing was paid or distributed
+    pub practice: bool,
+    /// `ResolutionSource` code of whoever decided the result
+    pub resolution_source: u8,
+}
 
-                bounty.singularity_won = true;
-                global_state.singularity_balance = 0;
-                global_state.total_singularity_wins = global_state
-                    .total_singularity_wins
-                    .checked_add(1)
-                    .ok_or(SeekError::MathOverflow)?;
+/// Emitted when finalize_my_wins settles a player's batch of wins
+#[event]
+pub struct WinsClaimed {
+    pub player: Pubkey,
+    pub bounties_finalized: u32,
+    pub total_payout: u64,
+}
 
-                msg!("SINGULARITY WON! Jackpot: {} SKR", jackpot_won / DECIMALS_MULTIPLIER);
-            }
+/// Emitted when the protocol treasury recipient is rotated by the cold authority.
+#[event]
+pub struct TreasuryRotated {
+    pub authority: Pubkey,
+    pub old_treasury: Pubkey,
+    pub new_treasury: Pubkey,
+}
 
-            bounty.status = BountyStatus::Won;
-            global_state.total_bounties_won = global_state
-                .total_bounties_won
-                .checked_add(1)
-                .ok_or(SeekError::MathOverflow)?;
+/// Emitted when a bounty is cancelled by the player (early or after expiry)
+#[event]
+pub struct BountyCancelled {
+    pub player: Pubkey,
+    pub bounty: Pubkey,
+    pub refund_amount: u64,
+    pub fee: u64,
+}
 
-            // Emit win event
-            emit!(BountyWon {
-                player: bounty.player,
-                bounty: bounty.key(),
-                payout: bounty.payout_amount,
-                singularity_won: bounty.singularity_won,
-                singularity_amount: jackpot_won,
-            });
+/// Emitted when a player undoes a cancel within REOPEN_GRACE
+#[event]
+pub struct BountyReopened {
+    pub player: Pubkey,
+    pub bounty: Pubkey,
+    /// Entry re-deposited (the cancel fee stays with the house)
+    pub redeposit: u64,
+}
 
-            msg!("Bounty WON! Payout: {} SKR", bounty.payout_amount / DECIMALS_MULTIPLIER);
-        } else {
-            // === LOSS PATH ===
-            // Distribute entry: 70% house, 20% singularity, 10% protocol
-            let entry = bounty.entry_amount;
+/// Emitted when a player's funds come out of a bounty stuck behind a long
+/// pause, committed to a blocked mission, or never proposed after reveal
+#[event]
+pub struct BountyReclaimed {
+    pub player: Pubkey,
+    pub bounty: Pubkey,
+    pub refund_amount: u64,
+}
 
-            // Calculate shares (using basis points for precision)
-            let house_share = entry
-                .checked_mul(HOUSE_SHARE_BPS)
-                .ok_or(SeekError::MathOverflow)?
-                .checked_div(10000)
-                .ok_or(SeekError::MathOverflow)?;
+/// Emitted when authority is transferred
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
 
-            let singularity_share = entry
-                .checked_mul(SINGULARITY_SHARE_BPS)
-                .ok_or(SeekError::MathOverflow)?
-                .checked_div(10000)
-                .ok_or(SeekError::MathOverflow)?;
+// ============================================================================
+// SETTLEMENT HELPERS - Shared by single and batch instructions
+// ============================================================================
 
-            let protocol_share = entry
-                .checked_mul(PROTOCOL_SHARE_BPS)
-                .ok_or(SeekError::MathOverflow)?
-                .checked_div(10000)
-                .ok_or(SeekError::MathOverflow)?;
+/// Accounts needed to move SKR out of the PDA-owned vaults.
+struct VaultAccounts<'info> {
+    global_state: AccountInfo<'info>,
+    house_vault: AccountInfo<'info>,
+    singularity_vault: AccountInfo<'info>,
+    protocol_treasury: AccountInfo<'info>,
+    /// Only passed by instructions that can roll the jackpot
+    charity_vault: Option<AccountInfo<'info>>,
+    /// Configured protocol fee destinations, in GlobalState order (empty
+    /// when the protocol share goes to protocol_treasury alone)
+    protocol_destinations: Vec<AccountInfo<'info>>,
+    token_program: AccountInfo<'info>,
+    /// Stored global_state bump, read once when the bundle is built so batch
+    /// handlers sign every item's transfers without re-deriving the PDA.
+    global_state_bump: u8,
+}
 
-            let seeds = &[b"global_state".as_ref(), &[global_state.bump]];
-            let signer_seeds = &[&seeds[..]];
+impl<'info> VaultAccounts<'info> {
+    /// Burn `amount` from the house vault, signed by the global_state PDA.
+    fn burn(&self, mint: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        let seeds = &[b"global_state".as_ref(), &[self.global_state_bump]];
+        let signer_seeds = &[&seeds[..]];
 
-            // 70% stays in house vault (already there from accept_bounty)
-            // Just update the tracked balance
-            // We need to subtract the full entry first, then add back the house share
-            global_state.house_fund_balance = global_state
-                .house_fund_balance
-                .checked_sub(entry)
-                .ok_or(SeekError::MathOverflow)?
-                .checked_add(house_share)
-                .ok_or(SeekError::MathOverflow)?;
+        let burn_ctx = CpiContext::new_with_signer(
+            self.token_program.clone(),
+            Burn {
+                mint: mint.clone(),
+                from: self.house_vault.clone(),
+                authority: self.global_state.clone(),
+            },
+            signer_seeds,
+        );
+        token::burn(burn_ctx, amount)
+    }
 
-            // 20% transfer to singularity vault
-            let singularity_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.house_vault.to_account_info(),
-                    to: ctx.accounts.singularity_vault.to_account_info(),
-                    authority: global_state.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(singularity_ctx, singularity_share)?;
+    /// Transfer `amount` out of a vault, signed by the global_state PDA.
+    fn transfer(
+        &self,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let seeds = &[b"global_state".as_ref(), &[self.global_state_bump]];
+        let signer_seeds = &[&seeds[..]];
 
-            global_state.singularity_balance = global_state
-                .singularity_balance
-                .checked_add(singularity_share)
-                .ok_or(SeekError::MathOverflow)?;
+        let transfer_ctx = CpiContext::new_with_signer(
+            self.token_program.clone(),
+            Transfer {
+                from: from.clone(),
+                to: to.clone(),
+                authority: self.global_state.clone(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)
+    }
 
-            // 10% transfer to protocol treasury
-            let protocol_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.house_vault.to_account_info(),
-                    to: ctx.accounts.protocol_treasury.to_account_info(),
-                    authority: global_state.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(protocol_ctx, protocol_share)?;
+    /// Close an emptied vault token account, signed by the global_state PDA,
+    /// sending its rent to `destination`.
+    fn close(&self, vault: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+        let seeds = &[b"global_state".as_ref(), &[self.global_state_bump]];
+        let signer_seeds = &[&seeds[..]];
 
-            bounty.status = BountyStatus::Lost;
-            global_state.total_bounties_lost = global_state
-                .total_bounties_lost
-                .checked_add(1)
-                .ok_or(SeekError::MathOverflow)?;
+        let close_ctx = CpiContext::new_with_signer(
+            self.token_program.clone(),
+            CloseAccount {
+                account: vault.clone(),
+                destination: destination.clone(),
+                authority: self.global_state.clone(),
+            },
+            signer_seeds,
+        );
+        token::close_account(close_ctx)
+    }
 
-            // Emit loss event
-            emit!(BountyLost {
-                player: bounty.player,
-                bounty: bounty.key(),
-                entry_amount: entry,
-                house_share,
-                singularity_share,
-                protocol_share,
-            });
+    /// Pay a loss's protocol share out of the house vault: all of it to
+    /// protocol_treasury, or split by weight across the configured
+    /// destinations with the last one taking the rounding remainder.
+    fn pay_protocol_share(&self, global_state: &GlobalState, amount: u64) -> Result<()> {
+        let Some(last) = self.protocol_destinations.len().checked_sub(1) else {
+            return self.transfer(&self.house_vault, &self.protocol_treasury, amount);
+        };
 
-            msg!("Bounty LOST. Distribution:");
-            msg!("  House: {} SKR (70%)", house_share / DECIMALS_MULTIPLIER);
-            msg!("  Singularity: {} SKR (20%)", singularity_share / DECIMALS_MULTIPLIER);
-            msg!("  Protocol: {} SKR (10%)", protocol_share / DECIMALS_MULTIPLIER);
+        let mut left = amount;
+        for (i, destination) in self.protocol_destinations.iter().enumerate() {
+            let part = if i == last {
+                left
+            } else {
+                bps_of(amount, u64::from(global_state.protocol_weights[i]))?
+            };
+            left = left.checked_sub(part).ok_or(SeekError::MathOverflow)?;
+            if part > 0 {
+                self.transfer(&self.house_vault, destination, part)?;
+            }
         }
-
-        // Emit finalized event
-        emit!(BountyFinalized {
-            bounty: bounty.key(),
-            player: bounty.player,
-            final_status: if success { 1 } else { 0 },
-        });
-
         Ok(())
     }
+}
 
-    /// Fund the house vault - authority deposits SKR for player payouts
-    pub fn fund_house(ctx: Context<FundHouse>, amount: u64) -> Result<()> {
-        // Transfer from authority to house vault
-        let transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.authority_token_account.to_account_info(),
-                to: ctx.accounts.house_vault.to_account_info(),
-                authority: ctx.accounts.authority.to_account_info(),
-            },
+/// Split the configured protocol fee destinations off the front of
+/// `remaining_accounts`, checking they match GlobalState in order. Returns
+/// the destinations and the accounts after them (batch items).
+fn take_protocol_destinations<'a, 'info>(
+    global_state: &GlobalState,
+    remaining: &'a [AccountInfo<'info>],
+) -> Result<(Vec<AccountInfo<'info>>, &'a [AccountInfo<'info>])> {
+    let count = global_state.protocol_destination_count();
+    require!(
+        remaining.len() >= count,
+        SeekError::ProtocolDestinationsMismatch
+    );
+    let (destinations, rest) = remaining.split_at(count);
+    for (info, expected) in destinations.iter().zip(global_state.protocol_destinations.iter()) {
+        require!(
+            info.key() == *expected && info.is_writable,
+            SeekError::ProtocolDestinationsMismatch
         );
-        token::transfer(transfer_ctx, amount)?;
+    }
+    Ok((destinations.to_vec(), rest))
+}
 
-        // Update tracked balance
-        let global_state = &mut ctx.accounts.global_state;
-        global_state.house_fund_balance = global_state
-            .house_fund_balance
-            .checked_add(amount)
-            .ok_or(SeekError::MathOverflow)?;
+/// Load a batch item's PlayerStats and check it is `player`'s.
+fn load_player_stats<'info>(
+    info: &'info AccountInfo<'info>,
+    player: &Pubkey,
+) -> Result<Account<'info, PlayerStats>> {
+    require!(info.is_writable, anchor_lang::error::ErrorCode::ConstraintMut);
+    let player_stats: Account<'info, PlayerStats> = Account::try_from(info)?;
+    require!(player_stats.player == *player, SeekError::Unauthorized);
+    Ok(player_stats)
+}
 
-        // Emit event
-        emit!(HouseFunded {
-            authority: ctx.accounts.authority.key(),
-            amount,
-            new_balance: global_state.house_fund_balance,
-        });
+/// Ordering key for an accept: the first 8 bytes of SHA-256(most recent
+/// slot hash || bounty address), plus the slot of that hash. Accepts in the
+/// same slot share the hash, so the bounty address breaks the tie, and
+/// anyone can recompute the key from the SlotHashes history. The sysvar is
+/// read raw: a u64 entry count, then (slot: u64, hash: [u8; 32]) entries,
+/// newest first.
+fn accept_order_key(slot_hashes: &AccountInfo, bounty_key: &Pubkey) -> Result<(u64, u64)> {
+    let data = slot_hashes.try_borrow_data()?;
+    require!(data.len() >= 8 + 8 + 32, SeekError::SlotHashesUnavailable);
+
+    let mut slot = [0u8; 8];
+    slot.copy_from_slice(&data[8..16]);
+    let digest = anchor_lang::solana_program::hash::hashv(&[&data[16..48], bounty_key.as_ref()]);
+    let mut key = [0u8; 8];
+    key.copy_from_slice(&digest.to_bytes()[..8]);
+
+    Ok((u64::from_le_bytes(slot), u64::from_le_bytes(key)))
+}
 
-        msg!("House funded with {} SKR", amount / DECIMALS_MULTIPLIER);
-        msg!("New balance: {} SKR", global_state.house_fund_balance / DECIMALS_MULTIPLIER);
+/// Roll the singularity jackpot for a bounty that just won and, on a hit,
+/// pay the whole pool to the player. Emits `SingularityRoll` either way.
+/// Hits 1 in `odds` (see `GlobalState::jackpot_odds`). Returns the jackpot
+/// amount paid (0 on a miss or an empty pool).
+fn roll_singularity<'info>(
+    global_state: &mut GlobalState,
+    bounty: &mut Bounty,
+    bounty_key: Pubkey,
+    vaults: &VaultAccounts<'info>,
+    player_token_account: &AccountInfo<'info>,
+    clock: &Clock,
+    odds: u64,
+) -> Result<u64> {
+    // Past the epoch's jackpot budget a win pays its payout only
+    if global_state.jackpot_cap_reached() {
+        msg!("Epoch jackpot cap reached: no singularity roll");
+        return Ok(0);
+    }
 
-        Ok(())
+    // Nor is a pool too small to be worth the roll rolled for
+    if global_state.singularity_balance < global_state.min_jackpot_roll_balance {
+        msg!("Singularity pool below roll minimum: no singularity roll");
+        return Ok(0);
     }
 
-    /// Dispute a bounty result - player stakes additional SKR to challenge
-    /// Can only dispute LOSS results during challenge period
-    pub fn dispute_bounty(ctx: Context<DisputeBounty>) -> Result<()> {
-        let bounty = &mut ctx.accounts.bounty;
-        let clock = Clock::get()?;
-        let current_time = clock.unix_timestamp;
+    // Entropy sources (stacked by hardness for a grinding attacker):
+    //   1. bounty.mission_commitment  - 32-byte hash(mission_id || salt) fixed at accept_bounty
+    //   2. bounty.key()               - PDA derived from player + timestamp
+    //   3. clock.slot                 - current slot (manipulable by slot leader)
+    //   4. clock.unix_timestamp       - best-effort wall clock
+    //
+    // A slot leader at finalize time can still grind by choosing which finalize_bounty
+    // transactions to include in their slot, but they must match both a specific
+    // mission_commitment AND a specific bounty PDA, which sharply limits the attack's
+    // expected value unless the jackpot pool dwarfs a slot's block production revenue.
+    //
+    // TODO (post-launch): migrate to Switchboard On-Demand VRF once the Singularity
+    // jackpot pool exceeds ~$50k USD equivalent — grinding ROI threshold. See
+    // tasks/audit-2026-04-22.md section C-2 and task #3.
+    //
+    // Slot and timestamp are hashed side by side as fixed-width bytes, never
+    // summed, so extreme values can't overflow. A negative timestamp would
+    // wrap silently under an `as u64` cast; it's rejected instead.
+    let slot_bytes = clock.slot.to_le_bytes();
+    let ts_bytes = u64::try_from(clock.unix_timestamp)
+        .map_err(|_| SeekError::InvalidTimestamp)?
+        .to_le_bytes();
+    let bounty_key_bytes = bounty_key.to_bytes();
+
+    let mut seed = Vec::with_capacity(32 + 32 + 8 + 8);
+    seed.extend_from_slice(&bounty.mission_commitment);
+    seed.extend_from_slice(&bounty_key_bytes);
+    seed.extend_from_slice(&slot_bytes);
+    seed.extend_from_slice(&ts_bytes);
+
+    let digest = anchor_lang::solana_program::hash::hash(&seed);
+    let mut rng_u64 = [0u8; 8];
+    rng_u64.copy_from_slice(&digest.to_bytes()[..8]);
+    let roll = u64::from_le_bytes(rng_u64)
+        .checked_rem(odds)
+        .ok_or(SeekError::MathOverflow)?;
+
+    // Track jackpot amount (player's part) and charity slice for events
+    let mut jackpot_won: u64 = 0;
+    let mut charity_amount: u64 = 0;
+
+    if roll == 0 && global_state.singularity_balance > 0 {
+        // JACKPOT! Transfer entire singularity pool to player
+        let pool = global_state.singularity_balance;
+
+        // Optional charity slice comes off the top of the pool
+        charity_amount = bps_of(pool, u64::from(global_state.charity_jackpot_bps))?;
+        if charity_amount > 0 {
+            let charity_vault = vaults
+                .charity_vault
+                .as_ref()
+                .ok_or(SeekError::CharityVaultMissing)?;
+            vaults.transfer(&vaults.singularity_vault, charity_vault, charity_amount)?;
+        }
 
-        // Can only dispute losses (no point disputing wins)
-        require!(
-            bounty.status == BountyStatus::ChallengeLost,
-            SeekError::BountyNotPending
-        );
+        jackpot_won = pool
+            .checked_sub(charity_amount)
+            .ok_or(SeekError::MathOverflow)?;
+        vaults.transfer(&vaults.singularity_vault, player_token_account, jackpot_won)?;
 
-        // Must be within challenge period
-        require!(
-            current_time < bounty.challenge_ends_at,
-            SeekError::ChallengePeriodEnded
-        );
+        bounty.singularity_won = true;
+        global_state.singularity_balance = 0;
+        global_state.singularity_paid_this_epoch = global_state
+            .singularity_paid_this_epoch
+            .checked_add(pool)
+            .ok_or(SeekError::MathOverflow)?;
+        global_state.total_singularity_wins = global_state
+            .total_singularity_wins
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
 
-        // Cannot dispute twice
-        require!(!bounty.is_disputed, SeekError::AlreadyDisputed);
+        msg!("SINGULARITY WON! Jackpot: {} SKR", global_state.whole_skr(jackpot_won));
+    }
 
-        // Calculate dispute stake (50% of original entry)
-        let dispute_stake = bounty.entry_amount
-            .checked_mul(DISPUTE_STAKE_BPS)
-            .ok_or(SeekError::MathOverflow)?
-            .checked_div(10000)
-            .ok_or(SeekError::MathOverflow)?;
+    // Publish every roll, winning or not, so the odds are auditable
+    emit!(SingularityRoll {
+        bounty: bounty_key,
+        roll_value: roll,
+        odds,
+        won: bounty.singularity_won,
+        player_amount: jackpot_won,
+        charity_amount,
+    });
+
+    Ok(jackpot_won)
+}
 
-        // Transfer dispute stake from player to house vault
-        let transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.player_token_account.to_account_info(),
-                to: ctx.accounts.house_vault.to_account_info(),
-                authority: ctx.accounts.player.to_account_info(),
-            },
-        );
-        token::transfer(transfer_ctx, dispute_stake)?;
+/// Add a settlement's house P&L to the current epoch, announcing the
+/// breaker the moment it trips.
+fn record_epoch_house_net(global_state: &mut GlobalState, delta: i64) -> Result<()> {
+    if global_state.record_house_net(delta)? {
+        emit!(EpochLossCapReached {
+            epoch: global_state.epoch,
+            house_net_this_epoch: global_state.house_net_this_epoch,
+            max_epoch_house_loss: global_state.max_epoch_house_loss,
+        });
+        msg!("Epoch house loss cap reached: accepts closed until advance_epoch");
+    }
+    Ok(())
+}
 
-        // Track dispute stake in house balance
-        let global_state = &mut ctx.accounts.global_state;
-        global_state.house_fund_balance = global_state
-            .house_fund_balance
-            .checked_add(dispute_stake)
-            .ok_or(SeekError::MathOverflow)?;
+/// Emit the tracked balances as they stand after a vault-moving
+/// instruction, so indexers can reconcile against a ground-truth stream.
+fn emit_balances_snapshot(global_state: &GlobalState) -> Result<()> {
+    emit!(BalancesSnapshot {
+        house_fund_balance: global_state.house_fund_balance,
+        singularity_balance: global_state.singularity_balance,
+        total_burned: global_state.total_burned,
+        at: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
 
-        // Mark as disputed
-        bounty.is_disputed = true;
-        bounty.dispute_stake = dispute_stake;
-        bounty.disputed_at = current_time;
-        bounty.status = BountyStatus::Disputed;
+/// The player accounts a settlement writes to: lifetime stats, and the
+/// loyalty escrow when the instruction carries it.
+struct PlayerBooks<'a> {
+    stats: &'a mut PlayerStats,
+    /// Batch cranks settle without it; their wins are paid in full
+    loyalty_escrow: Option<&'a mut LoyaltyEscrow>,
+}
 
-        emit!(BountyDisputed {
-            bounty: bounty.key(),
+/// Pay out a proposed win or distribute a proposed loss. Caller has
+/// already checked that the result is final (challenge period over, or
+/// skipped for a fast-resolve tier). `house_vault_amount` is the live vault
+/// balance used for the solvency check on a win. `player_token_account` is
+/// only needed on a win; a loss settles without it. Returns the amount paid
+/// to the player (0 on a loss), not counting an escrowed loyalty rebate.
+fn settle_resolution<'info>(
+    global_state: &mut GlobalState,
+    bounty: &mut Account<'info, Bounty>,
+    house_vault_amount: u64,
+    vaults: &VaultAccounts<'info>,
+    skr_mint: &AccountInfo<'info>,
+    player_token_account: Option<&AccountInfo<'info>>,
+    player: PlayerBooks<'_>,
+) -> Result<u64> {
+    let PlayerBooks { stats: player_stats, loyalty_escrow } = player;
+    // Disputed bounties settle through settle_dispute only, whichever
+    // instruction (or caller) gets here first
+    require!(!bounty.in_dispute(), SeekError::AlreadyDisputed);
+
+    let bounty_key = bounty.key();
+    let success = bounty.proposed_win;
+    let mut paid = 0;
+
+    // Practice hunts only walk the state machine
+    if bounty.practice {
+        bounty.status = if success { BountyStatus::Won } else { BountyStatus::Lost };
+        bounty.finalized_at = Clock::get()?.unix_timestamp;
+        emit!(BountyFinalized {
+            bounty: bounty_key,
             player: bounty.player,
-            dispute_stake,
+            final_status: if success { 1 } else { 0 },
+            practice: true,
+            resolution_source: bounty.resolution_source,
         });
-
-        msg!("Bounty disputed! Stake: {} SKR", dispute_stake / DECIMALS_MULTIPLIER);
-
-        Ok(())
+        msg!("Practice bounty finalized: {}", if success { "WIN" } else { "LOSS" });
+        return Ok(0);
     }
+    retire_bounty(global_state, bounty)?;
 
-    /// Resolve a dispute - authority reviews and decides
-    /// player_wins = true: player gets original entry back + dispute stake
-    /// player_wins = false: dispute stake forfeited, loss stands
-    pub fn resolve_dispute(ctx: Context<ResolveDispute>, player_wins: bool) -> Result<()> {
-        let bounty = &mut ctx.accounts.bounty;
-        let global_state = &mut ctx.accounts.global_state;
+    if success {
+        // === WIN PATH ===
+        let player_token_account =
+            player_token_account.ok_or(SeekError::PlayerTokenAccountRequired)?;
 
-        // Verify bounty is disputed
+        // A low house is topped up from the treasury before paying
+        let house_vault_amount = house_vault_amount
+            .checked_add(auto_fund_house(global_state, vaults)?)
+            .ok_or(SeekError::MathOverflow)?;
+
+        // Check house vault has enough actual tokens for 3x payout
+        // Use actual vault balance (not tracked) to avoid divergence issues,
+        // minus what's still reserved for other prefunded bounties and
+        // players' unclaimed loyalty rebates
         require!(
-            bounty.status == BountyStatus::Disputed,
-            SeekError::NotDisputed
+            global_state.unreserved(house_vault_amount) >= bounty.payout_amount,
+            SeekError::InsufficientHouseFunds
         );
 
-        let seeds = &[b"global_state".as_ref(), &[global_state.bump]];
-        let signer_seeds = &[&seeds[..]];
-
-        if player_wins {
-            // Player wins dispute: refund entry + dispute stake back
-            let total_refund = bounty.entry_amount
-                .checked_add(bounty.dispute_stake)
+        // Optional deflationary burn: a slice of the payout is burned
+        // from the house before the player is paid the rest. With
+        // burning disabled the slice is withheld but stays in the house.
+        let win_burn = bps_of(bounty.payout_amount, u64::from(global_state.win_burn_bps))?;
+        let burned = if global_state.burn_enabled { win_burn } else { 0 };
+        if burned > 0 {
+            vaults.burn(skr_mint, burned)?;
+
+            global_state.total_burned = global_state
+                .total_burned
+                .checked_add(burned)
                 .ok_or(SeekError::MathOverflow)?;
+        }
 
-            // Verify vault has enough actual tokens
-            require!(
-                ctx.accounts.house_vault.amount >= total_refund,
-                SeekError::InsufficientHouseFunds
-            );
-
-            let transfer_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.house_vault.to_account_info(),
-                    to: ctx.accounts.player_token_account.to_account_info(),
-                    authority: global_state.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(transfer_ctx, total_refund)?;
-
-            // Use saturating_sub for tracked balance
-            global_state.house_fund_balance = global_state
-                .house_fund_balance
-                .saturating_sub(total_refund);
+        // Optional jackpot seeding: a slice of the payout moves from the
+        // house to the singularity vault, keeping the pool growing through
+        // win streaks. Moved before the roll, so this win can hit it.
+        let jackpot_contribution =
+            bps_of(bounty.payout_amount, u64::from(global_state.win_jackpot_bps))?;
+        if jackpot_contribution > 0 {
+            vaults.transfer(&vaults.house_vault, &vaults.singularity_vault, jackpot_contribution)?;
 
-            bounty.status = BountyStatus::Won;
-            global_state.total_bounties_won = global_state
-                .total_bounties_won
-                .checked_add(1)
+            global_state.singularity_balance = global_state
+                .singularity_balance
+                .checked_add(jackpot_contribution)
                 .ok_or(SeekError::MathOverflow)?;
+        }
 
-            msg!("Dispute resolved: PLAYER WINS | Refund: {} SKR", total_refund / DECIMALS_MULTIPLIER);
-        } else {
-            // Player loses dispute: stake forfeited, distribute entry (70/20/10)
-            // Dispute stake already tracked in house_fund_balance (from dispute_bounty)
-            // Now distribute the original entry amount
-            let entry = bounty.entry_amount;
+        // Optional loyalty rebate: a slice of the payout stays in the house
+        // vault, owed to the player through their LoyaltyEscrow.
+        let loyalty_rebate = match loyalty_escrow {
+            Some(escrow) => {
+                let rebate =
+                    bps_of(bounty.payout_amount, u64::from(global_state.loyalty_rebate_bps))?;
+                escrow.accrue(rebate)?;
+                global_state.loyalty_escrowed = global_state
+                    .loyalty_escrowed
+                    .checked_add(rebate)
+                    .ok_or(SeekError::MathOverflow)?;
+                rebate
+            }
+            None => 0,
+        };
+        let player_payout = bounty.payout_amount
+            .checked_sub(win_burn)
+            .and_then(|p| p.checked_sub(jackpot_contribution))
+            .and_then(|p| p.checked_sub(loyalty_rebate))
+            .ok_or(SeekError::MathOverflow)?;
 
-            let house_share = entry
-                .checked_mul(HOUSE_SHARE_BPS)
-                .ok_or(SeekError::MathOverflow)?
-                .checked_div(10000)
-                .ok_or(SeekError::MathOverflow)?;
+        // Transfer 3x entry (minus any win burn / jackpot slice / rebate) to player (entry back + 2x profit)
+        vaults.transfer(&vaults.house_vault, player_token_account, player_payout)?;
+        log_compute_units!("settle_resolution: payout transferred");
 
-            let singularity_share = entry
-                .checked_mul(SINGULARITY_SHARE_BPS)
-                .ok_or(SeekError::MathOverflow)?
-                .checked_div(10000)
-                .ok_or(SeekError::MathOverflow)?;
+        // Update house balance (subtract 3x, but we received 1x, so net -2x)
+        // Use saturating_sub: tracked balance may be lower than actual vault balance.
+        // An unburned win-burn slice never left the vault; an escrowed
+        // rebate is the player's, so it leaves the house's books.
+        let house_outflow = player_payout + burned + jackpot_contribution + loyalty_rebate;
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .saturating_sub(house_outflow);
+        let house_loss = i64::try_from(house_outflow.saturating_sub(bounty.entry_amount))
+            .map_err(|_| SeekError::MathOverflow)?;
+        record_epoch_house_net(global_state, -house_loss)?;
+
+        // === SINGULARITY JACKPOT ROLL ===
+        // Skipped while the player is inside their jackpot cooldown
+        // Odds tighten with the player's loss streak going into this win
+        let clock = Clock::get()?;
+        let odds = global_state.jackpot_odds(player_stats.loss_streak)?;
+        let jackpot_won =
+            if player_stats.take_jackpot_roll(global_state.jackpot_cooldown, clock.unix_timestamp)? {
+                roll_singularity(
+                    global_state,
+                    bounty,
+                    bounty_key,
+                    vaults,
+                    player_token_account,
+                    &clock,
+                    odds,
+                )?
+            } else {
+                msg!("Jackpot cooldown: no singularity roll");
+                0
+            };
+        log_compute_units!("settle_resolution: jackpot rolled");
+
+        player_stats.record_result(
+            true,
+            player_payout
+                .checked_add(jackpot_won)
+                .and_then(|p| p.checked_add(loyalty_rebate))
+                .ok_or(SeekError::MathOverflow)?,
+        )?;
+
+        bounty.status = BountyStatus::Won;
+        bounty.finalized_at = clock.unix_timestamp;
+        global_state.total_bounties_won = global_state
+            .total_bounties_won
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
 
-            let protocol_share = entry
-                .checked_mul(PROTOCOL_SHARE_BPS)
-                .ok_or(SeekError::MathOverflow)?
-                .checked_div(10000)
-                .ok_or(SeekError::MathOverflow)?;
+        // Emit win event
+        emit!(BountyWon {
+            player: bounty.player,
+            bounty: bounty_key,
+            payout: player_payout,
+            singularity_won: bounty.singularity_won,
+            singularity_amount: jackpot_won,
+            burned,
+            jackpot_contribution,
+            loyalty_rebate,
+        });
 
-            // 20% to singularity vault
-            let singularity_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.house_vault.to_account_info(),
-                    to: ctx.accounts.singularity_vault.to_account_info(),
-                    authority: global_state.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(singularity_ctx, singularity_share)?;
+        msg!("Bounty WON! Payout: {} SKR", global_state.whole_skr(player_payout));
+        paid = player_payout;
+    } else {
+        // === LOSS PATH ===
+        // Distribute entry: 70% house, 20% singularity, 10% protocol
+        let entry = bounty.entry_amount;
+
+        // Calculate shares (using basis points for precision)
+        let LossShares {
+            house_share,
+            singularity_share,
+            protocol_share,
+            remainder,
+        } = compute_loss_shares(entry)?;
+        let mut house_kept = house_share + remainder;
+
+        // 70% (plus rounding remainder) stays in house vault (already
+        // there from accept_bounty). Just update the tracked balance:
+        // subtract the full entry first, then add back what the house keeps
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .checked_sub(entry)
+            .ok_or(SeekError::MathOverflow)?
+            .checked_add(house_share)
+            .ok_or(SeekError::MathOverflow)?
+            .checked_add(remainder)
+            .ok_or(SeekError::MathOverflow)?;
 
-            global_state.singularity_balance = global_state
-                .singularity_balance
-                .checked_add(singularity_share)
-                .ok_or(SeekError::MathOverflow)?;
+        // 20% transfer to singularity vault
+        vaults.transfer(&vaults.house_vault, &vaults.singularity_vault, singularity_share)?;
 
-            // 10% to protocol treasury
-            let protocol_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.house_vault.to_account_info(),
-                    to: ctx.accounts.protocol_treasury.to_account_info(),
-                    authority: global_state.to_account_info(),
-                },
-                signer_seeds,
-            );
-            token::transfer(protocol_ctx, protocol_share)?;
+        global_state.singularity_balance = global_state
+            .singularity_balance
+            .checked_add(singularity_share)
+            .ok_or(SeekError::MathOverflow)?;
 
-            // Update house balance: subtract entry, add back house_share (net: keep 70% + dispute_stake)
+        // 10% transfer to protocol treasury, unless it compounds into
+        // the house (already in the vault, so only the balance moves)
+        if record_protocol_share(global_state, protocol_share)? {
             global_state.house_fund_balance = global_state
                 .house_fund_balance
-                .saturating_sub(entry)
-                .checked_add(house_share)
+                .checked_add(protocol_share)
                 .ok_or(SeekError::MathOverflow)?;
+            house_kept += protocol_share;
+        } else {
+            vaults.pay_protocol_share(global_state, protocol_share)?;
+        }
+        let house_kept = i64::try_from(house_kept).map_err(|_| SeekError::MathOverflow)?;
+        record_epoch_house_net(global_state, house_kept)?;
+        log_compute_units!("settle_resolution: loss distributed");
 
-            bounty.status = BountyStatus::Lost;
-            global_state.total_bounties_lost = global_state
-                .total_bounties_lost
-                .checked_add(1)
-                .ok_or(SeekError::MathOverflow)?;
+        player_stats.record_result(false, 0)?;
 
-            msg!("Dispute resolved: PLAYER LOSES | Entry distributed 70/20/10, stake forfeited");
-        }
+        bounty.status = BountyStatus::Lost;
+        bounty.finalized_at = Clock::get()?.unix_timestamp;
+        global_state.total_bounties_lost = global_state
+            .total_bounties_lost
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
 
-        emit!(DisputeResolved {
-            bounty: bounty.key(),
+        // Emit loss event
+        emit!(BountyLost {
             player: bounty.player,
-            player_won_dispute: player_wins,
-            stake_returned: player_wins,
+            bounty: bounty_key,
+            entry_amount: entry,
+            house_share,
+            singularity_share,
+            protocol_share,
+            timeout: bounty.timeout,
         });
 
-        Ok(())
+        msg!("Bounty LOST. Distribution:");
+        msg!("  House: {} SKR (70%)", global_state.whole_skr(house_share));
+        msg!("  Singularity: {} SKR (20%)", global_state.whole_skr(singularity_share));
+        msg!("  Protocol: {} SKR (10%)", global_state.whole_skr(protocol_share));
     }
 
-    /// Cancel a bounty - player can reclaim entry after expiry + grace period
-    /// Only works if bounty is still in Pending or Submitted state (not resolved)
-    pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
-        let bounty = &mut ctx.accounts.bounty;
-        let global_state = &mut ctx.accounts.global_state;
-        let clock = Clock::get()?;
-        let current_time = clock.unix_timestamp;
+    // Emit finalized event
+    emit!(BountyFinalized {
+        bounty: bounty_key,
+        player: bounty.player,
+        final_status: if success { 1 } else { 0 },
+        practice: false,
+        resolution_source: bounty.resolution_source,
+    });
 
-        // Only Pending bounties (no photo submitted yet) can be cancelled.
-        // Once a player submits a photo and the backend reveals the mission,
-        // the bounty is in `Submitted` and must flow through propose_resolution
-        // → finalize_bounty (or dispute). Allowing cancel from `Submitted` was
-        // a loss-rate exploit — a backend outage > grace_period would let
-        // every Submitted bounty reclaim entry, draining the house pool.
-        require!(
-            bounty.status == BountyStatus::Pending,
-            SeekError::BountyAlreadyResolved
-        );
+    Ok(paid)
+}
+
+/// Apply the authority's ruling to a `Disputed` bounty. Caller has already
+/// checked the status and that the bounty belongs to `global_state`.
+/// `house_vault_amount` is the live vault balance used for the solvency check.
+fn settle_dispute<'info>(
+    global_state: &mut GlobalState,
+    bounty: &mut Account<'info, Bounty>,
+    ruling: DisputeRuling,
+    house_vault_amount: u64,
+    vaults: &VaultAccounts<'info>,
+    player_token_account: &AccountInfo<'info>,
+    player_stats: &mut PlayerStats,
+) -> Result<()> {
+    let bounty_key = bounty.key();
+    let player_wins = ruling.player_wins;
+    DisputeReason::from_code(ruling.reason_code)?;
+    retire_bounty(global_state, bounty)?;
+    bounty.resolution_source = ResolutionSource::DisputeArbiter as u8;
+    let stake_refund;
+
+    if player_wins {
+        stake_refund = bounty.dispute_stake;
+
+        // Player wins dispute: refund entry + dispute stake back, plus
+        // the player's pro-rata slice of any bonus (all of it unless
+        // others joined the dispute)
+        let total_stake = bounty
+            .dispute_stake
+            .checked_add(bounty.co_dispute_stake)
+            .ok_or(SeekError::MathOverflow)?;
+        let stake_return = dispute_stake_return(
+            bounty.entry_amount,
+            global_state.dispute_win_bonus_bps,
+            bounty.dispute_stake,
+            total_stake,
+        )?;
+        let total_refund = bounty
+            .entry_amount
+            .checked_add(stake_return)
+            .ok_or(SeekError::MathOverflow)?;
 
-        // Must be expired + 1 hour grace period for backend to resolve
-        let grace_period: i64 = 3600; // 1 hour
+        // Verify vault has enough actual tokens, net of other bounties'
+        // reservations (this one's was released by retire_bounty above)
         require!(
-            current_time > bounty.expires_at + grace_period,
-            SeekError::BountyNotExpired
+            global_state.unreserved(house_vault_amount) >= total_refund,
+            SeekError::InsufficientHouseFunds
         );
 
-        // Refund entry from house vault to player
-        let seeds = &[b"global_state".as_ref(), &[global_state.bump]];
-        let signer_seeds = &[&seeds[..]];
-
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.house_vault.to_account_info(),
-                to: ctx.accounts.player_token_account.to_account_info(),
-                authority: global_state.to_account_info(),
-            },
-            signer_seeds,
-        );
-        token::transfer(transfer_ctx, bounty.entry_amount)?;
+        vaults.transfer(&vaults.house_vault, player_token_account, total_refund)?;
 
-        // Update tracked balance
+        // Use saturating_sub for tracked balance
         global_state.house_fund_balance = global_state
             .house_fund_balance
-            .saturating_sub(bounty.entry_amount);
+            .saturating_sub(total_refund);
 
-        // Mark as cancelled
-        bounty.status = BountyStatus::Cancelled;
+        // Entry and stake were the player's deposits; only the bonus on top
+        // is a house loss for the epoch breaker
+        let bonus = i64::try_from(stake_return.saturating_sub(bounty.dispute_stake))
+            .map_err(|_| SeekError::MathOverflow)?;
+        record_epoch_house_net(global_state, -bonus)?;
 
-        emit!(BountyCancelled {
-            player: bounty.player,
-            bounty: bounty.key(),
-            refund_amount: bounty.entry_amount,
-        });
+        // Overturned losses only roll the jackpot when configured to; by
+        // default a dispute win is a refund, not a full win. The player's
+        // jackpot cooldown applies as on a normal win.
+        let clock = Clock::get()?;
+        let odds = global_state.jackpot_odds(player_stats.loss_streak)?;
+        let jackpot_won = if global_state.dispute_wins_roll_jackpot
+            && player_stats.take_jackpot_roll(global_state.jackpot_cooldown, clock.unix_timestamp)?
+        {
+            roll_singularity(
+                global_state,
+                bounty,
+                bounty_key,
+                vaults,
+                player_token_account,
+                &clock,
+                odds,
+            )?
+        } else {
+            0
+        };
+        player_stats.record_result(
+            true,
+            total_refund
+                .checked_add(jackpot_won)
+                .ok_or(SeekError::MathOverflow)?,
+        )?;
+
+        bounty.status = BountyStatus::Won;
+        bounty.finalized_at = clock.unix_timestamp;
+        global_state.total_bounties_won = global_state
+            .total_bounties_won
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
+        global_state.total_disputes_won_by_player = global_state
+            .total_disputes_won_by_player
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
+
+        msg!(
+            "Dispute resolved: PLAYER WINS | Refund: {} SKR",
+            global_state.whole_skr(total_refund)
+        );
+    } else {
+        // Player loses dispute: stake forfeited, distribute entry (70/20/10)
+        // Dispute stake already tracked in house_fund_balance (from dispute_bounty)
+        // Now distribute the original entry amount
+        let entry = bounty.entry_amount;
+
+        let LossShares {
+            house_share,
+            singularity_share,
+            protocol_share,
+            remainder,
+        } = compute_loss_shares(entry)?;
+
+        // 20% to singularity vault
+        vaults.transfer(&vaults.house_vault, &vaults.singularity_vault, singularity_share)?;
+
+        global_state.singularity_balance = global_state
+            .singularity_balance
+            .checked_add(singularity_share)
+            .ok_or(SeekError::MathOverflow)?;
 
-        msg!("Bounty cancelled! Refund: {} SKR", bounty.entry_amount / DECIMALS_MULTIPLIER);
+        // 10% to protocol treasury, or kept by the house when compounding
+        let compounded = record_protocol_share(global_state, protocol_share)?;
+        if !compounded {
+            vaults.pay_protocol_share(global_state, protocol_share)?;
+        }
 
-        Ok(())
-    }
+        // Update house balance: subtract entry, add back house_share + remainder
+        // (net: keep 70% + dispute_stake)
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .saturating_sub(entry)
+            .checked_add(house_share)
+            .ok_or(SeekError::MathOverflow)?
+            .checked_add(remainder)
+            .ok_or(SeekError::MathOverflow)?
+            .checked_add(if compounded { protocol_share } else { 0 })
+            .ok_or(SeekError::MathOverflow)?;
 
-    /// Step 1 of two-step authority transfer. Current authority proposes a new
-    /// authority; no state changes until the new authority signs `accept_authority_transfer`.
-    /// Overwrites any previously pending transfer.
-    pub fn propose_authority_transfer(
-        ctx: Context<ProposeAuthorityTransfer>,
-        new_authority: Pubkey,
-    ) -> Result<()> {
-        // Guard against accidentally proposing the zero address (= cancel, not a transfer)
-        require!(
-            new_authority != Pubkey::default(),
-            SeekError::Unauthorized
-        );
+        bounty.status = BountyStatus::Lost;
+        bounty.finalized_at = Clock::get()?.unix_timestamp;
+        global_state.total_bounties_lost = global_state
+            .total_bounties_lost
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
 
-        let global_state = &mut ctx.accounts.global_state;
-        global_state.pending_authority = new_authority;
+        // Optionally soften the forfeit: part of the stake goes back to
+        // the player, the rest is forfeited to the house (and treasury)
+        stake_refund = bps_of(bounty.dispute_stake, u64::from(global_state.dispute_loss_refund_bps))?;
+        if stake_refund > 0 {
+            vaults.transfer(&vaults.house_vault, player_token_account, stake_refund)?;
+            global_state.house_fund_balance = global_state
+                .house_fund_balance
+                .saturating_sub(stake_refund);
+        }
+        player_stats.record_result(false, stake_refund)?;
+        let forfeited = bounty.dispute_stake - stake_refund;
+        route_forfeited_stake(global_state, vaults, forfeited)?;
+        global_state.total_dispute_stake_forfeited = global_state
+            .total_dispute_stake_forfeited
+            .checked_add(forfeited)
+            .ok_or(SeekError::MathOverflow)?;
 
         msg!(
-            "Authority transfer proposed: {} -> {} (awaiting acceptance)",
-            global_state.authority,
-            new_authority
+            "Dispute resolved: PLAYER LOSES | Entry distributed 70/20/10, stake refund: {} SKR",
+            global_state.whole_skr(stake_refund)
         );
+    }
 
-        Ok(())
+    emit!(DisputeResolved {
+        bounty: bounty_key,
+        player: bounty.player,
+        player_won_dispute: player_wins,
+        stake_returned: player_wins,
+        stake_refund,
+        reason_code: ruling.reason_code,
+        resolution_source: bounty.resolution_source,
+    });
+
+    Ok(())
+}
+
+/// Grow a program-owned account whose type has gained fields to `new_size`,
+/// zero-filling the tail, with `payer` topping up the rent. Only accounts
+/// carrying `discriminator` and still shorter than `new_size` qualify.
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    discriminator: [u8; 8],
+    new_size: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    require_keys_eq!(
+        *account.owner,
+        crate::ID,
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+    );
+    require!(
+        account.try_borrow_data()?.get(..8) == Some(&discriminator[..]),
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+    require!(account.data_len() < new_size, SeekError::AlreadyMigrated);
+
+    let top_up = Rent::get()?
+        .minimum_balance(new_size)
+        .saturating_sub(account.lamports());
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
     }
+    account.realloc(new_size, true)?;
+    Ok(())
+}
 
-    /// Step 2 of two-step authority transfer. The pending authority signs to
-    /// accept, atomically swapping `authority` and clearing `pending_authority`.
-    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
-        let global_state = &mut ctx.accounts.global_state;
+/// How settle_co_disputers treats each co-disputer's stake.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CoDisputeOutcome {
+    /// Dispute won: the stake plus a pro-rata slice of the bonus
+    Won,
+    /// Dispute lost: `dispute_loss_refund_bps` of the stake, like the player's
+    Lost,
+    /// Bounty reclaimed unresolved during a pause: the stake in full
+    Refunded,
+}
 
-        // Must have a pending transfer
+/// Pay out (or forfeit) each co-disputer's stake once the bounty's dispute
+/// is over, and close their DisputeShare. `accounts` is one
+/// `[dispute_share, disputer_token_account, disputer]` triple per
+/// co-disputer; every DisputeShare of the bounty must be present exactly
+/// once, and its rent goes back to `disputer`, who paid it in join_dispute.
+fn settle_co_disputers<'info>(
+    global_state: &mut GlobalState,
+    bounty: &Bounty,
+    bounty_key: Pubkey,
+    outcome: CoDisputeOutcome,
+    vaults: &VaultAccounts<'info>,
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    require!(
+        accounts.len() == usize::from(bounty.co_disputers) * 3,
+        SeekError::CoDisputersMismatch
+    );
+    let total_stake = bounty
+        .dispute_stake
+        .checked_add(bounty.co_dispute_stake)
+        .ok_or(SeekError::MathOverflow)?;
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(accounts.len() / 3);
+    let mut stake_seen: u64 = 0;
+
+    for item in accounts.chunks_exact(3) {
+        let share: Account<'info, DisputeShare> = Account::try_from(&item[0])?;
         require!(
-            global_state.pending_authority != Pubkey::default(),
-            SeekError::Unauthorized
+            share.bounty == bounty_key && !seen.contains(&share.key()),
+            SeekError::CoDisputersMismatch
         );
-
-        // Only the pending authority can accept
         require!(
-            ctx.accounts.new_authority.key() == global_state.pending_authority,
+            item[1].key() == get_associated_token_address(&share.disputer, &SKR_MINT),
             SeekError::Unauthorized
         );
+        require!(
+            item[1].key() != vaults.house_vault.key(),
+            SeekError::InvalidTokenAccount
+        );
+        require!(item[2].key() == share.disputer, SeekError::Unauthorized);
+        seen.push(share.key());
+        stake_seen = stake_seen
+            .checked_add(share.stake)
+            .ok_or(SeekError::MathOverflow)?;
 
-        let old_authority = global_state.authority;
-        let new_authority = global_state.pending_authority;
+        let payout = match outcome {
+            CoDisputeOutcome::Won => {
+                let payout = dispute_stake_return(
+                    bounty.entry_amount,
+                    global_state.dispute_win_bonus_bps,
+                    share.stake,
+                    total_stake,
+                )?;
+                let bonus =
+                    i64::try_from(payout - share.stake).map_err(|_| SeekError::MathOverflow)?;
+                record_epoch_house_net(global_state, -bonus)?;
+                payout
+            }
+            CoDisputeOutcome::Lost => {
+                let refund =
+                    bps_of(share.stake, u64::from(global_state.dispute_loss_refund_bps))?;
+                route_forfeited_stake(global_state, vaults, share.stake - refund)?;
+                global_state.total_dispute_stake_forfeited = global_state
+                    .total_dispute_stake_forfeited
+                    .checked_add(share.stake - refund)
+                    .ok_or(SeekError::MathOverflow)?;
+                refund
+            }
+            CoDisputeOutcome::Refunded => share.stake,
+        };
+        if payout > 0 {
+            vaults.transfer(&vaults.house_vault, &item[1], payout)?;
+            global_state.house_fund_balance = global_state
+                .house_fund_balance
+                .saturating_sub(payout);
+        }
 
-        global_state.authority = new_authority;
-        global_state.pending_authority = Pubkey::default();
-
-        emit!(AuthorityTransferred {
-            old_authority,
-            new_authority,
+        emit!(DisputeShareSettled {
+            bounty: bounty_key,
+            disputer: share.disputer,
+            payout,
         });
+        share.close(item[2].clone())?;
+    }
 
-        msg!("Authority transferred from {} to {}", old_authority, new_authority);
+    require!(stake_seen == bounty.co_dispute_stake, SeekError::CoDisputersMismatch);
+    Ok(())
+}
 
-        Ok(())
+/// Shared proposal step for propose_resolution and reveal_and_propose:
+/// stamps the result on a revealed, unproposed bounty and opens the
+/// challenge period (closed on the spot for fast-resolve tiers).
+fn record_proposal(
+    bounty: &mut Account<Bounty>,
+    success: bool,
+    fast_resolve: bool,
+    dispute_disabled: bool,
+) -> Result<()> {
+    // Verify mission was revealed (commit-reveal completed)
+    require!(bounty.mission_revealed, SeekError::MissionNotRevealed);
+
+    // A proposed result is immutable: once resolved_at is stamped the
+    // outcome can never be re-proposed, whatever the status says
+    require!(bounty.resolved_at == 0, SeekError::ResolutionAlreadyProposed);
+
+    // Verify bounty is in Submitted state
+    require!(
+        bounty.status == BountyStatus::Submitted,
+        SeekError::ExpectedSubmitted
+    );
+
+    // Get current time
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+
+    // Past the propose deadline the bounty belongs to reclaim_unproposed
+    require!(
+        current_time < bounty.propose_deadline()?,
+        SeekError::ProposeDeadlinePassed
+    );
+
+    // Calculate challenge period end (none for fast-resolve tiers)
+    let challenge_ends_at = if fast_resolve {
+        current_time
+    } else {
+        current_time
+            .checked_add(CHALLENGE_PERIOD)
+            .ok_or(SeekError::MathOverflow)?
+    };
+
+    // Set optimistic resolution fields
+    bounty.resolved_at = current_time;
+    bounty.challenge_ends_at = challenge_ends_at;
+    bounty.proposed_win = success;
+
+    // A loss attested by the oracle's co-signature is final
+    bounty.dispute_disabled = dispute_disabled;
+    bounty.resolution_source = if dispute_disabled {
+        ResolutionSource::Proof
+    } else {
+        ResolutionSource::Authority
+    } as u8;
+
+    // Update status to challenge period
+    bounty.status = if success {
+        BountyStatus::ChallengeWon
+    } else {
+        BountyStatus::ChallengeLost
+    };
+
+    emit!(BountyResolutionProposed {
+        bounty: bounty.key(),
+        player: bounty.player,
+        proposed_win: success,
+        challenge_ends_at,
+        dispute_disabled,
+    });
+
+    msg!("Resolution proposed: {} | Challenge ends: {}",
+        if success { "WIN" } else { "LOSS" },
+        challenge_ends_at
+    );
+
+    Ok(())
+}
+
+/// Shared commit-reveal check for reveal_mission and reveal_mission_fields.
+/// `preimage` is the ordered list of byte strings whose SHA-256 must equal
+/// the bounty's commitment; `mission_id` is what gets stored and counted.
+/// `mission_usage` (with its bump) is only required while a reuse cap is
+/// set; without one, a reveal passed no counter isn't counted.
+fn verify_and_record_reveal(
+    global_state: &GlobalState,
+    bounty: &mut Account<Bounty>,
+    mission_usage: Option<(&mut MissionUsage, u8)>,
+    mission_id: [u8; 32],
+    preimage: &[&[u8]],
+    salt: [u8; 32],
+) -> Result<()> {
+    // Verify bounty is pending (photo submitted but not resolved)
+    require!(
+        bounty.status == BountyStatus::Pending || bounty.status == BountyStatus::Submitted,
+        SeekError::ExpectedPending
+    );
+
+    // Verify mission hasn't already been revealed
+    require!(!bounty.mission_revealed, SeekError::MissionAlreadyRevealed);
+
+    // A reveal after the timer only counts if the player recorded an
+    // on-time submission. Otherwise backend delay could turn a hunt into
+    // a loss; the bounty instead stays Pending and is refundable via
+    // cancel_bounty after the grace period.
+    let clock = Clock::get()?;
+    let current_time = clock.unix_timestamp;
+    require!(
+        bounty.timer_running(current_time)
+            || (bounty.submitted_at != 0 && bounty.timer_running(bounty.submitted_at)),
+        SeekError::LateReveal
+    );
+
+    // Bound how long a commitment has to stay secret. Past its TTL the
+    // mission may have leaked, so the bounty can only be refunded
+    // (cancel_bounty / keeper_sweep) rather than revealed.
+    require!(
+        current_time <= bounty.commitment_valid_until(global_state.commitment_ttl),
+        SeekError::CommitmentExpired
+    );
+
+    // The hunt has to run for at least min_hunt_slots, measured in slots so
+    // a trusted backend can't collapse it by revealing in the accept's block.
+    require!(
+        clock.slot
+            >= bounty
+                .accepted_slot
+                .checked_add(global_state.min_hunt_slots)
+                .ok_or(SeekError::MathOverflow)?,
+        SeekError::RevealTooEarly
+    );
+
+    // Reject trivially guessable commitments. An all-zero salt lets anyone
+    // brute-force the mission from the commitment; an all-zero mission_id
+    // is never a real mission.
+    require!(
+        salt != [0u8; 32] && mission_id != [0u8; 32],
+        SeekError::WeakCommitment
+    );
+
+    // Hash the preimage parts in order (SHA-256 of their concatenation)
+    // and verify against the commitment
+    let computed_hash = anchor_lang::solana_program::hash::hashv(preimage);
+
+    require!(
+        computed_hash.to_bytes() == bounty.mission_commitment,
+        SeekError::InvalidMissionHash
+    );
+
+    // Track how often this mission has been handed out
+    let max_reuse = global_state.max_mission_reuse;
+    match mission_usage {
+        Some((mission_usage, bump)) => mission_usage.record_reveal(mission_id, bump, max_reuse)?,
+        None => require!(max_reuse == 0, SeekError::MissionUsageRequired),
     }
 
-    /// Cancel an in-flight authority transfer. Current authority only.
-    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
-        let global_state = &mut ctx.accounts.global_state;
+    // Store revealed mission
+    bounty.mission_id = mission_id;
+    bounty.mission_revealed = true;
+    bounty.revealed_at = current_time;
 
-        require!(
-            global_state.pending_authority != Pubkey::default(),
-            SeekError::Unauthorized
-        );
+    // Update status to Submitted
+    bounty.status = BountyStatus::Submitted;
 
-        let cancelled = global_state.pending_authority;
-        global_state.pending_authority = Pubkey::default();
+    emit!(MissionRevealed {
+        bounty: bounty.key(),
+        player: bounty.player,
+        tier: bounty.tier,
+        mission_id,
+        commitment_verified: true,
+    });
 
-        msg!("Authority transfer to {} cancelled", cancelled);
-        Ok(())
+    msg!("Mission revealed and verified!");
+
+    Ok(())
+}
+
+/// Open the bounty's geofence commitment, if it has one. A bounty without a
+/// geo_commitment takes no geofence; one with a commitment must be revealed
+/// with a geofence that hashes to it.
+fn verify_geofence(bounty: &mut Account<Bounty>, geofence: Option<Geofence>) -> Result<()> {
+    if bounty.geo_commitment == [0u8; 32] {
+        require!(geofence.is_none(), SeekError::GeofenceNotCommitted);
+        return Ok(());
     }
+    let geofence = geofence.ok_or(SeekError::GeofenceRequired)?;
+
+    let computed_hash = anchor_lang::solana_program::hash::hashv(&[
+        &geofence.lat_e6.to_le_bytes(),
+        &geofence.lon_e6.to_le_bytes(),
+        &geofence.radius_m.to_le_bytes(),
+        &geofence.salt,
+    ]);
+    require!(
+        computed_hash.to_bytes() == bounty.geo_commitment,
+        SeekError::InvalidGeofenceHash
+    );
+
+    bounty.geo_lat_e6 = geofence.lat_e6;
+    bounty.geo_lon_e6 = geofence.lon_e6;
+    bounty.geo_radius_m = geofence.radius_m;
+    bounty.geo_revealed = true;
+
+    emit!(GeofenceRevealed {
+        bounty: bounty.key(),
+        lat_e6: geofence.lat_e6,
+        lon_e6: geofence.lon_e6,
+        radius_m: geofence.radius_m,
+    });
+    Ok(())
+}
 
-    /// Rotate the hot authority (used for reveal_mission + propose_resolution).
-    /// Cold authority only. Used when the backend keypair is compromised or rotated.
-    pub fn set_hot_authority(ctx: Context<SetHotAuthority>, new_hot: Pubkey) -> Result<()> {
-        require!(new_hot != Pubkey::default(), SeekError::Unauthorized);
+#[program]
+pub mod seek_protocol {
+    use super::*;
 
+    /// Initialize the Seek protocol - Step 1: Create global state
+    /// Call initialize_vaults after this to set up token vaults
+    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         let global_state = &mut ctx.accounts.global_state;
-        let old_hot = global_state.hot_authority;
-        global_state.hot_authority = new_hot;
 
-        msg!("Hot authority rotated: {} -> {}", old_hot, new_hot);
-        Ok(())
-    }
+        // Set authority (vault addresses set in initialize_vaults)
+        global_state.authority = ctx.accounts.authority.key();
 
-    /// Rotate the protocol_treasury recipient. Cold authority only.
-    /// Used when the fees-wallet key is compromised, lost, or operationally rotated.
-    /// `new_treasury` must be a TokenAccount of SKR_MINT (validated in the
-    /// account context). Only redirects FUTURE inflows — funds already in the
-    /// old treasury stay under that account's owner.
-    pub fn set_treasury(ctx: Context<SetTreasury>) -> Result<()> {
-        let global_state = &mut ctx.accounts.global_state;
-        let old_treasury = global_state.protocol_treasury;
-        let new_treasury = ctx.accounts.new_treasury.key();
+        // Default hot_authority to the same key; rotate later via set_hot_authority.
+        global_state.hot_authority = ctx.accounts.authority.key();
 
-        require!(new_treasury != old_treasury, SeekError::Unauthorized);
+        // No pending authority transfer initially.
+        global_state.pending_authority = Pubkey::default();
 
-        global_state.protocol_treasury = new_treasury;
+        // Initialize counters to zero
+        global_state.house_fund_balance = 0;
+        global_state.singularity_balance = 0;
+        global_state.total_burned = 0;
+        global_state.total_bounties_created = 0;
+        global_state.total_bounties_won = 0;
+        global_state.total_bounties_lost = 0;
+        global_state.total_singularity_wins = 0;
 
-        emit!(TreasuryRotated {
-            authority: ctx.accounts.authority.key(),
-            old_treasury,
-            new_treasury,
-        });
+        // Mission reuse is uncapped until the authority sets a limit.
+        global_state.max_mission_reuse = 0;
 
-        msg!("Protocol treasury rotated: {} -> {}", old_treasury, new_treasury);
-        Ok(())
-    }
+        // Early cancel stays disabled until a fee schedule is configured.
+        global_state.min_cancel_fee_bps = 0;
+        global_state.max_cancel_fee_bps = 0;
 
-    /// Close a bounty account after it reaches a terminal state + 24h cooldown.
-    /// Refunds rent to the player. The cooldown prevents PDA reuse races — the
-    /// bounty PDA seed is [b"bounty", player, timestamp] so after close, the
-    /// same (player, timestamp) can be re-init'd. 24h is plenty of slack for
-    /// any in-flight finalizer retries or downstream indexer catch-up.
-    pub fn close_bounty(ctx: Context<CloseBounty>) -> Result<()> {
-        let now = Clock::get()?.unix_timestamp;
-        let bounty = &ctx.accounts.bounty;
-        require!(
-            now >= bounty.created_at.saturating_add(86_400),
-            SeekError::BountyCooldown
-        );
-        msg!("Bounty account closed, rent refunded to player");
-        Ok(())
-    }
-}
+        // No extra buffer after the challenge period until configured.
+        global_state.finalize_grace = 0;
 
-/// Step 1: Initialize global state only (small stack footprint)
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    /// Authority who will manage the protocol. On mainnet, must match the
-    /// hardcoded `EXPECTED_INITIAL_AUTHORITY` (see top of file). Closes the
-    /// front-run vector between `anchor deploy` and the first `initialize` tx.
-    #[account(
-        mut,
-        constraint = is_expected_initial_authority(&authority.key()) @ SeekError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
+        // Global rate limit disabled until configured.
+        global_state.global_rate_limit = 0;
+        global_state.rate_limit_window = 0;
+        global_state.rate_window_start = 0;
+        global_state.rate_window_count = 0;
 
-    /// Global state PDA
-    #[account(
-        init,
-        payer = authority,
-        space = GlobalState::SIZE,
-        seeds = [b"global_state"],
-        bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
+        // Dispute wins are refunds only unless configured otherwise.
+        global_state.dispute_wins_roll_jackpot = false;
 
-    /// System program for account creation
-    pub system_program: Program<'info, System>,
-}
+        // No burn on wins until configured.
+        global_state.win_burn_bps = 0;
 
-/// Runtime check for the initialize-time authority. On mainnet, requires the
-/// hardcoded value AND rejects the System-Program placeholder so a forgotten
-/// edit fails fast. On devnet, permissive (no production value at risk).
-#[inline(always)]
-fn is_expected_initial_authority(_caller: &Pubkey) -> bool {
-    #[cfg(feature = "mainnet")]
-    {
-        // Reject the placeholder so a forgotten edit fails at init time.
-        if EXPECTED_INITIAL_AUTHORITY == Pubkey::default() {
-            return false;
-        }
-        return *_caller == EXPECTED_INITIAL_AUTHORITY;
-    }
-    #[cfg(not(feature = "mainnet"))]
-    {
-        true
-    }
-}
+        // Protocol fees go to the treasury unless compounding is enabled.
+        global_state.auto_compound_protocol = false;
+        global_state.total_protocol_revenue = 0;
 
-/// Step 2: Initialize house vault
-#[derive(Accounts)]
-pub struct InitializeHouseVault<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+        // Per-tier daily caps disabled until configured.
+        global_state.tier_daily_caps = [0; 3];
+        global_state.tier_daily_counts = [0; 3];
+        global_state.tier_day_start = 0;
 
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump,
-        constraint = global_state.authority == authority.key() @ SeekError::Unauthorized
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
+        // Payouts are not prefunded until configured.
+        global_state.prefund_payout = false;
+        global_state.outstanding_liability = 0;
 
-    #[account(
-        init,
-        payer = authority,
-        token::mint = skr_mint,
-        token::authority = global_state,
-        seeds = [b"house_vault"],
-        bump
-    )]
-    pub house_vault: Box<Account<'info, TokenAccount>>,
+        // No loss oracle: every loss stays disputable.
+        global_state.loss_oracle = Pubkey::default();
 
-    #[account(address = SKR_MINT @ SeekError::InvalidMint)]
-    pub skr_mint: Box<Account<'info, Mint>>,
+        // Live, with any jackpot returned to the house on shutdown.
+        global_state.shutdown = false;
+        global_state.shutdown_jackpot_policy = ShutdownJackpotPolicy::ReturnToHouse as u8;
 
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-}
+        // Not paused.
+        global_state.paused = false;
+        global_state.paused_at = 0;
 
-/// Step 3: Initialize singularity vault + set treasury
-#[derive(Accounts)]
-pub struct InitializeSingularityVault<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+        // Disputes may be resolved without an evidence attestation.
+        global_state.require_dispute_evidence = false;
 
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump,
-        constraint = global_state.authority == authority.key() @ SeekError::Unauthorized
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
+        // Dispute wins refund entry + stake, with no extra compensation.
+        global_state.dispute_win_bonus_bps = 0;
 
-    #[account(
-        init,
-        payer = authority,
-        token::mint = skr_mint,
-        token::authority = global_state,
-        seeds = [b"singularity_vault"],
-        bump
-    )]
-    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+        // No dispute fee: disputing costs only the refundable stake.
+        global_state.dispute_fee = 0;
 
-    /// Protocol treasury - existing token account for protocol fees
-    #[account(
-        token::mint = skr_mint,
-    )]
-    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+        // Burning allowed (win burn itself defaults to 0 bps).
+        global_state.burn_enabled = true;
 
-    #[account(address = SKR_MINT @ SeekError::InvalidMint)]
-    pub skr_mint: Box<Account<'info, Mint>>,
+        // Every tier goes through the challenge period.
+        global_state.fast_resolve_tiers = 0;
 
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-}
+        // Accepting opens once fund_house meets the launch threshold.
+        global_state.min_launch_balance = 0;
+        global_state.accepting_enabled = false;
 
-#[derive(Accounts)]
-#[instruction(entry_amount: u64, timestamp: i64)]
-pub struct AcceptBounty<'info> {
-    /// Player accepting the bounty
-    #[account(mut)]
-    pub player: Signer<'info>,
+        // Dispute statistics
+        global_state.total_disputes_filed = 0;
+        global_state.total_disputes_won_by_player = 0;
+        global_state.total_dispute_stake_forfeited = 0;
 
-    /// Global state PDA
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
+        // Tier timers start at the built-in defaults.
+        global_state.tier_durations = [
+            get_tier_duration(1)?,
+            get_tier_duration(2)?,
+            get_tier_duration(3)?,
+        ];
+        global_state.max_duration = DEFAULT_MAX_DURATION;
 
-    /// Bounty PDA - unique per player + timestamp
-    #[account(
-        init,
-        payer = player,
-        space = Bounty::SIZE,
-        seeds = [b"bounty", player.key().as_ref(), &timestamp.to_le_bytes()],
-        bump
-    )]
-    pub bounty: Box<Account<'info, Bounty>>,
+        // Epoch 0 starts now; no house loss cap.
+        global_state.epoch = 0;
+        global_state.epoch_started_at = Clock::get()?.unix_timestamp;
+        global_state.house_net_this_epoch = 0;
+        global_state.max_epoch_house_loss = 0;
+        global_state.epoch_opening_totals = EpochTotals::default();
 
-    /// Player's SKR token account — pinned to the canonical ATA.
-    /// Prevents passing a delegated/frozen/alt-ATA that could reroute winnings.
-    #[account(
-        mut,
-        constraint = player_token_account.key() == get_associated_token_address(&player.key(), &SKR_MINT) @ SeekError::Unauthorized
-    )]
-    pub player_token_account: Box<Account<'info, TokenAccount>>,
+        // Jackpots go to the player in full.
+        global_state.charity_vault = Pubkey::default();
+        global_state.charity_jackpot_bps = 0;
 
-    /// House vault to receive entry
-    #[account(
-        mut,
-        seeds = [b"house_vault"],
-        bump,
-        constraint = house_vault.key() == global_state.house_vault
-    )]
-    pub house_vault: Box<Account<'info, TokenAccount>>,
+        // Any payout is worth paying.
+        global_state.min_payout = 0;
 
-    /// The SKR token mint
-    #[account(
-        address = SKR_MINT @ SeekError::InvalidMint
-    )]
-    pub skr_mint: Box<Account<'info, Mint>>,
+        // Keepers may push wins to players.
+        global_state.player_claim_required = false;
 
-    /// System program
-    pub system_program: Program<'info, System>,
+        // Commitments go stale a day after the bounty is created.
+        global_state.commitment_ttl = COMMITMENT_TTL;
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
-}
+        // The whole protocol share goes to protocol_treasury.
+        global_state.protocol_destinations = [Pubkey::default(); MAX_PROTOCOL_DESTINATIONS];
+        global_state.protocol_weights = [0; MAX_PROTOCOL_DESTINATIONS];
 
-// === NEW TRUST-MINIMIZATION ACCOUNT STRUCTS ===
+        // A lost dispute forfeits the whole stake.
+        global_state.dispute_loss_refund_bps = 0;
 
-#[derive(Accounts)]
-pub struct RevealMission<'info> {
-    /// Hot authority revealing the mission (backend-held)
-    #[account(
-        constraint = hot_authority.key() == global_state.hot_authority @ SeekError::Unauthorized
-    )]
-    pub hot_authority: Signer<'info>,
+        // Accepts carry no ordering key.
+        global_state.accept_ordering = false;
 
-    /// Global state PDA
-    #[account(
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Account<'info, GlobalState>,
+        // Only losses feed the jackpot until configured.
+        global_state.win_jackpot_bps = 0;
 
-    /// The bounty to reveal mission for
-    #[account(
-        mut,
-        constraint = bounty.global_state == global_state.key()
-    )]
-    pub bounty: Account<'info, Bounty>,
-}
+        // Wins pay 3x.
+        global_state.payout_multiplier_bps = DEFAULT_PAYOUT_MULTIPLIER_BPS;
 
-#[derive(Accounts)]
-pub struct ProposeResolution<'info> {
-    /// Hot authority proposing the resolution (backend-held)
-    #[account(
-        constraint = hot_authority.key() == global_state.hot_authority @ SeekError::Unauthorized
-    )]
-    pub hot_authority: Signer<'info>,
+        // No bounties yet.
+        global_state.active_bounties = 0;
 
-    /// Global state PDA
-    #[account(
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Account<'info, GlobalState>,
+        // No per-player daily wager cap.
+        global_state.daily_wager_cap = 0;
 
-    /// The bounty being resolved
-    #[account(
-        mut,
-        constraint = bounty.global_state == global_state.key()
-    )]
-    pub bounty: Account<'info, Bounty>,
-}
+        // No loyalty rebate withheld from wins.
+        global_state.loyalty_rebate_bps = 0;
+        global_state.loyalty_escrowed = 0;
 
-#[derive(Accounts)]
-pub struct FinalizeBounty<'info> {
-    /// Anyone can finalize after challenge period (permissionless)
-    pub caller: Signer<'info>,
+        // Disputes are the player's alone until co-disputes are enabled.
+        global_state.co_disputes_enabled = false;
 
-    /// Global state PDA
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
+        // No per-player jackpot cooldown.
+        global_state.jackpot_cooldown = 0;
 
-    /// The bounty being finalized
-    #[account(
-        mut,
-        constraint = bounty.global_state == global_state.key()
-    )]
-    pub bounty: Box<Account<'info, Bounty>>,
+        // Reveals allowed from the accept's own slot.
+        global_state.min_hunt_slots = 0;
 
-    /// Player's token account for payout (on win)
-    #[account(
-        mut,
-        constraint = player_token_account.key() == get_associated_token_address(&bounty.player, &SKR_MINT) @ SeekError::Unauthorized
-    )]
-    pub player_token_account: Box<Account<'info, TokenAccount>>,
+        // Forfeited dispute stakes stay in the house.
+        global_state.forfeit_stake_treasury_bps = 0;
 
-    /// House vault
-    #[account(
-        mut,
-        seeds = [b"house_vault"],
-        bump,
-        constraint = house_vault.key() == global_state.house_vault
-    )]
-    pub house_vault: Box<Account<'info, TokenAccount>>,
+        // No loss-streak odds boost.
+        global_state.loss_streak_boost_bps = 0;
+        global_state.max_loss_streak_boost_bps = 0;
 
-    /// Singularity vault for jackpot
-    #[account(
-        mut,
-        seeds = [b"singularity_vault"],
-        bump,
-        constraint = singularity_vault.key() == global_state.singularity_vault
-    )]
-    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+        // The house is never topped up from the treasury automatically.
+        global_state.auto_fund_house = false;
+        global_state.auto_fund_threshold = 0;
+        global_state.auto_fund_amount = 0;
 
-    /// Protocol treasury for fees
-    #[account(
-        mut,
-        constraint = protocol_treasury.key() == global_state.protocol_treasury,
-        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
-    )]
-    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+        // No cap on concurrently active bounties.
+        global_state.max_active_global = 0;
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
-}
+        // Every tier stakes DISPUTE_STAKE_BPS to dispute.
+        global_state.tier_dispute_stake_bps = [0; 3];
 
-#[derive(Accounts)]
-pub struct FundHouse<'info> {
-    /// Authority funding the house
-    #[account(
-        mut,
-        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
+        // Every win rolls, whatever the pool size.
+        global_state.min_jackpot_roll_balance = 0;
 
-    /// Global state PDA
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Account<'info, GlobalState>,
+        // Unclaimed wins escheat back into the jackpot pool.
+        global_state.escheat_policy = EscheatPolicy::ReturnToPool as u8;
 
-    /// Authority's SKR token account
-    #[account(
-        mut,
-        constraint = authority_token_account.mint == SKR_MINT @ SeekError::InvalidMint,
-        constraint = authority_token_account.owner == authority.key() @ SeekError::Unauthorized
-    )]
-    pub authority_token_account: Account<'info, TokenAccount>,
+        // No cap on jackpot payouts per epoch.
+        global_state.singularity_paid_this_epoch = 0;
+        global_state.max_jackpot_per_epoch = 0;
 
-    /// House vault to receive funds
-    #[account(
-        mut,
-        seeds = [b"house_vault"],
-        bump,
-        constraint = house_vault.key() == global_state.house_vault
-    )]
-    pub house_vault: Account<'info, TokenAccount>,
+        // Store bump for future PDA derivations
+        global_state.bump = ctx.bumps.global_state;
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
-}
+        msg!("Seek Protocol global state initialized!");
+        msg!("Authority: {}", global_state.authority);
+        msg!("Hot authority: {} (rotate via set_hot_authority)", global_state.hot_authority);
 
-#[derive(Accounts)]
-pub struct DisputeBounty<'info> {
-    /// Player disputing the bounty
-    #[account(
-        mut,
-        constraint = player.key() == bounty.player @ SeekError::Unauthorized
-    )]
-    pub player: Signer<'info>,
+        Ok(())
+    }
 
-    /// Global state PDA (mut to track dispute stake)
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
+    /// Initialize the Seek protocol - Step 2: Create house vault
+    /// Must be called after initialize()
+    pub fn initialize_house_vault(ctx: Context<InitializeHouseVault>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.house_vault = ctx.accounts.house_vault.key();
+        global_state.house_vault_bump = ctx.bumps.house_vault;
 
-    /// The bounty being disputed
-    #[account(
-        mut,
-        constraint = bounty.global_state == global_state.key()
-    )]
-    pub bounty: Box<Account<'info, Bounty>>,
+        // Entry amounts and logs follow the mint's own decimals
+        let decimals = ctx.accounts.skr_mint.decimals;
+        require!(decimals <= MAX_SKR_DECIMALS, SeekError::InvalidMint);
+        global_state.skr_decimals = decimals;
 
-    /// Player's token account for stake — pinned to canonical ATA.
-    #[account(
-        mut,
-        constraint = player_token_account.key() == get_associated_token_address(&player.key(), &SKR_MINT) @ SeekError::Unauthorized
-    )]
-    pub player_token_account: Box<Account<'info, TokenAccount>>,
+        msg!("House vault initialized: {}", global_state.house_vault);
+        Ok(())
+    }
 
-    /// House vault to receive stake
-    #[account(
-        mut,
-        seeds = [b"house_vault"],
-        bump,
-        constraint = house_vault.key() == global_state.house_vault
-    )]
-    pub house_vault: Box<Account<'info, TokenAccount>>,
+    /// Initialize the Seek protocol - Step 3: Create singularity vault + set treasury
+    /// Must be called after initialize_house_vault()
+    pub fn initialize_singularity_vault(ctx: Context<InitializeSingularityVault>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.singularity_vault = ctx.accounts.singularity_vault.key();
+        global_state.singularity_vault_bump = ctx.bumps.singularity_vault;
+        global_state.protocol_treasury = ctx.accounts.protocol_treasury.key();
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
-}
+        msg!("Singularity vault initialized: {}", global_state.singularity_vault);
+        msg!("Protocol treasury set: {}", global_state.protocol_treasury);
+        Ok(())
+    }
 
-#[derive(Accounts)]
-pub struct ResolveDispute<'info> {
-    /// Authority resolving the dispute
-    #[account(
-        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
+    /// Accept a bounty - player submits their entry and starts the hunt.
+    /// entry_amount must be exactly 1000 / 2000 / 3000 SKR in base units, at
+    /// the mint decimals recorded in GlobalState.skr_decimals.
+    /// mission_commitment is hash(mission_id || salt) for commit-reveal.
+    /// geo_commitment optionally commits the target area the same way
+    /// (see Geofence); [0; 32] for a hunt without one.
+    /// timestamp must be within 60 seconds of current time (for PDA derivation).
+    pub fn accept_bounty(
+        ctx: Context<AcceptBounty>,
+        entry_amount: u64,
+        timestamp: i64,
+        mission_commitment: [u8; 32],
+        geo_commitment: [u8; 32],
+    ) -> Result<AcceptReceipt> {
+        log_compute_units!("accept_bounty: start");
 
-    /// Global state PDA
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
+        // No new hunts once the kill switch is pulled, or while paused
+        require!(!ctx.accounts.global_state.shutdown, SeekError::ProtocolShutdown);
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
 
-    /// The disputed bounty
-    #[account(
-        mut,
-        constraint = bounty.global_state == global_state.key()
-    )]
-    pub bounty: Box<Account<'info, Bounty>>,
+        // Not open until the house is funded to the launch threshold
+        require!(ctx.accounts.global_state.accepting_enabled, SeekError::AcceptingDisabled);
 
-    /// Player's token account for refund — pinned to canonical ATA.
-    #[account(
-        mut,
-        constraint = player_token_account.key() == get_associated_token_address(&bounty.player, &SKR_MINT) @ SeekError::Unauthorized
-    )]
-    pub player_token_account: Box<Account<'info, TokenAccount>>,
+        // Closed for the rest of the epoch once the house loss breaker trips
+        require!(
+            !ctx.accounts.global_state.epoch_loss_cap_reached(),
+            SeekError::EpochLossCapReached
+        );
 
-    /// House vault
-    #[account(
-        mut,
-        seeds = [b"house_vault"],
-        bump,
-        constraint = house_vault.key() == global_state.house_vault
-    )]
-    pub house_vault: Box<Account<'info, TokenAccount>>,
+        // Bounded total open state and exposure, until a settle frees a slot
+        require!(
+            !ctx.accounts.global_state.at_capacity(),
+            SeekError::ProtocolAtCapacity
+        );
 
-    /// Singularity vault for loss distribution
-    #[account(
-        mut,
-        seeds = [b"singularity_vault"],
-        bump,
-        constraint = singularity_vault.key() == global_state.singularity_vault
-    )]
-    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+        // Validate entry amount and get tier
+        let tier = validate_entry_amount(
+            entry_amount,
+            ctx.accounts.global_state.decimals_multiplier(),
+        )?;
 
-    /// Protocol treasury for loss distribution
-    #[account(
-        mut,
-        constraint = protocol_treasury.key() == global_state.protocol_treasury,
-        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
-    )]
-    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+        // Get current timestamp and validate provided timestamp is recent
+        let clock = Clock::get()?;
+        let current_time = clock.unix_timestamp;
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
-}
+        // Timestamp must be within 60 seconds of current time
+        require!(
+            (current_time - timestamp).abs() <= 60,
+            SeekError::InvalidTimestamp
+        );
 
-#[derive(Accounts)]
-pub struct CancelBounty<'info> {
-    /// Player cancelling the bounty
-    #[account(
-        mut,
-        constraint = player.key() == bounty.player @ SeekError::Unauthorized
-    )]
-    pub player: Signer<'info>,
+        // Protocol-wide rate limit (smooths load during viral spikes)
+        ctx.accounts.global_state.record_accept_for_rate_limit(current_time)?;
 
-    /// Global state PDA
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
+        // Per-tier daily exposure cap
+        ctx.accounts.global_state.record_accept_for_tier_cap(tier, current_time)?;
 
-    /// The bounty being cancelled
-    #[account(
-        mut,
-        constraint = bounty.global_state == global_state.key()
-    )]
-    pub bounty: Box<Account<'info, Bounty>>,
+        // Calculate expiration based on tier
+        let duration = ctx.accounts.global_state.tier_durations[tier_index(tier)?];
+        let expires_at = current_time
+            .checked_add(duration)
+            .ok_or(SeekError::MathOverflow)?;
+        require!(
+            expires_at - current_time <= ctx.accounts.global_state.max_duration,
+            SeekError::DurationTooLong
+        );
 
-    /// Player's token account for refund — pinned to canonical ATA.
-    #[account(
-        mut,
-        constraint = player_token_account.key() == get_associated_token_address(&player.key(), &SKR_MINT) @ SeekError::Unauthorized
-    )]
-    pub player_token_account: Box<Account<'info, TokenAccount>>,
+        // Calculate payout (3x by default: entry back + 2x profit)
+        let payout_amount =
+            compute_payout(entry_amount, ctx.accounts.global_state.payout_multiplier_bps)?;
 
-    /// House vault to refund from
-    #[account(
-        mut,
+        // Rejected here rather than rounded up at finalize
+        require!(
+            payout_amount >= ctx.accounts.global_state.min_payout,
+            SeekError::PayoutBelowMinimum
+        );
+
+        // Initialize bounty account
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.player = ctx.accounts.player.key();
+        bounty.rent_payer = ctx.accounts.rent_payer.key();
+        bounty.global_state = ctx.accounts.global_state.key();
+        bounty.entry_amount = entry_amount;
+        bounty.payout_amount = payout_amount;
+        bounty.created_at = current_time;
+        bounty.expires_at = expires_at;
+        bounty.accepted_slot = clock.slot;
+        bounty.status = BountyStatus::Pending;
+        bounty.tier = tier;
+        bounty.singularity_won = false;
+        bounty.bump = ctx.bumps.bounty;
+
+        // Commit-reveal: store mission commitment hash
+        bounty.mission_commitment = mission_commitment;
+        bounty.mission_id = [0u8; 32];
+        bounty.mission_revealed = false;
+        bounty.geo_commitment = geo_commitment;
+
+        // A commitment drawn from a pre-published pool was fixed before play
+        if let Some(pool) = ctx.accounts.commitment_pool.as_deref_mut() {
+            let index = pool.draw(&mission_commitment)?;
+            emit!(CommitmentDrawn {
+                pool: pool.key(),
+                bounty: bounty.key(),
+                index,
+            });
+        }
+
+        // Optimistic resolution: initialize to zero
+        bounty.resolved_at = 0;
+        bounty.challenge_ends_at = 0;
+        bounty.proposed_win = false;
+
+        // Dispute: initialize to false
+        bounty.is_disputed = false;
+        bounty.dispute_stake = 0;
+        bounty.disputed_at = 0;
+
+        // Submission: recorded later by submit_photo
+        bounty.submitted_at = 0;
+
+        // Prefund: reserved below once the entry is in the house
+        bounty.prefunded = false;
+
+        // Disputable unless a proof-backed loss is proposed
+        bounty.dispute_disabled = false;
+
+        // Evidence: recorded later by submit_photo
+        bounty.photo_hash = [0u8; 32];
+
+        // Real tokens at stake
+        bounty.practice = false;
+
+        // Same-slot tie-break, when enabled
+        (bounty.accept_slot, bounty.accept_order_key) = if ctx.accounts.global_state.accept_ordering {
+            accept_order_key(&ctx.accounts.slot_hashes, &bounty.key())?
+        } else {
+            (0, 0)
+        };
+
+        // Transfer entry from player to house vault
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_token_account.to_account_info(),
+                to: ctx.accounts.house_vault.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, entry_amount)?;
+        log_compute_units!("accept_bounty: entry transferred");
+
+        // Lifetime totals (the account is created on the first bounty)
+        let player_stats = &mut ctx.accounts.player_stats;
+        player_stats.player = ctx.accounts.player.key();
+        player_stats.bump = ctx.bumps.player_stats;
+        player_stats.record_wager(entry_amount)?;
+        player_stats.record_daily_wager(
+            entry_amount,
+            ctx.accounts.global_state.daily_wager_cap,
+            current_time,
+        )?;
+        let loyalty_escrow = &mut ctx.accounts.loyalty_escrow;
+        loyalty_escrow.player = ctx.accounts.player.key();
+        loyalty_escrow.bump = ctx.bumps.loyalty_escrow;
+
+        // Update global state
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .checked_add(entry_amount)
+            .ok_or(SeekError::MathOverflow)?;
+        global_state.total_bounties_created = global_state
+            .total_bounties_created
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
+        global_state.active_bounties = global_state
+            .active_bounties
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
+
+        // Earmark the full payout so this win can't be starved by others
+        // (house_fund_balance already includes this entry, hence entry 0)
+        if global_state.prefund_payout {
+            require!(
+                global_state.can_back_payout(0, payout_amount)?,
+                SeekError::InsufficientHouseFunds
+            );
+            global_state.outstanding_liability = global_state
+                .outstanding_liability
+                .checked_add(payout_amount)
+                .ok_or(SeekError::MathOverflow)?;
+            bounty.prefunded = true;
+        }
+
+        // Emit event
+        emit!(BountyAccepted {
+            player: bounty.player,
+            bounty: bounty.key(),
+            entry_amount,
+            tier,
+            expires_at,
+            practice: false,
+        });
+
+        msg!("Bounty accepted!");
+        msg!("Player: {}", bounty.player);
+        msg!("Entry: {} SKR (Tier {})", global_state.whole_skr(entry_amount), tier);
+        msg!("Expires at: {}", expires_at);
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(AcceptReceipt {
+            bounty: bounty.key(),
+            expires_at,
+            tier,
+            payout_amount,
+        })
+    }
+
+    /// Start a practice hunt for `tier`: same lifecycle as accept_bounty
+    /// (submit_photo, reveal_mission, propose_resolution, finalize_bounty)
+    /// but no entry is taken and nothing is ever paid out. The bounty's
+    /// amounts stay 0, it can't be disputed, and settlement leaves vaults
+    /// and protocol stats untouched. Lets the app demo the full flow.
+    pub fn accept_practice_bounty(
+        ctx: Context<AcceptPracticeBounty>,
+        tier: u8,
+        timestamp: i64,
+        mission_commitment: [u8; 32],
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.shutdown, SeekError::ProtocolShutdown);
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let clock = Clock::get()?;
+        let current_time = clock.unix_timestamp;
+        require!(
+            (current_time - timestamp).abs() <= 60,
+            SeekError::InvalidTimestamp
+        );
+
+        let duration = ctx.accounts.global_state.tier_durations[tier_index(tier)?];
+        let expires_at = current_time
+            .checked_add(duration)
+            .ok_or(SeekError::MathOverflow)?;
+
+        // Fields not set here keep their zeroed init values (no amounts,
+        // nothing revealed, proposed, disputed or submitted yet)
+        let bounty = &mut ctx.accounts.bounty;
+        bounty.player = ctx.accounts.player.key();
+        bounty.rent_payer = ctx.accounts.rent_payer.key();
+        bounty.global_state = ctx.accounts.global_state.key();
+        bounty.created_at = current_time;
+        bounty.expires_at = expires_at;
+        bounty.accepted_slot = clock.slot;
+        bounty.status = BountyStatus::Pending;
+        bounty.tier = tier;
+        bounty.bump = ctx.bumps.bounty;
+        bounty.mission_commitment = mission_commitment;
+        bounty.dispute_disabled = true;
+        bounty.practice = true;
+
+        let player_stats = &mut ctx.accounts.player_stats;
+        player_stats.player = ctx.accounts.player.key();
+        player_stats.bump = ctx.bumps.player_stats;
+
+        emit!(BountyAccepted {
+            player: bounty.player,
+            bounty: bounty.key(),
+            entry_amount: 0,
+            tier,
+            expires_at,
+            practice: true,
+        });
+
+        msg!("Practice bounty accepted (Tier {}) | Expires at: {}", tier, expires_at);
+        Ok(())
+    }
+
+    /// Record the player's photo submission on-chain. Player only, and only
+    /// while the hunt timer is running. The timestamp lets `reveal_mission`
+    /// proceed after expiry for a hunt the player finished on time, while
+    /// still refusing late reveals the player never asked for. `photo_hash`
+    /// (SHA-256 of the photo bytes) is kept as evidence for disputes.
+    pub fn submit_photo(ctx: Context<SubmitPhoto>, photo_hash: [u8; 32]) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let bounty = &mut ctx.accounts.bounty;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(
+            bounty.status == BountyStatus::Pending,
+            SeekError::ExpectedPending
+        );
+        require!(bounty.submitted_at == 0, SeekError::AlreadySubmitted);
+        require!(bounty.timer_running(current_time), SeekError::BountyExpired);
+
+        bounty.submitted_at = current_time;
+        bounty.photo_hash = photo_hash;
+
+        emit!(PhotoSubmitted {
+            bounty: bounty.key(),
+            player: bounty.player,
+            submitted_at: current_time,
+            photo_hash,
+        });
+
+        msg!("Photo submission recorded at {}", current_time);
+
+        Ok(())
+    }
+
+    /// Reveal the mission - backend reveals mission_id and salt after player submits photo
+    /// Verifies hash(mission_id || salt) matches the original commitment,
+    /// and opens the geofence commitment when the bounty has one
+    pub fn reveal_mission(
+        ctx: Context<RevealMission>,
+        mission_id: [u8; 32],
+        salt: [u8; 32],
+        geofence: Option<Geofence>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+        require!(ctx.accounts.blocked_mission.data_is_empty(), SeekError::MissionBlocked);
+        verify_geofence(&mut ctx.accounts.bounty, geofence)?;
+
+        verify_and_record_reveal(
+            &ctx.accounts.global_state,
+            &mut ctx.accounts.bounty,
+            ctx.accounts
+                .mission_usage
+                .as_deref_mut()
+                .map(|u| &mut **u)
+                .zip(ctx.bumps.mission_usage),
+            mission_id,
+            &[&mission_id, &salt],
+            salt,
+        )
+    }
+
+    /// Reveal a multi-field mission. The commitment is
+    /// hash(field_0 || field_1 || field_2 || salt), fields in that fixed
+    /// order; field 0 is passed as `mission_id` (stored on the bounty and
+    /// counted for reuse) and the rest as `other_fields`. Otherwise
+    /// identical to reveal_mission. Hot authority only.
+    pub fn reveal_mission_fields(
+        ctx: Context<RevealMissionFields>,
+        mission_id: [u8; 32],
+        other_fields: [[u8; 32]; MISSION_FIELD_COUNT - 1],
+        salt: [u8; 32],
+        geofence: Option<Geofence>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+        require!(ctx.accounts.blocked_mission.data_is_empty(), SeekError::MissionBlocked);
+        verify_geofence(&mut ctx.accounts.bounty, geofence)?;
+
+        let mut preimage: Vec<&[u8]> = vec![&mission_id];
+        preimage.extend(other_fields.iter().map(|f| f.as_slice()));
+        preimage.push(&salt);
+
+        verify_and_record_reveal(
+            &ctx.accounts.global_state,
+            &mut ctx.accounts.bounty,
+            ctx.accounts
+                .mission_usage
+                .as_deref_mut()
+                .map(|u| &mut **u)
+                .zip(ctx.bumps.mission_usage),
+            mission_id,
+            &preimage,
+            salt,
+        )
+    }
+
+    /// Propose bounty resolution (OPTIMISTIC) - starts challenge period
+    /// Result is NOT final until challenge period ends
+    /// success = true: proposes win
+    /// success = false: proposes loss
+    /// Tiers in `fast_resolve_tiers` skip the challenge period and are
+    /// paid out or distributed in this same instruction, except for losses
+    /// the player can still dispute (see settles_at_proposal).
+    pub fn propose_resolution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProposeResolution<'info>>,
+        success: bool,
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let dispute_disabled = !success && ctx.accounts.loss_oracle.is_some();
+        let fast_resolve = ctx.accounts.global_state.settles_at_proposal(
+            ctx.accounts.bounty.tier,
+            success,
+            dispute_disabled,
+        )?;
+        record_proposal(&mut ctx.accounts.bounty, success, fast_resolve, dispute_disabled)?;
+
+        if !fast_resolve {
+            return Ok(());
+        }
+
+        let global_state = &mut ctx.accounts.global_state;
+        let (protocol_destinations, _) =
+            take_protocol_destinations(global_state, ctx.remaining_accounts)?;
+        let vaults = VaultAccounts {
+            global_state: global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            charity_vault: ctx.accounts.charity_vault.as_ref().map(|c| c.to_account_info()),
+            protocol_destinations,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: global_state.bump,
+        };
+        let player_token_info = ctx
+            .accounts
+            .player_token_account
+            .as_ref()
+            .map(|p| p.to_account_info());
+
+        settle_resolution(
+            global_state,
+            &mut ctx.accounts.bounty,
+            ctx.accounts.house_vault.amount,
+            &vaults,
+            &ctx.accounts.skr_mint.to_account_info(),
+            player_token_info.as_ref(),
+            PlayerBooks {
+                stats: &mut ctx.accounts.player_stats,
+                loyalty_escrow: ctx.accounts.loyalty_escrow.as_deref_mut().map(|e| &mut **e),
+            },
+        )?;
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// reveal_mission followed by propose_resolution in one instruction,
+    /// for operators running a trusted backend: every check of both steps
+    /// still applies, and the resulting state is the same as the two-step
+    /// flow. Hot authority only.
+    pub fn reveal_and_propose<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevealAndPropose<'info>>,
+        mission_id: [u8; 32],
+        salt: [u8; 32],
+        geofence: Option<Geofence>,
+        success: bool,
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+        require!(ctx.accounts.blocked_mission.data_is_empty(), SeekError::MissionBlocked);
+        verify_geofence(&mut ctx.accounts.bounty, geofence)?;
+
+        verify_and_record_reveal(
+            &ctx.accounts.global_state,
+            &mut ctx.accounts.bounty,
+            ctx.accounts
+                .mission_usage
+                .as_deref_mut()
+                .map(|u| &mut **u)
+                .zip(ctx.bumps.mission_usage),
+            mission_id,
+            &[&mission_id, &salt],
+            salt,
+        )?;
+
+        let dispute_disabled = !success && ctx.accounts.loss_oracle.is_some();
+        let fast_resolve = ctx.accounts.global_state.settles_at_proposal(
+            ctx.accounts.bounty.tier,
+            success,
+            dispute_disabled,
+        )?;
+        record_proposal(&mut ctx.accounts.bounty, success, fast_resolve, dispute_disabled)?;
+
+        if !fast_resolve {
+            return Ok(());
+        }
+
+        let global_state = &mut ctx.accounts.global_state;
+        let (protocol_destinations, _) =
+            take_protocol_destinations(global_state, ctx.remaining_accounts)?;
+        let vaults = VaultAccounts {
+            global_state: global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            charity_vault: ctx.accounts.charity_vault.as_ref().map(|c| c.to_account_info()),
+            protocol_destinations,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: global_state.bump,
+        };
+        let player_token_info = ctx
+            .accounts
+            .player_token_account
+            .as_ref()
+            .map(|p| p.to_account_info());
+
+        settle_resolution(
+            global_state,
+            &mut ctx.accounts.bounty,
+            ctx.accounts.house_vault.amount,
+            &vaults,
+            &ctx.accounts.skr_mint.to_account_info(),
+            player_token_info.as_ref(),
+            PlayerBooks {
+                stats: &mut ctx.accounts.player_stats,
+                loyalty_escrow: ctx.accounts.loyalty_escrow.as_deref_mut().map(|e| &mut **e),
+            },
+        )?;
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Record a hunt that ran out without an on-time photo as a loss and
+    /// distribute the entry immediately, skipping commit-reveal and the
+    /// challenge period. Hot authority only. Allowed from the moment the
+    /// timer expires until the refund grace ends; after that the player's
+    /// full refund (cancel_bounty) takes precedence. A bounty whose player
+    /// recorded a submission in time can't be timed out.
+    pub fn mark_timeout<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MarkTimeout<'info>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let bounty = &mut ctx.accounts.bounty;
+        let global_state = &mut ctx.accounts.global_state;
+
+        require!(
+            (bounty.status == BountyStatus::Pending || bounty.status == BountyStatus::Submitted)
+                && bounty.resolved_at == 0,
+            SeekError::ExpectedPending
+        );
+        require!(bounty.submitted_at == 0, SeekError::SubmittedInTime);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(!bounty.timer_running(current_time), SeekError::BountyNotExpired);
+        require!(
+            current_time <= bounty.expires_at + EXPIRED_REFUND_GRACE,
+            SeekError::TimeoutWindowClosed
+        );
+
+        bounty.resolved_at = current_time;
+        bounty.challenge_ends_at = current_time;
+        bounty.proposed_win = false;
+        bounty.timeout = true;
+        bounty.resolution_source = ResolutionSource::Timeout as u8;
+
+        let (protocol_destinations, _) =
+            take_protocol_destinations(global_state, ctx.remaining_accounts)?;
+        let vaults = VaultAccounts {
+            global_state: global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            charity_vault: None,
+            protocol_destinations,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: global_state.bump,
+        };
+
+        settle_resolution(
+            global_state,
+            bounty,
+            ctx.accounts.house_vault.amount,
+            &vaults,
+            &ctx.accounts.skr_mint.to_account_info(),
+            None,
+            PlayerBooks { stats: &mut ctx.accounts.player_stats, loyalty_escrow: None },
+        )?;
+
+        msg!("Bounty timed out: no on-time photo");
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Waive the remaining challenge period on a proposed WIN so it can be
+    /// finalized immediately. Player only. The challenge period exists to
+    /// protect the player's right to dispute a loss; on a win the player is
+    /// the only party it protects, so they may give it up. Losses cannot be
+    /// waived.
+    pub fn waive_challenge(ctx: Context<WaiveChallenge>) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let bounty = &mut ctx.accounts.bounty;
+
+        require!(
+            bounty.status == BountyStatus::ChallengeWon,
+            SeekError::ChallengeNotWaivable
+        );
+
+        let current_time = Clock::get()?.unix_timestamp;
+        if current_time < bounty.challenge_ends_at {
+            bounty.challenge_ends_at = current_time;
+        }
+
+        emit!(ChallengeWaived {
+            bounty: bounty.key(),
+            player: bounty.player,
+            challenge_ends_at: bounty.challenge_ends_at,
+        });
+
+        msg!("Challenge period waived by player | Finalizable at: {}", bounty.challenge_ends_at);
+
+        Ok(())
+    }
+
+    /// Finalize bounty - called after challenge period ends (if no dispute)
+    /// Actually executes the payout or distribution. With
+    /// player_claim_required set, a win must be finalized by the player.
+    pub fn finalize_bounty<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeBounty<'info>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        log_compute_units!("finalize_bounty: start");
+
+        let bounty = &mut ctx.accounts.bounty;
+        let global_state = &mut ctx.accounts.global_state;
+
+        // A disputed bounty is the authority's to settle (resolve_dispute),
+        // so it's rejected the same way in every state
+        require!(!bounty.in_dispute(), SeekError::AlreadyDisputed);
+
+        // Verify bounty is in challenge period
+        require!(
+            bounty.status == BountyStatus::ChallengeWon || bounty.status == BountyStatus::ChallengeLost,
+            SeekError::ExpectedChallenge
+        );
+
+        // Verify challenge period (plus finalize grace on losses) has ended.
+        // The window is half-open: dispute_bounty accepts `now < deadline`,
+        // finalize accepts `now >= deadline`, so a dispute and a finalize can
+        // never both be valid at the same timestamp. Whichever lands first
+        // wins the race; the other fails on status or time.
+        let current_time = Clock::get()?.unix_timestamp;
+        require!(
+            current_time >= finalize_deadline(bounty, global_state)?,
+            SeekError::ChallengePeriodActive
+        );
+
+        // Optionally, only the player may collect a win
+        require!(
+            !(bounty.proposed_win && global_state.player_claim_required)
+                || ctx.accounts.caller.key() == bounty.player,
+            SeekError::PlayerClaimRequired
+        );
+
+        let (protocol_destinations, _) =
+            take_protocol_destinations(global_state, ctx.remaining_accounts)?;
+        let vaults = VaultAccounts {
+            global_state: global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            charity_vault: ctx.accounts.charity_vault.as_ref().map(|c| c.to_account_info()),
+            protocol_destinations,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: global_state.bump,
+        };
+        let player_token_info = ctx
+            .accounts
+            .player_token_account
+            .as_ref()
+            .map(|p| p.to_account_info());
+
+        settle_resolution(
+            global_state,
+            bounty,
+            ctx.accounts.house_vault.amount,
+            &vaults,
+            &ctx.accounts.skr_mint.to_account_info(),
+            player_token_info.as_ref(),
+            PlayerBooks {
+                stats: &mut ctx.accounts.player_stats,
+                loyalty_escrow: ctx.accounts.loyalty_escrow.as_deref_mut().map(|e| &mut **e),
+            },
+        )?;
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Read-only: the exact loss distribution `finalize_bounty` would produce
+    /// for `bet_amount`, returned via return data. Lets auditors check the
+    /// per-tier amounts without settling a real bounty.
+    pub fn simulate_loss(_ctx: Context<SimulateLoss>, bet_amount: u64) -> Result<LossShares> {
+        compute_loss_shares(bet_amount)
+    }
+
+    /// Read-only: protocol PDA addresses and bumps in one call, returned via
+    /// return data, so clients don't re-derive and guess bumps. Vault
+    /// addresses and bumps come from GlobalState (the canonical record).
+    pub fn get_pda_info(ctx: Context<GetPdaInfo>) -> Result<PdaInfo> {
+        let global_state = &ctx.accounts.global_state;
+
+        Ok(PdaInfo {
+            global_state: global_state.key(),
+            global_state_bump: global_state.bump,
+            house_vault: global_state.house_vault,
+            house_vault_bump: global_state.house_vault_bump,
+            singularity_vault: global_state.singularity_vault,
+            singularity_vault_bump: global_state.singularity_vault_bump,
+        })
+    }
+
+    /// Read-only: lifetime protocol statistics (balances, bounty outcomes,
+    /// dispute counters) in one call, via return data.
+    pub fn get_protocol_stats(ctx: Context<GetPdaInfo>) -> Result<ProtocolStats> {
+        let g = &ctx.accounts.global_state;
+
+        Ok(ProtocolStats {
+            house_fund_balance: g.house_fund_balance,
+            singularity_balance: g.singularity_balance,
+            total_burned: g.total_burned,
+            total_bounties_created: g.total_bounties_created,
+            total_bounties_won: g.total_bounties_won,
+            total_bounties_lost: g.total_bounties_lost,
+            total_singularity_wins: g.total_singularity_wins,
+            total_protocol_revenue: g.total_protocol_revenue,
+            total_disputes_filed: g.total_disputes_filed,
+            total_disputes_won_by_player: g.total_disputes_won_by_player,
+            total_dispute_stake_forfeited: g.total_dispute_stake_forfeited,
+        })
+    }
+
+    /// Read-only: the whole effective configuration in one call, via return
+    /// data, so clients don't stitch together reads or guess defaults.
+    pub fn get_config(ctx: Context<GetPdaInfo>) -> Result<ProtocolConfig> {
+        let g = &ctx.accounts.global_state;
+
+        Ok(ProtocolConfig {
+            authority: g.authority,
+            hot_authority: g.hot_authority,
+            protocol_treasury: g.protocol_treasury,
+            charity_vault: g.charity_vault,
+            loss_oracle: g.loss_oracle,
+            protocol_destinations: g.protocol_destinations,
+            protocol_weights: g.protocol_weights,
+            paused: g.paused,
+            shutdown: g.shutdown,
+            accepting_enabled: g.accepting_enabled,
+            burn_enabled: g.burn_enabled,
+            auto_compound_protocol: g.auto_compound_protocol,
+            prefund_payout: g.prefund_payout,
+            player_claim_required: g.player_claim_required,
+            require_dispute_evidence: g.require_dispute_evidence,
+            dispute_wins_roll_jackpot: g.dispute_wins_roll_jackpot,
+            co_disputes_enabled: g.co_disputes_enabled,
+            accept_ordering: g.accept_ordering,
+            shutdown_jackpot_policy: g.shutdown_jackpot_policy,
+            escheat_policy: g.escheat_policy,
+            fast_resolve_tiers: g.fast_resolve_tiers,
+            payout_multiplier_bps: g.payout_multiplier_bps,
+            min_payout: g.min_payout,
+            win_burn_bps: g.win_burn_bps,
+            win_jackpot_bps: g.win_jackpot_bps,
+            loyalty_rebate_bps: g.loyalty_rebate_bps,
+            charity_jackpot_bps: g.charity_jackpot_bps,
+            loss_streak_boost_bps: g.loss_streak_boost_bps,
+            max_loss_streak_boost_bps: g.max_loss_streak_boost_bps,
+            min_cancel_fee_bps: g.min_cancel_fee_bps,
+            max_cancel_fee_bps: g.max_cancel_fee_bps,
+            dispute_fee: g.dispute_fee,
+            dispute_win_bonus_bps: g.dispute_win_bonus_bps,
+            dispute_loss_refund_bps: g.dispute_loss_refund_bps,
+            forfeit_stake_treasury_bps: g.forfeit_stake_treasury_bps,
+            tier_dispute_stake_bps: g.tier_dispute_stake_bps,
+            tier_durations: g.tier_durations,
+            max_duration: g.max_duration,
+            finalize_grace: g.finalize_grace,
+            commitment_ttl: g.commitment_ttl,
+            max_mission_reuse: g.max_mission_reuse,
+            global_rate_limit: g.global_rate_limit,
+            rate_limit_window: g.rate_limit_window,
+            tier_daily_caps: g.tier_daily_caps,
+            daily_wager_cap: g.daily_wager_cap,
+            min_launch_balance: g.min_launch_balance,
+            max_epoch_house_loss: g.max_epoch_house_loss,
+            max_jackpot_per_epoch: g.max_jackpot_per_epoch,
+            min_jackpot_roll_balance: g.min_jackpot_roll_balance,
+            jackpot_cooldown: g.jackpot_cooldown,
+            min_hunt_slots: g.min_hunt_slots,
+            auto_fund_house: g.auto_fund_house,
+            auto_fund_threshold: g.auto_fund_threshold,
+            auto_fund_amount: g.auto_fund_amount,
+            max_active_global: g.max_active_global,
+        })
+    }
+
+    /// Read-only: the player's entry and dispute stake held by the house for
+    /// this bounty, and the refund a winning dispute would pay, via return
+    /// data. Lets a UI show exactly what is at risk while a dispute is open.
+    pub fn get_player_at_risk(ctx: Context<GetPlayerAtRisk>) -> Result<PlayerAtRisk> {
+        let bounty = &ctx.accounts.bounty;
+
+        let potential_refund = if bounty.status == BountyStatus::Disputed {
+            let total_stake = bounty
+                .dispute_stake
+                .checked_add(bounty.co_dispute_stake)
+                .ok_or(SeekError::MathOverflow)?;
+            bounty.entry_amount
+                .checked_add(dispute_stake_return(
+                    bounty.entry_amount,
+                    ctx.accounts.global_state.dispute_win_bonus_bps,
+                    bounty.dispute_stake,
+                    total_stake,
+                )?)
+                .ok_or(SeekError::MathOverflow)?
+        } else {
+            0
+        };
+
+        Ok(PlayerAtRisk {
+            bet: bounty.entry_amount,
+            dispute_stake: bounty.dispute_stake,
+            potential_refund,
+        })
+    }
+
+    /// Read-only: the settled result of a Won or Lost bounty, via return
+    /// data, for leaderboards and other integrations verifying a reported
+    /// win. Fails with BountyNotFinalized on any other status.
+    pub fn verify_win(ctx: Context<VerifyWin>) -> Result<WinProof> {
+        let bounty = &ctx.accounts.bounty;
+        require!(
+            matches!(bounty.status, BountyStatus::Won | BountyStatus::Lost),
+            SeekError::BountyNotFinalized
+        );
+
+        let is_won = bounty.status == BountyStatus::Won && !bounty.practice;
+        Ok(WinProof {
+            is_won,
+            payout: if is_won { bounty.payout_amount } else { 0 },
+            singularity_won: is_won && bounty.singularity_won,
+            finalized_at: bounty.finalized_at,
+        })
+    }
+
+    /// Read-only: the player's lifetime net result (`total_won -
+    /// total_wagered`, as magnitude + sign) and win rate, via return data.
+    pub fn get_player_pnl(ctx: Context<GetPlayerPnl>) -> Result<PlayerPnl> {
+        let stats = &ctx.accounts.player_stats;
+
+        let settled = u64::from(stats.wins) + u64::from(stats.losses);
+        let win_rate_bps = if settled == 0 {
+            0
+        } else {
+            u16::try_from(mul_div(u64::from(stats.wins), 10000, settled)?)
+                .map_err(|_| SeekError::MathOverflow)?
+        };
+
+        Ok(PlayerPnl {
+            total_wagered: stats.total_wagered,
+            total_won: stats.total_won,
+            net_pnl: stats.total_won.abs_diff(stats.total_wagered),
+            net_negative: stats.total_won < stats.total_wagered,
+            win_rate_bps,
+        })
+    }
+
+    /// Read-only: whether a new bounty of `tier` could be accepted right now,
+    /// plus an `AcceptBlockReason` code, via return data. Mirrors
+    /// accept_bounty's shutdown/pause/launch gates, rate-limit and tier-cap checks, and reports the house
+    /// unable to back the payout (net of prefund reservations) even when
+    /// prefunding is off, so frontends can grey out a tier the house can't
+    /// safely cover.
+    pub fn can_accept_tier(ctx: Context<CanAcceptTier>, tier: u8) -> Result<TierAvailability> {
+        let global_state = &ctx.accounts.global_state;
+        let now = Clock::get()?.unix_timestamp;
+
+        let reason = match get_tier_entry(tier, global_state.decimals_multiplier()) {
+            _ if global_state.shutdown => AcceptBlockReason::Shutdown,
+            _ if global_state.paused => AcceptBlockReason::Paused,
+            _ if !global_state.accepting_enabled => AcceptBlockReason::AcceptingDisabled,
+            _ if global_state.epoch_loss_cap_reached() => AcceptBlockReason::EpochLossCapReached,
+            _ if global_state.at_capacity() => AcceptBlockReason::ProtocolAtCapacity,
+            Err(_) => AcceptBlockReason::InvalidTier,
+            Ok(_) if !global_state.rate_limit_open(now)? => AcceptBlockReason::GlobalRateLimited,
+            Ok(_) if !global_state.tier_cap_open(tier, now)? => {
+                AcceptBlockReason::TierDailyCapReached
+            }
+            Ok(entry) => {
+                let payout = compute_payout(entry, global_state.payout_multiplier_bps)?;
+                if global_state.can_back_payout(entry, payout)? {
+                    AcceptBlockReason::None
+                } else {
+                    AcceptBlockReason::InsufficientHouseFunds
+                }
+            }
+        };
+
+        Ok(TierAvailability {
+            can_accept: reason == AcceptBlockReason::None,
+            reason_code: reason as u8,
+        })
+    }
+
+    /// Read-only: how much SKR the house needs to back `count` more bounties
+    /// of `tier` at the current payout multiplier, on top of payouts already
+    /// reserved, via return data. Entries the new bounties bring in are not
+    /// counted, so the shortfall is the safe deposit.
+    pub fn required_house_funding(
+        ctx: Context<GetPdaInfo>,
+        tier: u8,
+        count: u32,
+    ) -> Result<HouseFunding> {
+        let g = &ctx.accounts.global_state;
+
+        let payout = compute_payout(
+            get_tier_entry(tier, g.decimals_multiplier())?,
+            g.payout_multiplier_bps,
+        )?;
+        let target_liability = payout
+            .checked_mul(u64::from(count))
+            .ok_or(SeekError::MathOverflow)?;
+        let total_liability = g
+            .outstanding_liability
+            .checked_add(target_liability)
+            .ok_or(SeekError::MathOverflow)?;
+
+        Ok(HouseFunding {
+            target_liability,
+            total_liability,
+            shortfall: total_liability.saturating_sub(g.house_fund_balance),
+        })
+    }
+
+    /// Close the current epoch and start the next, resetting the per-epoch
+    /// counters (and with them the house loss breaker). Permissionless once
+    /// EPOCH_DURATION has passed since the epoch started.
+    pub fn advance_epoch(ctx: Context<AdvanceEpoch>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let now = Clock::get()?.unix_timestamp;
+
+        let next_epoch_at = global_state
+            .epoch_started_at
+            .checked_add(EPOCH_DURATION)
+            .ok_or(SeekError::MathOverflow)?;
+        require!(now >= next_epoch_at, SeekError::EpochNotOver);
+
+        let house_net_last_epoch = global_state.house_net_this_epoch;
+        let lifetime = global_state.lifetime_totals();
+        let last_epoch = lifetime.since(&global_state.epoch_opening_totals)?;
+        global_state.epoch_opening_totals = lifetime;
+        global_state.epoch = global_state
+            .epoch
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
+        global_state.epoch_started_at = now;
+        global_state.house_net_this_epoch = 0;
+        global_state.singularity_paid_this_epoch = 0;
+
+        emit!(EpochAdvanced {
+            epoch: global_state.epoch,
+            started_at: now,
+            house_net_last_epoch,
+            last_epoch,
+        });
+
+        msg!("Epoch {} started | Last epoch house net: {}", global_state.epoch, house_net_last_epoch);
+        Ok(())
+    }
+
+    /// Fund the house vault - authority deposits SKR for player payouts
+    pub fn fund_house(ctx: Context<FundHouse>, amount: u64) -> Result<()> {
+        require!(amount > 0, SeekError::InvalidAmount);
+
+        // Transfer from authority to house vault
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                to: ctx.accounts.house_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        // Update tracked balance
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .checked_add(amount)
+            .ok_or(SeekError::MathOverflow)?;
+
+        // Open accepting the first time the house reaches the launch threshold
+        if !global_state.accepting_enabled
+            && global_state.house_fund_balance >= global_state.min_launch_balance
+        {
+            global_state.accepting_enabled = true;
+            msg!("Launch balance reached: accepting enabled");
+        }
+
+        // Emit event
+        emit!(HouseFunded {
+            authority: ctx.accounts.authority.key(),
+            amount,
+            new_balance: global_state.house_fund_balance,
+        });
+
+        msg!("House funded with {} SKR", global_state.whole_skr(amount));
+        msg!("New balance: {} SKR", global_state.whole_skr(global_state.house_fund_balance));
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Top up the singularity pool directly (e.g. for a jackpot promotion)
+    /// instead of waiting for losses to feed it. Not after shutdown, when no
+    /// win can roll the jackpot any more. Cold authority only.
+    pub fn fund_singularity(ctx: Context<FundSingularity>, amount: u64) -> Result<()> {
+        require!(amount > 0, SeekError::InvalidAmount);
+        require!(!ctx.accounts.global_state.shutdown, SeekError::ProtocolShutdown);
+
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                to: ctx.accounts.singularity_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.singularity_balance = global_state
+            .singularity_balance
+            .checked_add(amount)
+            .ok_or(SeekError::MathOverflow)?;
+
+        emit!(SingularityFunded {
+            authority: ctx.accounts.authority.key(),
+            amount,
+            new_balance: global_state.singularity_balance,
+        });
+
+        msg!("Singularity funded with {} SKR", global_state.whole_skr(amount));
+        msg!("New jackpot: {} SKR", global_state.whole_skr(global_state.singularity_balance));
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Kill switch: stop accepting new bounties, permanently. Bounties already
+    /// in flight can still be resolved, finalized, disputed and cancelled.
+    /// Cold authority only.
+    pub fn trigger_shutdown(ctx: Context<UpdateConfig>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        require!(!global_state.shutdown, SeekError::ProtocolShutdown);
+        global_state.shutdown = true;
+
+        emit!(ProtocolShutdown {
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Protocol SHUT DOWN: no new bounties will be accepted");
+        Ok(())
+    }
+
+    /// Empty a wound-down deployment's vaults: move the rest of the house
+    /// and singularity vaults to an SKR account of the cold authority and
+    /// close both vault token accounts, their rent going to the authority.
+    /// Only after trigger_shutdown, once every bounty is settled or
+    /// cancelled and every loyalty rebate claimed. Run
+    /// distribute_jackpot_on_shutdown first for the jackpot policy to apply.
+    /// Cold authority only.
+    pub fn sweep_vaults(ctx: Context<SweepVaults>) -> Result<()> {
+        require_wound_down(&ctx.accounts.global_state)?;
+
+        let house_amount = ctx.accounts.house_vault.amount;
+        let singularity_amount = ctx.accounts.singularity_vault.amount;
+        let vaults = VaultAccounts {
+            global_state: ctx.accounts.global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.authority_token_account.to_account_info(),
+            charity_vault: None,
+            protocol_destinations: Vec::new(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: ctx.accounts.global_state.bump,
+        };
+        sweep_vaults_out(
+            &mut ctx.accounts.global_state,
+            &vaults,
+            house_amount,
+            singularity_amount,
+            &ctx.accounts.authority_token_account.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+        )?;
+
+        emit!(VaultsSwept {
+            authority: ctx.accounts.authority.key(),
+            house_amount,
+            singularity_amount,
+            destination: ctx.accounts.authority_token_account.key(),
+        });
+
+        msg!(
+            "Vaults swept and closed: {} SKR to the authority",
+            ctx.accounts.global_state.whole_skr(house_amount.saturating_add(singularity_amount))
+        );
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Decommission a wound-down deployment: close GlobalState and return its
+    /// rent to the cold authority. Only after trigger_shutdown, once every
+    /// bounty is settled or cancelled and sweep_vaults has closed both
+    /// vaults. Cold authority only.
+    pub fn close_protocol(ctx: Context<CloseProtocol>) -> Result<()> {
+        require_wound_down(&ctx.accounts.global_state)?;
+        require_vaults_closed(&ctx.accounts.house_vault, &ctx.accounts.singularity_vault)?;
+
+        emit!(ProtocolClosed {
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Protocol closed: GlobalState rent returned to the authority");
+        Ok(())
+    }
+
+    /// Choose where the singularity pool goes after shutdown
+    /// (`ShutdownJackpotPolicy` code). Cold authority only.
+    pub fn set_shutdown_jackpot_policy(ctx: Context<UpdateConfig>, policy: u8) -> Result<()> {
+        ShutdownJackpotPolicy::from_code(policy)?;
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.shutdown_jackpot_policy = policy;
+
+        msg!("Shutdown jackpot policy set to {}", policy);
+        Ok(())
+    }
+
+    /// Empty the singularity vault per `shutdown_jackpot_policy`. Only after
+    /// trigger_shutdown, since no further wins can roll the jackpot. Moves the
+    /// live vault balance so any untracked dust goes too. Cold authority only.
+    pub fn distribute_jackpot_on_shutdown(ctx: Context<DistributeJackpotOnShutdown>) -> Result<()> {
+        require!(ctx.accounts.global_state.shutdown, SeekError::ProtocolNotShutdown);
+
+        let policy = ShutdownJackpotPolicy::from_code(ctx.accounts.global_state.shutdown_jackpot_policy)?;
+        let amount = ctx.accounts.singularity_vault.amount;
+        let vaults = VaultAccounts {
+            global_state: ctx.accounts.global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            charity_vault: None,
+            protocol_destinations: Vec::new(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: ctx.accounts.global_state.bump,
+        };
+        let destination = match policy {
+            ShutdownJackpotPolicy::ReturnToHouse => &vaults.house_vault,
+            ShutdownJackpotPolicy::SendToTreasury => &vaults.protocol_treasury,
+        };
+
+        let global_state = &mut ctx.accounts.global_state;
+        if amount > 0 {
+            vaults.transfer(&vaults.singularity_vault, destination, amount)?;
+        }
+
+        global_state.singularity_balance = 0;
+        if policy == ShutdownJackpotPolicy::ReturnToHouse {
+            global_state.house_fund_balance = global_state
+                .house_fund_balance
+                .checked_add(amount)
+                .ok_or(SeekError::MathOverflow)?;
+        }
+
+        emit!(JackpotDistributedOnShutdown {
+            policy: policy as u8,
+            amount,
+            destination: destination.key(),
+        });
+
+        msg!("Shutdown: {} SKR moved out of the singularity vault", global_state.whole_skr(amount));
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Choose where escheat_unclaimed_jackpot sends an unclaimed win's
+    /// payout (`EscheatPolicy` code). Cold authority only.
+    pub fn set_escheat_policy(ctx: Context<UpdateConfig>, policy: u8) -> Result<()> {
+        EscheatPolicy::from_code(policy)?;
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.escheat_policy = policy;
+
+        msg!("Escheat policy set to {}", policy);
+        Ok(())
+    }
+
+    /// Close out a win its player never claimed. With player_claim_required
+    /// set, only the player can finalize a win, so one they walk away from
+    /// would sit in ChallengeWon forever, its payout reserved. Once
+    /// ESCHEAT_TIMEOUT has passed since the challenge period ended, the
+    /// payout moves from the house to the singularity pool or the protocol
+    /// treasury per `escheat_policy`, and the bounty ends Lost. Cold
+    /// authority only.
+    pub fn escheat_unclaimed_jackpot(ctx: Context<EscheatUnclaimedJackpot>) -> Result<()> {
+        let bounty = &mut ctx.accounts.bounty;
+        let global_state = &mut ctx.accounts.global_state;
+
+        require!(!bounty.in_dispute(), SeekError::AlreadyDisputed);
+        require!(bounty.status == BountyStatus::ChallengeWon, SeekError::ExpectedChallenge);
+        require!(global_state.player_claim_required, SeekError::ClaimNotRequired);
+
+        let current_time = Clock::get()?.unix_timestamp;
+        let escheat_at = finalize_deadline(bounty, global_state)?
+            .checked_add(ESCHEAT_TIMEOUT)
+            .ok_or(SeekError::MathOverflow)?;
+        require!(current_time >= escheat_at, SeekError::EscheatTooEarly);
+
+        let policy = EscheatPolicy::from_code(global_state.escheat_policy)?;
+        retire_bounty(global_state, bounty)?;
+        let amount = if bounty.practice { 0 } else { bounty.payout_amount };
+        require!(
+            global_state.unreserved(ctx.accounts.house_vault.amount) >= amount,
+            SeekError::InsufficientHouseFunds
+        );
+
+        let vaults = VaultAccounts {
+            global_state: global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            charity_vault: None,
+            protocol_destinations: Vec::new(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: global_state.bump,
+        };
+        let destination = match policy {
+            EscheatPolicy::ReturnToPool => &vaults.singularity_vault,
+            EscheatPolicy::SendToTreasury => &vaults.protocol_treasury,
+        };
+        if amount > 0 {
+            vaults.transfer(&vaults.house_vault, destination, amount)?;
+        }
+
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .checked_sub(amount)
+            .ok_or(SeekError::MathOverflow)?;
+        if policy == EscheatPolicy::ReturnToPool {
+            global_state.singularity_balance = global_state
+                .singularity_balance
+                .checked_add(amount)
+                .ok_or(SeekError::MathOverflow)?;
+        }
+
+        bounty.status = BountyStatus::Lost;
+        bounty.finalized_at = current_time;
+
+        emit!(UnclaimedJackpotEscheated {
+            bounty: bounty.key(),
+            player: bounty.player,
+            policy: policy as u8,
+            amount,
+            destination: destination.key(),
+        });
+
+        msg!("Unclaimed win escheated: {} SKR", global_state.whole_skr(amount));
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Burn SKR from the protocol treasury (deflationary lever for the DAO).
+    /// Requires the cold authority AND the treasury's token owner: the
+    /// treasury is an external wallet, not a PDA, so the program cannot sign
+    /// for it. Adds to `total_burned`.
+    pub fn burn_treasury(ctx: Context<BurnTreasury>, amount: u64) -> Result<()> {
+        require!(amount > 0, SeekError::InvalidAmount);
+        require!(ctx.accounts.global_state.burn_enabled, SeekError::BurnDisabled);
+
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.skr_mint.to_account_info(),
+                from: ctx.accounts.protocol_treasury.to_account_info(),
+                authority: ctx.accounts.treasury_owner.to_account_info(),
+            },
+        );
+        token::burn(burn_ctx, amount)?;
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.total_burned = global_state
+            .total_burned
+            .checked_add(amount)
+            .ok_or(SeekError::MathOverflow)?;
+
+        emit!(TreasuryBurned {
+            authority: ctx.accounts.authority.key(),
+            amount,
+            total_burned: global_state.total_burned,
+        });
+
+        msg!("Burned {} SKR from treasury", global_state.whole_skr(amount));
+        msg!("Total burned: {} SKR", global_state.whole_skr(global_state.total_burned));
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Dispute a bounty result - player stakes additional SKR to challenge
+    /// Can only dispute LOSS results during challenge period. Only the
+    /// bounty's player can open a dispute (DisputeBounty pins the signer);
+    /// anyone else can at most join it via join_dispute.
+    pub fn dispute_bounty(ctx: Context<DisputeBounty>) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let bounty = &mut ctx.accounts.bounty;
+        let clock = Clock::get()?;
+        let current_time = clock.unix_timestamp;
+
+        // A keeper's finalize_bounty may have landed first in this block
+        require!(
+            !matches!(
+                bounty.status,
+                BountyStatus::Won | BountyStatus::Lost | BountyStatus::Cancelled
+            ),
+            SeekError::BountyAlreadyFinalized
+        );
+
+        // Can only dispute losses (no point disputing wins)
+        require!(
+            bounty.status == BountyStatus::ChallengeLost,
+            SeekError::ExpectedChallenge
+        );
+
+        // Must be within challenge period (including finalize grace)
+        require!(
+            current_time < finalize_deadline(bounty, &ctx.accounts.global_state)?,
+            SeekError::ChallengePeriodEnded
+        );
+
+        // Cannot dispute twice
+        require!(!bounty.is_disputed, SeekError::AlreadyDisputed);
+
+        // Oracle-attested losses are final
+        require!(!bounty.dispute_disabled, SeekError::DisputeNotAllowed);
+
+        // Calculate dispute stake (the tier's share of the original entry,
+        // 50% by default)
+        let dispute_stake_bps = ctx.accounts.global_state.dispute_stake_bps(bounty.tier)?;
+        let dispute_stake = bps_of(bounty.entry_amount, dispute_stake_bps)?;
+
+        // Transfer dispute stake from player to house vault
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player_token_account.to_account_info(),
+                to: ctx.accounts.house_vault.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, dispute_stake)?;
+
+        // Arbitration fee goes straight to the treasury and is never
+        // refunded, whatever the ruling
+        let dispute_fee = ctx.accounts.global_state.dispute_fee;
+        if dispute_fee > 0 {
+            let fee_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.player_token_account.to_account_info(),
+                    to: ctx.accounts.protocol_treasury.to_account_info(),
+                    authority: ctx.accounts.player.to_account_info(),
+                },
+            );
+            token::transfer(fee_ctx, dispute_fee)?;
+        }
+        ctx.accounts.player_stats.record_wager(
+            dispute_stake
+                .checked_add(dispute_fee)
+                .ok_or(SeekError::MathOverflow)?,
+        )?;
+
+        // Track dispute stake in house balance
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .checked_add(dispute_stake)
+            .ok_or(SeekError::MathOverflow)?;
+
+        global_state.total_disputes_filed = global_state
+            .total_disputes_filed
+            .checked_add(1)
+            .ok_or(SeekError::MathOverflow)?;
+
+        // Only accept disputes the house could honor if the player wins,
+        // without touching other bounties' reservations. A prefunded
+        // bounty's own reservation is released on a win, so it counts.
+        let own_reservation = if bounty.prefunded { bounty.payout_amount } else { 0 };
+        require!(
+            global_state
+                .unreserved(global_state.house_fund_balance)
+                .saturating_add(own_reservation)
+                >= dispute_win_refund(
+                    bounty.entry_amount,
+                    dispute_stake,
+                    global_state.dispute_win_bonus_bps,
+                )?,
+            SeekError::InsufficientHouseFunds
+        );
+
+        // Mark as disputed
+        bounty.is_disputed = true;
+        bounty.dispute_stake = dispute_stake;
+        bounty.disputed_at = current_time;
+        bounty.status = BountyStatus::Disputed;
+
+        emit!(BountyDisputed {
+            bounty: bounty.key(),
+            player: bounty.player,
+            dispute_stake,
+            dispute_fee,
+        });
+
+        msg!("Bounty disputed! Stake: {} SKR | Fee: {} SKR",
+            global_state.whole_skr(dispute_stake),
+            global_state.whole_skr(dispute_fee)
+        );
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Back another player's open dispute with `stake` SKR. The stake joins
+    /// the house vault alongside the player's; if the dispute is won every
+    /// disputer gets their stake back plus a pro-rata share of the bonus,
+    /// if it's lost each forfeits like the player does. One join per
+    /// disputer, at most MAX_CO_DISPUTERS per bounty. Only while
+    /// co_disputes_enabled.
+    pub fn join_dispute(ctx: Context<JoinDispute>, stake: u64) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+        require!(
+            ctx.accounts.global_state.co_disputes_enabled,
+            SeekError::CoDisputesDisabled
+        );
+        require!(stake > 0, SeekError::InvalidAmount);
+
+        let bounty = &mut ctx.accounts.bounty;
+        require!(
+            bounty.status == BountyStatus::Disputed,
+            SeekError::ExpectedDisputed
+        );
+        require!(
+            ctx.accounts.disputer.key() != bounty.player,
+            SeekError::CannotJoinOwnDispute
+        );
+        require!(
+            bounty.co_disputers < MAX_CO_DISPUTERS,
+            SeekError::TooManyCoDisputers
+        );
+
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.disputer_token_account.to_account_info(),
+                to: ctx.accounts.house_vault.to_account_info(),
+                authority: ctx.accounts.disputer.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, stake)?;
+
+        let share = &mut ctx.accounts.dispute_share;
+        share.bounty = bounty.key();
+        share.disputer = ctx.accounts.disputer.key();
+        share.stake = stake;
+        share.bump = ctx.bumps.dispute_share;
+
+        bounty.co_dispute_stake = bounty
+            .co_dispute_stake
+            .checked_add(stake)
+            .ok_or(SeekError::MathOverflow)?;
+        bounty.co_disputers += 1;
+
+        // The stake comes back out of the house on a win, so it's tracked
+        // like the player's
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .checked_add(stake)
+            .ok_or(SeekError::MathOverflow)?;
+
+        let total_stake = bounty
+            .dispute_stake
+            .checked_add(bounty.co_dispute_stake)
+            .ok_or(SeekError::MathOverflow)?;
+        emit!(DisputeJoined {
+            bounty: bounty.key(),
+            disputer: share.disputer,
+            stake,
+            total_stake,
+        });
+
+        msg!("Dispute joined with {} SKR | Total stake: {} SKR",
+            global_state.whole_skr(stake),
+            global_state.whole_skr(total_stake)
+        );
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Resolve a dispute - authority reviews and decides
+    /// player_wins = true: player gets original entry back + dispute stake
+    ///   (+ dispute_win_bonus_bps of the entry, if configured)
+    /// player_wins = false: dispute stake forfeited, loss stands
+    /// reason_code: a `DisputeReason`, carried into `DisputeResolved`
+    /// Co-disputers (join_dispute) are settled pro-rata from one
+    /// `[dispute_share, disputer_token_account, disputer]` triple each in
+    /// `remaining_accounts`, after any protocol fee destinations.
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+        player_wins: bool,
+        reason_code: u8,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.global_state.require_dispute_evidence,
+            SeekError::EvidenceRequired
+        );
+
+        let (protocol_destinations, co_disputers) =
+            take_protocol_destinations(&ctx.accounts.global_state, ctx.remaining_accounts)?;
+        ctx.accounts.resolve(
+            DisputeRuling {
+                player_wins,
+                reason_code,
+            },
+            protocol_destinations,
+            co_disputers,
+        )
+    }
+
+    /// Resolve a dispute while attesting to the evidence reviewed: `photo_hash`
+    /// must match the hash the player recorded in submit_photo, tying the
+    /// ruling to that photo. Required when `require_dispute_evidence` is set.
+    pub fn resolve_dispute_with_evidence<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDispute<'info>>,
+        player_wins: bool,
+        reason_code: u8,
+        photo_hash: [u8; 32],
+    ) -> Result<()> {
+        let recorded = ctx.accounts.bounty.photo_hash;
+        require!(
+            recorded != [0u8; 32] && recorded == photo_hash,
+            SeekError::PhotoHashMismatch
+        );
+
+        let (protocol_destinations, co_disputers) =
+            take_protocol_destinations(&ctx.accounts.global_state, ctx.remaining_accounts)?;
+        ctx.accounts.resolve(
+            DisputeRuling {
+                player_wins,
+                reason_code,
+            },
+            protocol_destinations,
+            co_disputers,
+        )
+    }
+
+    /// Resolve many disputes in one transaction. Cold authority only.
+    /// `rulings[i]` is the ruling for the i-th bounty; `remaining_accounts`
+    /// carries one `[bounty, player_token_account, player_stats]` triple per
+    /// ruling, in order, after any protocol fee destinations
+    /// (set_protocol_split).
+    /// Bounties that are no longer `Disputed`, or that other players joined
+    /// (resolve those with resolve_dispute), are skipped rather than aborting
+    /// the batch. Solvency is re-checked against the live vault balance before
+    /// every player-win refund.
+    pub fn resolve_disputes_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResolveDisputesBatch<'info>>,
+        rulings: Vec<DisputeRuling>,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.global_state.require_dispute_evidence,
+            SeekError::EvidenceRequired
+        );
+
+        let (protocol_destinations, remaining) =
+            take_protocol_destinations(&ctx.accounts.global_state, ctx.remaining_accounts)?;
+        require!(
+            remaining.len() == rulings.len() * 3,
+            SeekError::BatchAccountsMismatch
+        );
+
+        let vaults = VaultAccounts {
+            global_state: ctx.accounts.global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            charity_vault: ctx.accounts.charity_vault.as_ref().map(|c| c.to_account_info()),
+            protocol_destinations,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: ctx.accounts.global_state.bump,
+        };
+        let global_state_key = ctx.accounts.global_state.key();
+        let mut resolved: u32 = 0;
+
+        for (item, &ruling) in remaining.chunks_exact(3).zip(rulings.iter()) {
+            let bounty_info = &item[0];
+            let player_token_info = &item[1];
+
+            require!(
+                bounty_info.is_writable,
+                anchor_lang::error::ErrorCode::ConstraintMut
+            );
+            let mut bounty: Account<'info, Bounty> = Account::try_from(bounty_info)?;
+            require!(
+                bounty.global_state == global_state_key,
+                SeekError::GlobalStateMismatch
+            );
+
+            if bounty.status != BountyStatus::Disputed {
+                msg!("Skipping {}: not disputed", bounty_info.key());
+                continue;
+            }
+            if bounty.co_disputers > 0 {
+                msg!("Skipping {}: has co-disputers", bounty_info.key());
+                continue;
+            }
+
+            // Same canonical-ATA pin as ResolveDispute.player_token_account.
+            require!(
+                player_token_info.key() == get_associated_token_address(&bounty.player, &SKR_MINT),
+                SeekError::Unauthorized
+            );
+            require!(
+                player_token_info.key() != ctx.accounts.house_vault.key(),
+                SeekError::InvalidTokenAccount
+            );
+            let mut player_stats = load_player_stats(&item[2], &bounty.player)?;
+
+            log_compute_units!("resolve_disputes_batch: item validated");
+
+            // Earlier refunds in this batch moved tokens out; re-read the vault.
+            ctx.accounts.house_vault.reload()?;
+
+            settle_dispute(
+                &mut ctx.accounts.global_state,
+                &mut bounty,
+                ruling,
+                ctx.accounts.house_vault.amount,
+                &vaults,
+                player_token_info,
+                &mut player_stats,
+            )?;
+
+            // Persist now so a duplicate entry later in the batch sees the
+            // terminal status and is skipped (and a repeated player's stats
+            // are re-read fresh).
+            bounty.exit(&crate::ID)?;
+            player_stats.exit(&crate::ID)?;
+            resolved += 1;
+            log_compute_units!("resolve_disputes_batch: item settled");
+        }
+
+        msg!("Batch resolved {} of {} disputes", resolved, rulings.len());
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Finalize several of the caller's own wins in one transaction, paying
+    /// every payout into their SKR token account. `remaining_accounts` is the
+    /// list of bounty PDAs (writable). Proposed losses, disputed bounties and
+    /// wins still inside their challenge period are skipped; a bounty that
+    /// belongs to someone else aborts the batch.
+    pub fn finalize_my_wins<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeMyWins<'info>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let remaining = ctx.remaining_accounts;
+        require!(!remaining.is_empty(), SeekError::BatchAccountsMismatch);
+
+        let vaults = VaultAccounts {
+            global_state: ctx.accounts.global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            charity_vault: ctx.accounts.charity_vault.as_ref().map(|c| c.to_account_info()),
+            protocol_destinations: Vec::new(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: ctx.accounts.global_state.bump,
+        };
+        let skr_mint = ctx.accounts.skr_mint.to_account_info();
+        let player_token_info = ctx.accounts.player_token_account.to_account_info();
+        let global_state_key = ctx.accounts.global_state.key();
+        let player_key = ctx.accounts.player.key();
+        let current_time = Clock::get()?.unix_timestamp;
+        let mut finalized: u32 = 0;
+        let mut total_payout: u64 = 0;
+
+        for bounty_info in remaining {
+            require!(
+                bounty_info.is_writable,
+                anchor_lang::error::ErrorCode::ConstraintMut
+            );
+            let mut bounty: Account<'info, Bounty> = Account::try_from(bounty_info)?;
+            require!(
+                bounty.global_state == global_state_key,
+                SeekError::GlobalStateMismatch
+            );
+            require!(bounty.player == player_key, SeekError::Unauthorized);
+
+            if bounty.status != BountyStatus::ChallengeWon
+                || bounty.is_disputed
+                || current_time < finalize_deadline(&bounty, &ctx.accounts.global_state)?
+            {
+                msg!("Skipping {}: not a finalizable win", bounty_info.key());
+                continue;
+            }
+
+            // Earlier payouts in this batch moved tokens out; re-read the vault.
+            ctx.accounts.house_vault.reload()?;
+
+            let paid = settle_resolution(
+                &mut ctx.accounts.global_state,
+                &mut bounty,
+                ctx.accounts.house_vault.amount,
+                &vaults,
+                &skr_mint,
+                Some(&player_token_info),
+                PlayerBooks {
+                    stats: &mut ctx.accounts.player_stats,
+                    loyalty_escrow: ctx.accounts.loyalty_escrow.as_deref_mut().map(|e| &mut **e),
+                },
+            )?;
+
+            // Persist now so a duplicate entry later in the batch sees the
+            // terminal status and is skipped.
+            bounty.exit(&crate::ID)?;
+            finalized += 1;
+            total_payout = total_payout
+                .checked_add(paid)
+                .ok_or(SeekError::MathOverflow)?;
+        }
+
+        emit!(WinsClaimed {
+            player: player_key,
+            bounties_finalized: finalized,
+            total_payout,
+        });
+
+        msg!("Claimed {} of {} wins | Total payout: {} SKR",
+            finalized,
+            remaining.len(),
+            ctx.accounts.global_state.whole_skr(total_payout)
+        );
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Keeper crank over a mixed batch of bounties. `remaining_accounts`
+    /// carries one `[bounty, player_token_account, player_stats]` triple per
+    /// bounty, after any protocol fee destinations (set_protocol_split). Each is
+    /// routed by status and the clock (see sweep_action):
+    /// - ChallengeWon/ChallengeLost past the finalize deadline: settled as
+    ///   finalize_bounty would (wins are left alone when player_claim_required).
+    /// - Pending past expiry + EXPIRED_REFUND_GRACE: refunded in full, as
+    ///   cancel_bounty would (a stuck hunt is the backend's fault, so it is
+    ///   not turned into a loss).
+    ///
+    /// Anything else (still running, in its challenge period, disputed, or
+    /// already terminal) is skipped. Disputes have no timeout; they wait for
+    /// the authority's ruling.
+    pub fn keeper_sweep<'info>(
+        ctx: Context<'_, '_, 'info, 'info, KeeperSweep<'info>>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let (protocol_destinations, remaining) =
+            take_protocol_destinations(&ctx.accounts.global_state, ctx.remaining_accounts)?;
+        require!(
+            !remaining.is_empty() && remaining.len().is_multiple_of(3),
+            SeekError::BatchAccountsMismatch
+        );
+
+        let vaults = VaultAccounts {
+            global_state: ctx.accounts.global_state.to_account_info(),
+            house_vault: ctx.accounts.house_vault.to_account_info(),
+            singularity_vault: ctx.accounts.singularity_vault.to_account_info(),
+            protocol_treasury: ctx.accounts.protocol_treasury.to_account_info(),
+            charity_vault: ctx.accounts.charity_vault.as_ref().map(|c| c.to_account_info()),
+            protocol_destinations,
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: ctx.accounts.global_state.bump,
+        };
+        let skr_mint = ctx.accounts.skr_mint.to_account_info();
+        let global_state_key = ctx.accounts.global_state.key();
+        let current_time = Clock::get()?.unix_timestamp;
+        let mut settled: u32 = 0;
+        let mut refunded: u32 = 0;
+
+        for item in remaining.chunks_exact(3) {
+            let bounty_info = &item[0];
+            let player_token_info = &item[1];
+
+            require!(
+                bounty_info.is_writable,
+                anchor_lang::error::ErrorCode::ConstraintMut
+            );
+            let mut bounty: Account<'info, Bounty> = Account::try_from(bounty_info)?;
+            require!(
+                bounty.global_state == global_state_key,
+                SeekError::GlobalStateMismatch
+            );
+
+            // Same canonical-ATA pin as FinalizeBounty.player_token_account.
+            require!(
+                player_token_info.key() == get_associated_token_address(&bounty.player, &SKR_MINT),
+                SeekError::Unauthorized
+            );
+            require!(
+                player_token_info.key() != ctx.accounts.house_vault.key(),
+                SeekError::InvalidTokenAccount
+            );
+            let mut player_stats = load_player_stats(&item[2], &bounty.player)?;
+
+            let global_state = &mut ctx.accounts.global_state;
+            match sweep_action(&bounty, global_state, current_time)? {
+                SweepAction::Settle => {
+                    // Earlier payouts in this batch moved tokens out; re-read the vault.
+                    ctx.accounts.house_vault.reload()?;
+
+                    settle_resolution(
+                        global_state,
+                        &mut bounty,
+                        ctx.accounts.house_vault.amount,
+                        &vaults,
+                        &skr_mint,
+                        Some(player_token_info),
+                        PlayerBooks { stats: &mut player_stats, loyalty_escrow: None },
+                    )?;
+                    settled += 1;
+                }
+                SweepAction::Refund => {
+                    let refund_amount = bounty.entry_amount;
+                    vaults.transfer(&vaults.house_vault, player_token_info, refund_amount)?;
+
+                    global_state.house_fund_balance = global_state
+                        .house_fund_balance
+                        .saturating_sub(refund_amount);
+                    player_stats.record_refund(refund_amount);
+                    retire_bounty(global_state, &mut bounty)?;
+                    bounty.status = BountyStatus::Cancelled;
+
+                    emit!(BountyCancelled {
+                        player: bounty.player,
+                        bounty: bounty_info.key(),
+                        refund_amount,
+                        fee: 0,
+                    });
+                    refunded += 1;
+                }
+                SweepAction::Skip => {
+                    msg!("Skipping {}: not actionable", bounty_info.key());
+                    continue;
+                }
+            }
+
+            // Persist now so a duplicate entry later in the batch sees the
+            // terminal status and is skipped (and a repeated player's stats
+            // are re-read fresh).
+            bounty.exit(&crate::ID)?;
+            player_stats.exit(&crate::ID)?;
+        }
+
+        msg!("Keeper sweep: {} settled, {} refunded, {} skipped",
+            settled,
+            refunded,
+            remaining.len() / 3 - (settled + refunded) as usize
+        );
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Cancel a bounty - player reclaims entry from a Pending bounty.
+    /// - While the hunt timer is running (early cancel): allowed only when a
+    ///   cancel fee schedule is configured. The fee scales linearly with the
+    ///   elapsed fraction of the timer, from `min_cancel_fee_bps` to
+    ///   `max_cancel_fee_bps`, and stays in the house.
+    /// - After expiry + grace period: full refund, no fee (the backend failed
+    ///   to resolve, which is not the player's fault).
+    pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let bounty = &mut ctx.accounts.bounty;
+        let global_state = &mut ctx.accounts.global_state;
+        let clock = Clock::get()?;
+        let current_time = clock.unix_timestamp;
+
+        // Only Pending bounties (no photo submitted yet) can be cancelled.
+        // Once a player submits a photo and the backend reveals the mission,
+        // the bounty is in `Submitted` and must flow through propose_resolution
+        // → finalize_bounty (or dispute). Allowing cancel from `Submitted` was
+        // a loss-rate exploit — a backend outage > grace_period would let
+        // every Submitted bounty reclaim entry, draining the house pool.
+        require!(
+            bounty.status == BountyStatus::Pending,
+            SeekError::ExpectedPending
+        );
+
+        // Must be expired + 1 hour grace period for backend to resolve,
+        // unless the player abandons the hunt early and pays the cancel fee
+        let fee = if current_time > bounty.expires_at + EXPIRED_REFUND_GRACE {
+            0
+        } else {
+            require!(
+                bounty.timer_running(current_time)
+                    && global_state.max_cancel_fee_bps > 0
+                    && bounty.submitted_at == 0,
+                SeekError::BountyNotExpired
+            );
+            let fee_bps = cancel_fee_bps(
+                global_state.min_cancel_fee_bps,
+                global_state.max_cancel_fee_bps,
+                current_time - bounty.created_at,
+                bounty.expires_at - bounty.created_at,
+            )?;
+            bps_of(bounty.entry_amount, fee_bps)?
+        };
+        let refund_amount = bounty.entry_amount
+            .checked_sub(fee)
+            .ok_or(SeekError::MathOverflow)?;
+
+        // Refund entry (minus any fee) from house vault to player
+        let seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.house_vault.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: global_state.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, refund_amount)?;
+
+        // Update tracked balance (the fee stays in the house)
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .saturating_sub(refund_amount);
+        ctx.accounts.player_stats.record_refund(refund_amount);
+
+        // Mark as cancelled
+        retire_bounty(global_state, bounty)?;
+        bounty.status = BountyStatus::Cancelled;
+        bounty.cancelled_at = current_time;
+        bounty.cancel_fee = fee;
+
+        emit!(BountyCancelled {
+            player: bounty.player,
+            bounty: bounty.key(),
+            refund_amount,
+            fee,
+        });
+
+        msg!(
+            "Bounty cancelled! Refund: {} SKR | Fee: {} SKR",
+            global_state.whole_skr(refund_amount),
+            global_state.whole_skr(fee)
+        );
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Pay the player's escrowed loyalty rebates from the house vault.
+    /// Player only.
+    pub fn claim_loyalty(ctx: Context<ClaimLoyalty>) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let amount = ctx.accounts.loyalty_escrow.balance;
+        require!(amount > 0, SeekError::NothingToClaim);
+
+        let global_state = &mut ctx.accounts.global_state;
+        let seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.house_vault.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: global_state.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, amount)?;
+
+        // Already off the house's books since the win that accrued it
+        global_state.loyalty_escrowed = global_state.loyalty_escrowed.saturating_sub(amount);
+        ctx.accounts.loyalty_escrow.balance = 0;
+
+        emit!(LoyaltyClaimed {
+            player: ctx.accounts.player.key(),
+            amount,
+        });
+
+        msg!("Loyalty rebate claimed: {} SKR", global_state.whole_skr(amount));
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Undo a cancel_bounty within REOPEN_GRACE: the player re-deposits the
+    /// refund they got (the cancel fee was already kept by the house) and
+    /// the bounty is Pending again with its original timer. Only while the
+    /// timer is still running and the mission hasn't been revealed. Player
+    /// only.
+    pub fn reopen_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+        require!(!ctx.accounts.global_state.shutdown, SeekError::ProtocolShutdown);
+
+        let bounty = &mut ctx.accounts.bounty;
+        let global_state = &mut ctx.accounts.global_state;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(
+            bounty.status == BountyStatus::Cancelled
+                && bounty.cancelled_at != 0
+                && current_time <= bounty.cancelled_at.saturating_add(REOPEN_GRACE)
+                && bounty.timer_running(current_time)
+                && !bounty.mission_revealed,
+            SeekError::ReopenNotAllowed
+        );
+
+        let redeposit = bounty.entry_amount
+            .checked_sub(bounty.cancel_fee)
+            .ok_or(SeekError::MathOverflow)?;
+        if redeposit > 0 {
+            let transfer_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.player_token_account.to_account_info(),
+                    to: ctx.accounts.house_vault.to_account_info(),
+                    authority: ctx.accounts.player.to_account_info(),
+                },
+            );
+            token::transfer(transfer_ctx, redeposit)?;
+        }
+
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .checked_add(redeposit)
+            .ok_or(SeekError::MathOverflow)?;
+        ctx.accounts.player_stats.record_wager(redeposit)?;
+
+        // Back on the books as if never cancelled, reservation included
+        if !bounty.practice {
+            require!(!global_state.at_capacity(), SeekError::ProtocolAtCapacity);
+            global_state.active_bounties = global_state
+                .active_bounties
+                .checked_add(1)
+                .ok_or(SeekError::MathOverflow)?;
+            if global_state.prefund_payout {
+                require!(
+                    global_state.can_back_payout(0, bounty.payout_amount)?,
+                    SeekError::InsufficientHouseFunds
+                );
+                global_state.outstanding_liability = global_state
+                    .outstanding_liability
+                    .checked_add(bounty.payout_amount)
+                    .ok_or(SeekError::MathOverflow)?;
+                bounty.prefunded = true;
+            }
+        }
+        bounty.status = BountyStatus::Pending;
+        bounty.cancelled_at = 0;
+        bounty.cancel_fee = 0;
+
+        emit!(BountyReopened {
+            player: bounty.player,
+            bounty: bounty.key(),
+            redeposit,
+        });
+
+        msg!("Bounty reopened! Re-deposited: {} SKR", global_state.whole_skr(redeposit));
+
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Escape hatch for a long pause: once the protocol has been paused for
+    /// PAUSE_REFUND_TIMEOUT, the player can take back their entry (plus any
+    /// dispute stake) from any unsettled bounty, regardless of the pause.
+    /// Co-disputers of a disputed bounty get their stakes back in full, from
+    /// one `[dispute_share, disputer_token_account, disputer]` triple each in
+    /// `remaining_accounts`, and their DisputeShares are closed.
+    /// Player only.
+    pub fn reclaim_during_pause<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelBounty<'info>>,
+    ) -> Result<()> {
+        // Refunds only move house -> disputer, so no other vault is touched
+        let house_vault = ctx.accounts.house_vault.to_account_info();
+        let vaults = VaultAccounts {
+            global_state: ctx.accounts.global_state.to_account_info(),
+            house_vault: house_vault.clone(),
+            singularity_vault: house_vault.clone(),
+            protocol_treasury: house_vault,
+            charity_vault: None,
+            protocol_destinations: Vec::new(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            global_state_bump: ctx.accounts.global_state.bump,
+        };
+        let bounty = &mut ctx.accounts.bounty;
+        let global_state = &mut ctx.accounts.global_state;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(global_state.paused, SeekError::ProtocolNotPaused);
+        require!(
+            current_time >= global_state.paused_at.saturating_add(PAUSE_REFUND_TIMEOUT),
+            SeekError::PauseRefundNotReady
+        );
+        require!(
+            !matches!(
+                bounty.status,
+                BountyStatus::Won | BountyStatus::Lost | BountyStatus::Cancelled
+            ),
+            SeekError::BountyAlreadyFinalized
+        );
+
+        let refund_amount = bounty.entry_amount
+            .checked_add(bounty.dispute_stake)
+            .ok_or(SeekError::MathOverflow)?;
+        require!(
+            ctx.accounts.house_vault.amount
+                >= refund_amount
+                    .checked_add(bounty.co_dispute_stake)
+                    .ok_or(SeekError::MathOverflow)?,
+            SeekError::InsufficientHouseFunds
+        );
+
+        let seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.house_vault.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: global_state.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, refund_amount)?;
+
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .saturating_sub(refund_amount);
+        ctx.accounts.player_stats.record_refund(refund_amount);
+
+        settle_co_disputers(
+            global_state,
+            bounty,
+            bounty.key(),
+            CoDisputeOutcome::Refunded,
+            &vaults,
+            ctx.remaining_accounts,
+        )?;
+
+        retire_bounty(global_state, bounty)?;
+        bounty.status = BountyStatus::Cancelled;
+
+        emit!(BountyReclaimed {
+            player: bounty.player,
+            bounty: bounty.key(),
+            refund_amount,
+        });
+
+        msg!(
+            "Bounty reclaimed during pause. Refund: {} SKR",
+            global_state.whole_skr(refund_amount)
+        );
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Refund a bounty whose mission was revealed but never proposed within
+    /// PROPOSE_TIMEOUT, bounding how long a reveal can leave the player's
+    /// entry in Submitted limbo. Player only.
+    pub fn reclaim_unproposed(ctx: Context<CancelBounty>) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let bounty = &mut ctx.accounts.bounty;
+        let global_state = &mut ctx.accounts.global_state;
+        let current_time = Clock::get()?.unix_timestamp;
+
+        require!(
+            bounty.status == BountyStatus::Submitted,
+            SeekError::ExpectedSubmitted
+        );
+        require!(bounty.mission_revealed, SeekError::MissionNotRevealed);
+        require!(
+            current_time >= bounty.propose_deadline()?,
+            SeekError::ProposeDeadlineNotReached
+        );
+
+        let refund_amount = bounty.entry_amount;
+        let seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.house_vault.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: global_state.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, refund_amount)?;
+
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .saturating_sub(refund_amount);
+        ctx.accounts.player_stats.record_refund(refund_amount);
+
+        retire_bounty(global_state, bounty)?;
+        bounty.status = BountyStatus::Cancelled;
+
+        emit!(BountyReclaimed {
+            player: bounty.player,
+            bounty: bounty.key(),
+            refund_amount,
+        });
+
+        msg!("Unproposed bounty reclaimed. Refund: {} SKR", global_state.whole_skr(refund_amount));
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Publish up to MAX_POOL_COMMITMENTS mission commitments ahead of play,
+    /// in a CommitmentPool PDA keyed by `pool_id`. accept_bounty can then
+    /// draw from it, proving each mission was fixed before any player
+    /// accepted. Hot authority only.
+    pub fn publish_commitment_pool(
+        ctx: Context<PublishCommitmentPool>,
+        pool_id: u64,
+        commitments: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(
+            !commitments.is_empty() && commitments.len() <= MAX_POOL_COMMITMENTS,
+            SeekError::InvalidCommitmentPool
+        );
+
+        let count = commitments.len() as u8;
+        let pool = &mut ctx.accounts.commitment_pool;
+        pool.pool_id = pool_id;
+        pool.published_at = Clock::get()?.unix_timestamp;
+        pool.commitments = commitments;
+        pool.consumed = 0;
+        pool.bump = ctx.bumps.commitment_pool;
+
+        emit!(CommitmentPoolPublished {
+            pool: pool.key(),
+            pool_id,
+            count,
+            published_at: pool.published_at,
+        });
+
+        msg!("Commitment pool {} published with {} commitments", pool_id, count);
+        Ok(())
+    }
+
+    /// Retire `mission_id`: every later reveal of it fails with
+    /// MissionBlocked, and bounties committed to it can be refunded with
+    /// reclaim_unrevealed. Cold authority only.
+    pub fn block_mission(ctx: Context<BlockMission>, mission_id: [u8; 32]) -> Result<()> {
+        let blocked = &mut ctx.accounts.blocked_mission;
+        blocked.mission_id = mission_id;
+        blocked.blocked_at = Clock::get()?.unix_timestamp;
+        blocked.bump = ctx.bumps.blocked_mission;
+
+        msg!("Mission blocked: {:?}", mission_id);
+        Ok(())
+    }
+
+    /// Refund the full entry of an unrevealed bounty whose mission has been
+    /// blocked. The caller proves it by opening the commitment:
+    /// hash(mission_id || salt) must match, and `mission_id` must have a
+    /// BlockedMission PDA. Permissionless (knowing the salt is the
+    /// authorization); the refund only ever goes to the player's ATA.
+    pub fn reclaim_unrevealed(
+        ctx: Context<ReclaimUnrevealed>,
+        mission_id: [u8; 32],
+        salt: [u8; 32],
+    ) -> Result<()> {
+        require!(!ctx.accounts.global_state.paused, SeekError::ProtocolPaused);
+
+        let bounty = &mut ctx.accounts.bounty;
+        let global_state = &mut ctx.accounts.global_state;
+
+        require!(
+            bounty.status == BountyStatus::Pending || bounty.status == BountyStatus::Submitted,
+            SeekError::ExpectedPending
+        );
+        require!(!bounty.mission_revealed, SeekError::MissionAlreadyRevealed);
+
+        let computed_hash = anchor_lang::solana_program::hash::hashv(&[&mission_id, &salt]);
+        require!(
+            computed_hash.to_bytes() == bounty.mission_commitment,
+            SeekError::InvalidMissionHash
+        );
+
+        let refund_amount = bounty.entry_amount;
+        let seeds = &[b"global_state".as_ref(), &[global_state.bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.house_vault.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: global_state.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, refund_amount)?;
+
+        global_state.house_fund_balance = global_state
+            .house_fund_balance
+            .saturating_sub(refund_amount);
+        ctx.accounts.player_stats.record_refund(refund_amount);
+
+        retire_bounty(global_state, bounty)?;
+        bounty.status = BountyStatus::Cancelled;
+
+        emit!(BountyReclaimed {
+            player: bounty.player,
+            bounty: bounty.key(),
+            refund_amount,
+        });
+
+        msg!(
+            "Blocked-mission bounty reclaimed. Refund: {} SKR",
+            global_state.whole_skr(refund_amount)
+        );
+        emit_balances_snapshot(&ctx.accounts.global_state)?;
+        Ok(())
+    }
+
+    /// Step 1 of two-step authority transfer. Current authority proposes a new
+    /// authority; no state changes until the new authority signs `accept_authority_transfer`.
+    /// Overwrites any previously pending transfer.
+    pub fn propose_authority_transfer(
+        ctx: Context<ProposeAuthorityTransfer>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        // Guard against accidentally proposing the zero address (= cancel, not a transfer)
+        require!(
+            new_authority != Pubkey::default(),
+            SeekError::Unauthorized
+        );
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.pending_authority = new_authority;
+
+        msg!(
+            "Authority transfer proposed: {} -> {} (awaiting acceptance)",
+            global_state.authority,
+            new_authority
+        );
+
+        Ok(())
+    }
+
+    /// Step 2 of two-step authority transfer. The pending authority signs to
+    /// accept, atomically swapping `authority` and clearing `pending_authority`.
+    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+
+        // Must have a pending transfer
+        require!(
+            global_state.pending_authority != Pubkey::default(),
+            SeekError::Unauthorized
+        );
+
+        // Only the pending authority can accept
+        require!(
+            ctx.accounts.new_authority.key() == global_state.pending_authority,
+            SeekError::Unauthorized
+        );
+
+        let old_authority = global_state.authority;
+        let new_authority = global_state.pending_authority;
+
+        global_state.authority = new_authority;
+        global_state.pending_authority = Pubkey::default();
+
+        emit!(AuthorityTransferred {
+            old_authority,
+            new_authority,
+        });
+
+        msg!("Authority transferred from {} to {}", old_authority, new_authority);
+
+        Ok(())
+    }
+
+    /// Cancel an in-flight authority transfer. Current authority only.
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+
+        require!(
+            global_state.pending_authority != Pubkey::default(),
+            SeekError::Unauthorized
+        );
+
+        let cancelled = global_state.pending_authority;
+        global_state.pending_authority = Pubkey::default();
+
+        msg!("Authority transfer to {} cancelled", cancelled);
+        Ok(())
+    }
+
+    /// Rotate the hot authority (used for reveal_mission + propose_resolution).
+    /// Cold authority only. Used when the backend keypair is compromised or rotated.
+    pub fn set_hot_authority(ctx: Context<SetHotAuthority>, new_hot: Pubkey) -> Result<()> {
+        require!(new_hot != Pubkey::default(), SeekError::Unauthorized);
+
+        let global_state = &mut ctx.accounts.global_state;
+        let old_hot = global_state.hot_authority;
+        global_state.hot_authority = new_hot;
+
+        msg!("Hot authority rotated: {} -> {}", old_hot, new_hot);
+        Ok(())
+    }
+
+    /// Cap how many bounties may reveal the same mission_id (0 = unlimited).
+    /// Cold authority only.
+    pub fn set_max_mission_reuse(ctx: Context<UpdateConfig>, max_reuse: u32) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.max_mission_reuse = max_reuse;
+
+        msg!("Max mission reuse set to {}", max_reuse);
+        Ok(())
+    }
+
+    /// Carry a GlobalState created by the original deployment over to the
+    /// current layout: realloc it from LEGACY_GLOBAL_STATE_SIZE to
+    /// GlobalState::SIZE (the authority pays the extra rent) and fill the
+    /// added fields with their initialize defaults. Must run once, right after upgrading a deployment that predates the
+    /// larger layout; every instruction that loads GlobalState fails until
+    /// it has. Cold authority only.
+    pub fn migrate_global_state(ctx: Context<MigrateGlobalState>) -> Result<()> {
+        let info = ctx.accounts.global_state.to_account_info();
+        require!(
+            info.data_len() == LEGACY_GLOBAL_STATE_SIZE,
+            SeekError::AlreadyMigrated
+        );
+        grow_account(
+            &info,
+            GlobalState::DISCRIMINATOR,
+            GlobalState::SIZE,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        // Must now load as a current GlobalState, owned by the signer
+        let mut global_state = GlobalState::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            global_state.authority,
+            ctx.accounts.authority.key(),
+            SeekError::Unauthorized
+        );
+        let decimals = ctx.accounts.skr_mint.decimals;
+        require!(decimals <= MAX_SKR_DECIMALS, SeekError::InvalidMint);
+        global_state.apply_migration_defaults(decimals, Clock::get()?.unix_timestamp)?;
+        global_state.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        msg!("GlobalState migrated to {} bytes", GlobalState::SIZE);
+        Ok(())
+    }
+
+    /// Carry a bounty created before the Bounty layout grew over to the
+    /// current one: realloc it to Bounty::SIZE with every added field
+    /// zeroed, which is how the handlers treat such bounties (disputable).
+    /// `payer` covers the extra rent. Anyone can call it, typically the
+    /// player or a keeper, before the bounty's first instruction after the
+    /// upgrade.
+    pub fn migrate_bounty(ctx: Context<MigrateBounty>) -> Result<()> {
+        let info = ctx.accounts.bounty.to_account_info();
+        grow_account(
+            &info,
+            Bounty::DISCRIMINATOR,
+            Bounty::SIZE,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        // Must now load as a current Bounty of this deployment
+        let bounty = Bounty::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            bounty.global_state,
+            ctx.accounts.global_state.key(),
+            SeekError::GlobalStateMismatch
+        );
+
+        msg!("Bounty {} migrated to {} bytes", info.key(), Bounty::SIZE);
+        Ok(())
+    }
+
+    /// Set the early-cancel fee schedule (basis points of entry). The fee
+    /// ramps linearly from `min_bps` at accept to `max_bps` at expiry.
+    /// `max_bps = 0` disables early cancel. Cold authority only.
+    pub fn set_cancel_fee(ctx: Context<UpdateConfig>, min_bps: u16, max_bps: u16) -> Result<()> {
+        require!(
+            min_bps <= max_bps && u64::from(max_bps) <= 10000,
+            SeekError::InvalidConfig
+        );
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.min_cancel_fee_bps = min_bps;
+        global_state.max_cancel_fee_bps = max_bps;
+
+        msg!("Cancel fee set: {} -> {} bps", min_bps, max_bps);
+        Ok(())
+    }
+
+    /// Set the grace window (seconds) after `challenge_ends_at` before a
+    /// proposed loss can be finalized. Capped at one hour. Cold authority only.
+    pub fn set_finalize_grace(ctx: Context<UpdateConfig>, grace: i64) -> Result<()> {
+        require!((0..=MAX_FINALIZE_GRACE).contains(&grace), SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.finalize_grace = grace;
+
+        msg!("Finalize grace set to {}s", grace);
+        Ok(())
+    }
+
+    /// Cap bounty creation protocol-wide to `max_accepts` per `window`
+    /// seconds. `max_accepts = 0` disables the limit. Resets the current
+    /// window. Windows are fixed, so size `max_accepts` knowing up to twice
+    /// as many can land across a window boundary. Cold authority only.
+    pub fn set_global_rate_limit(
+        ctx: Context<UpdateConfig>,
+        max_accepts: u32,
+        window: i64,
+    ) -> Result<()> {
+        require!(max_accepts == 0 || window > 0, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.global_rate_limit = max_accepts;
+        global_state.rate_limit_window = window;
+        global_state.rate_window_start = 0;
+        global_state.rate_window_count = 0;
+
+        msg!("Global rate limit set: {} accepts per {}s", max_accepts, window);
+        Ok(())
+    }
+
+    /// Cap how much a single player may wager per day (responsible-gaming
+    /// limit), in SKR base units. 0 disables. Cold authority only.
+    pub fn set_daily_wager_cap(ctx: Context<UpdateConfig>, cap: u64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.daily_wager_cap = cap;
+
+        msg!("Daily wager cap set to {} SKR", global_state.whole_skr(cap));
+        Ok(())
+    }
+
+    /// Cap how many bounties of `tier` (1-3) may be accepted per day
+    /// protocol-wide. 0 = unlimited. Cold authority only.
+    pub fn set_tier_daily_cap(ctx: Context<UpdateConfig>, tier: u8, cap: u32) -> Result<()> {
+        require!((1..=3).contains(&tier), SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.tier_daily_caps[usize::from(tier - 1)] = cap;
+
+        msg!("Tier {} daily cap set to {}", tier, cap);
+        Ok(())
+    }
+
+    /// Toggle prefunding: new bounties reserve their full payout at accept
+    /// time. Bounties already in flight are unaffected. Cold authority only.
+    pub fn set_prefund_payout(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.prefund_payout = enabled;
+
+        msg!("Prefund payout: {}", enabled);
+        Ok(())
+    }
+
+    /// Pause or unpause the bounty lifecycle. Pausing starts the
+    /// PAUSE_REFUND_TIMEOUT clock for reclaim_during_pause. Cold authority only.
+    pub fn set_paused(ctx: Context<UpdateConfig>, paused: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        if paused && !global_state.paused {
+            global_state.paused_at = Clock::get()?.unix_timestamp;
+        } else if !paused {
+            global_state.paused_at = 0;
+        }
+        global_state.paused = paused;
+
+        msg!("Protocol paused: {}", paused);
+        Ok(())
+    }
+
+    /// Set the house balance fund_house must reach before accept_bounty opens.
+    /// Only matters before launch: once accepting is enabled it stays on.
+    /// Cold authority only.
+    pub fn set_min_launch_balance(ctx: Context<UpdateConfig>, min_balance: u64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.min_launch_balance = min_balance;
+
+        msg!("Min launch balance set to {} SKR", global_state.whole_skr(min_balance));
+        Ok(())
+    }
+
+    /// Choose which tiers skip the challenge period: bit 0 = tier 1,
+    /// bit 1 = tier 2, bit 2 = tier 3. Their wins and oracle-attested losses
+    /// settle immediately; a disputable loss still waits out the challenge
+    /// period. 0 disables. Cold authority only.
+    pub fn set_fast_resolve_tiers(ctx: Context<UpdateConfig>, tiers: u8) -> Result<()> {
+        require!(tiers & !0b111 == 0, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.fast_resolve_tiers = tiers;
+
+        msg!("Fast-resolve tiers mask: {:#05b}", tiers);
+        Ok(())
+    }
+
+    /// Set the dispute stake for `tier` (1-3) in bps of the entry, at most
+    /// 10000. 0 restores DISPUTE_STAKE_BPS. Applies to disputes filed
+    /// afterwards. Cold authority only.
+    pub fn set_tier_dispute_stake(
+        ctx: Context<UpdateConfig>,
+        tier: u8,
+        stake_bps: u16,
+    ) -> Result<()> {
+        require!((1..=3).contains(&tier), SeekError::InvalidConfig);
+        require!(stake_bps <= 10000, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.tier_dispute_stake_bps[usize::from(tier - 1)] = stake_bps;
+
+        msg!("Tier {} dispute stake set to {} bps", tier, stake_bps);
+        Ok(())
+    }
+
+    /// Set the hunt timer for `tier` (1-3). Must be at least
+    /// MIN_TIER_DURATION. Applies to bounties accepted afterwards.
+    /// Cold authority only.
+    pub fn set_tier_duration(ctx: Context<UpdateConfig>, tier: u8, duration: i64) -> Result<()> {
+        require!((1..=3).contains(&tier), SeekError::InvalidConfig);
+        require!(duration >= MIN_TIER_DURATION, SeekError::TierDurationTooShort);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.tier_durations[usize::from(tier - 1)] = duration;
+
+        msg!("Tier {} duration set to {}s", tier, duration);
+        Ok(())
+    }
+
+    /// Cap how long any single hunt may run. accept_bounty rejects a tier
+    /// whose timer exceeds it. Must be at least MIN_TIER_DURATION and no
+    /// longer than commitment_ttl, so every hunt stays revealable.
+    /// Cold authority only.
+    pub fn set_max_duration(ctx: Context<UpdateConfig>, max_duration: i64) -> Result<()> {
+        require!(max_duration >= MIN_TIER_DURATION, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        require!(
+            max_duration <= global_state.commitment_ttl,
+            SeekError::InvalidConfig
+        );
+        global_state.max_duration = max_duration;
+
+        msg!("Max hunt duration set to {}s", max_duration);
+        Ok(())
+    }
+
+    /// Set how long after creation a bounty's mission commitment may be
+    /// revealed. Must cover max_duration so an on-time hunt can always be
+    /// revealed. Cold authority only.
+    pub fn set_commitment_ttl(ctx: Context<UpdateConfig>, ttl: i64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        require!(ttl >= global_state.max_duration, SeekError::InvalidConfig);
+        global_state.commitment_ttl = ttl;
+
+        msg!("Commitment TTL set to {}s", ttl);
+        Ok(())
+    }
+
+    /// Stamp new bounties with a slot-hash ordering key so accepts landing in
+    /// the same slot have a deterministic, verifiable order. Cold authority
+    /// only.
+    pub fn set_accept_ordering(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.accept_ordering = enabled;
+
+        msg!("Accept ordering keys: {}", enabled);
+        Ok(())
+    }
+
+    /// Set the oracle whose co-signature makes a proposed loss indisputable.
+    /// `Pubkey::default()` disables proof-backed losses. Cold authority only.
+    pub fn set_loss_oracle(ctx: Context<UpdateConfig>, loss_oracle: Pubkey) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.loss_oracle = loss_oracle;
+
+        msg!("Loss oracle set: {}", loss_oracle);
+        Ok(())
+    }
+
+    /// Require disputes to be resolved against the recorded photo hash
+    /// (resolve_dispute_with_evidence only). Cold authority only.
+    pub fn set_require_dispute_evidence(ctx: Context<UpdateConfig>, required: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.require_dispute_evidence = required;
+
+        msg!("Require dispute evidence: {}", required);
+        Ok(())
+    }
+
+    /// Set the compensation a player receives on top of entry + stake when
+    /// they win a dispute, in bps of the entry. Capped at
+    /// MAX_DISPUTE_WIN_BONUS_BPS. Cold authority only.
+    pub fn set_dispute_win_bonus(ctx: Context<UpdateConfig>, bonus_bps: u16) -> Result<()> {
+        require!(bonus_bps <= MAX_DISPUTE_WIN_BONUS_BPS, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.dispute_win_bonus_bps = bonus_bps;
+
+        msg!("Dispute win bonus set to {} bps", bonus_bps);
+        Ok(())
+    }
+
+    /// Set the share of the dispute stake returned to a player who loses a
+    /// dispute, in bps; the rest is forfeited (see forfeit_stake_treasury_bps).
+    /// 0 forfeits the whole stake. Cold authority only.
+    pub fn set_dispute_loss_refund(ctx: Context<UpdateConfig>, refund_bps: u16) -> Result<()> {
+        require!(refund_bps <= 10000, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.dispute_loss_refund_bps = refund_bps;
+
+        msg!("Dispute loss refund set to {} bps", refund_bps);
+        Ok(())
+    }
+
+    /// Set the share of a forfeited dispute stake routed to the protocol
+    /// treasury to fund arbitration, in bps; the rest stays in the house.
+    /// Cold authority only.
+    pub fn set_forfeit_stake_treasury(ctx: Context<UpdateConfig>, treasury_bps: u16) -> Result<()> {
+        require!(treasury_bps <= 10000, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.forfeit_stake_treasury_bps = treasury_bps;
+
+        msg!("Forfeited stake to treasury set to {} bps", treasury_bps);
+        Ok(())
+    }
+
+    /// Set the loss-streak jackpot boost: each consecutive loss tightens the
+    /// player's next jackpot odds by `per_loss_bps`, up to `max_bps` in total
+    /// (at most MAX_LOSS_STREAK_BOOST_BPS). A win resets the streak.
+    /// Cold authority only.
+    pub fn set_loss_streak_boost(
+        ctx: Context<UpdateConfig>,
+        per_loss_bps: u16,
+        max_bps: u16,
+    ) -> Result<()> {
+        require!(max_bps <= MAX_LOSS_STREAK_BOOST_BPS, SeekError::InvalidConfig);
+        require!(per_loss_bps <= max_bps, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.loss_streak_boost_bps = per_loss_bps;
+        global_state.max_loss_streak_boost_bps = max_bps;
+
+        msg!(
+            "Loss-streak boost set to {} bps per loss, {} bps max",
+            per_loss_bps,
+            max_bps
+        );
+        Ok(())
+    }
+
+    /// Set the flat dispute fee sent to the protocol treasury on every
+    /// dispute_bounty. Never refunded. 0 disables. Cold authority only.
+    pub fn set_dispute_fee(ctx: Context<UpdateConfig>, dispute_fee: u64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.dispute_fee = dispute_fee;
+
+        msg!("Dispute fee set to {} SKR", global_state.whole_skr(dispute_fee));
+        Ok(())
+    }
+
+    /// Require wins to be claimed by the player (finalize_bounty signed by
+    /// bounty.player) instead of pushed by a keeper. Losses are unaffected.
+    /// Cold authority only.
+    pub fn set_player_claim_required(ctx: Context<UpdateConfig>, required: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.player_claim_required = required;
+
+        msg!("Player claim required: {}", required);
+        Ok(())
+    }
+
+    /// Set the smallest payout a new bounty may carry; accept_bounty rejects
+    /// bets below it. 0 disables. Cold authority only.
+    pub fn set_min_payout(ctx: Context<UpdateConfig>, min_payout: u64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.min_payout = min_payout;
+
+        msg!("Min payout set to {} SKR", global_state.whole_skr(min_payout));
+        Ok(())
+    }
+
+    /// Cap the house's net loss per epoch. Once settled wins push it past
+    /// this, accept_bounty is rejected until advance_epoch. 0 disables.
+    /// Cold authority only.
+    pub fn set_max_epoch_house_loss(ctx: Context<UpdateConfig>, max_loss: u64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.max_epoch_house_loss = max_loss;
+
+        msg!("Max epoch house loss set to {} SKR", global_state.whole_skr(max_loss));
+        Ok(())
+    }
+
+    /// Set the singularity balance below which wins skip the jackpot roll
+    /// and pay their payout only. 0 = always roll. Cold authority only.
+    pub fn set_min_jackpot_roll_balance(ctx: Context<UpdateConfig>, min_balance: u64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.min_jackpot_roll_balance = min_balance;
+
+        msg!(
+            "Min jackpot roll balance set to {} SKR",
+            global_state.whole_skr(min_balance)
+        );
+        Ok(())
+    }
+
+    /// Cap total jackpot payouts per epoch. Once reached, wins settle without
+    /// a singularity roll until advance_epoch. 0 disables. Cold authority
+    /// only.
+    pub fn set_max_jackpot_per_epoch(ctx: Context<UpdateConfig>, max_jackpot: u64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.max_jackpot_per_epoch = max_jackpot;
+
+        msg!("Max jackpot per epoch set to {} SKR", global_state.whole_skr(max_jackpot));
+        Ok(())
+    }
+
+    /// Cap how many bounties may be active at once protocol-wide; accepts
+    /// fail with ProtocolAtCapacity until a settle or cancel frees a slot.
+    /// 0 disables. Cold authority only.
+    pub fn set_max_active_global(ctx: Context<UpdateConfig>, max_active: u64) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.max_active_global = max_active;
+
+        msg!("Max active bounties set to {}", max_active);
+        Ok(())
+    }
+
+    /// Split every loss's protocol share across up to
+    /// MAX_PROTOCOL_DESTINATIONS SKR token accounts (e.g. dev, ops, DAO).
+    /// `weights` are bps and must sum to 10000; empty vectors restore the
+    /// single protocol_treasury. Settlement instructions then take the
+    /// destinations, in this order, at the front of `remaining_accounts`.
+    /// Cold authority only.
+    pub fn set_protocol_split(
+        ctx: Context<UpdateConfig>,
+        destinations: Vec<Pubkey>,
+        weights: Vec<u16>,
+    ) -> Result<()> {
+        require!(
+            destinations.len() == weights.len()
+                && destinations.len() <= MAX_PROTOCOL_DESTINATIONS,
+            SeekError::InvalidConfig
+        );
+        require!(
+            weights.iter().all(|w| *w > 0)
+                && destinations.iter().all(|d| *d != Pubkey::default()),
+            SeekError::InvalidConfig
+        );
+        let total: u64 = weights.iter().map(|w| u64::from(*w)).sum();
+        require!(
+            weights.is_empty() || total == 10000,
+            SeekError::InvalidConfig
+        );
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.protocol_destinations = [Pubkey::default(); MAX_PROTOCOL_DESTINATIONS];
+        global_state.protocol_weights = [0; MAX_PROTOCOL_DESTINATIONS];
+        global_state.protocol_destinations[..destinations.len()].copy_from_slice(&destinations);
+        global_state.protocol_weights[..weights.len()].copy_from_slice(&weights);
+
+        msg!("Protocol fee split across {} destination(s)", destinations.len());
+        Ok(())
+    }
+
+    /// Route `charity_jackpot_bps` of every won jackpot to `charity_vault`
+    /// (an SKR token account) instead of the player. Capped at
+    /// MAX_CHARITY_JACKPOT_BPS; 0 disables. Cold authority only.
+    pub fn set_charity(
+        ctx: Context<UpdateConfig>,
+        charity_vault: Pubkey,
+        charity_jackpot_bps: u16,
+    ) -> Result<()> {
+        require!(
+            charity_jackpot_bps <= MAX_CHARITY_JACKPOT_BPS,
+            SeekError::InvalidConfig
+        );
+        require!(
+            charity_jackpot_bps == 0 || charity_vault != Pubkey::default(),
+            SeekError::InvalidConfig
+        );
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.charity_vault = charity_vault;
+        global_state.charity_jackpot_bps = charity_jackpot_bps;
+
+        msg!("Charity vault: {} | Jackpot share: {} bps", charity_vault, charity_jackpot_bps);
+        Ok(())
+    }
+
+    /// Choose whether a player-won dispute rolls the singularity jackpot.
+    /// Cold authority only.
+    pub fn set_dispute_wins_roll_jackpot(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.dispute_wins_roll_jackpot = enabled;
+
+        msg!("Dispute wins roll jackpot: {}", enabled);
+        Ok(())
+    }
+
+    /// Allow or stop other players joining open disputes. Disputes already
+    /// joined settle their shares either way. Cold authority only.
+    pub fn set_co_disputes_enabled(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.co_disputes_enabled = enabled;
+
+        msg!("Co-disputes enabled: {}", enabled);
+        Ok(())
+    }
+
+    /// Set the minimum time between a player's singularity rolls, up to
+    /// MAX_JACKPOT_COOLDOWN. 0 disables. Cold authority only.
+    pub fn set_jackpot_cooldown(ctx: Context<UpdateConfig>, cooldown: i64) -> Result<()> {
+        require!((0..=MAX_JACKPOT_COOLDOWN).contains(&cooldown), SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.jackpot_cooldown = cooldown;
+
+        msg!("Jackpot cooldown set to {}s", cooldown);
+        Ok(())
+    }
+
+    /// Set the minimum slots between accept and reveal, up to
+    /// MAX_MIN_HUNT_SLOTS. 0 disables. Cold authority only.
+    pub fn set_min_hunt_slots(ctx: Context<UpdateConfig>, min_hunt_slots: u64) -> Result<()> {
+        require!(min_hunt_slots <= MAX_MIN_HUNT_SLOTS, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.min_hunt_slots = min_hunt_slots;
+
+        msg!("Minimum hunt set to {} slots", min_hunt_slots);
+        Ok(())
+    }
+
+    /// Enable or disable all token burns. Disabled: the win-burn slice stays
+    /// in the house and burn_treasury is rejected. Cold authority only.
+    pub fn set_burn_enabled(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.burn_enabled = enabled;
+
+        msg!("Burning enabled: {}", enabled);
+        Ok(())
+    }
+
+    /// Set the win payout multiplier in basis points (e.g. 25000 = 2.5x).
+    /// Must return at least the entry and is capped at
+    /// MAX_PAYOUT_MULTIPLIER_BPS. Applies to bounties accepted afterwards;
+    /// existing bounties keep their recorded payout_amount. Cold authority
+    /// only.
+    pub fn set_payout_multiplier(ctx: Context<UpdateConfig>, multiplier_bps: u16) -> Result<()> {
+        require!(
+            (10000..=MAX_PAYOUT_MULTIPLIER_BPS).contains(&multiplier_bps),
+            SeekError::InvalidConfig
+        );
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.payout_multiplier_bps = multiplier_bps;
+
+        msg!("Payout multiplier set to {} bps", multiplier_bps);
+        Ok(())
+    }
+
+    /// Set the share of each win payout burned for deflationary pressure.
+    /// Capped at MAX_WIN_BURN_BPS. 0 disables. Cold authority only.
+    pub fn set_win_burn(ctx: Context<UpdateConfig>, win_burn_bps: u16) -> Result<()> {
+        require!(win_burn_bps <= MAX_WIN_BURN_BPS, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.win_burn_bps = win_burn_bps;
+
+        msg!("Win burn set to {} bps", win_burn_bps);
+        Ok(())
+    }
+
+    /// Set the share of each win payout routed into the singularity pool.
+    /// Capped at MAX_WIN_JACKPOT_BPS. 0 disables. Cold authority only.
+    pub fn set_win_jackpot(ctx: Context<UpdateConfig>, win_jackpot_bps: u16) -> Result<()> {
+        require!(win_jackpot_bps <= MAX_WIN_JACKPOT_BPS, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.win_jackpot_bps = win_jackpot_bps;
+
+        msg!("Win jackpot contribution set to {} bps", win_jackpot_bps);
+        Ok(())
+    }
+
+    /// Set the share of each win payout escrowed as a loyalty rebate.
+    /// Capped at MAX_LOYALTY_REBATE_BPS. 0 disables. Cold authority only.
+    pub fn set_loyalty_rebate(ctx: Context<UpdateConfig>, loyalty_rebate_bps: u16) -> Result<()> {
+        require!(loyalty_rebate_bps <= MAX_LOYALTY_REBATE_BPS, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.loyalty_rebate_bps = loyalty_rebate_bps;
+
+        msg!("Loyalty rebate set to {} bps", loyalty_rebate_bps);
+        Ok(())
+    }
+
+    /// Configure auto-fund: with `enabled`, a win that finds
+    /// house_fund_balance below `threshold` first moves `amount` from
+    /// protocol_treasury into the house. The treasury owner must separately
+    /// approve the global_state PDA as delegate. Cold authority only.
+    pub fn set_auto_fund(
+        ctx: Context<UpdateConfig>,
+        enabled: bool,
+        threshold: u64,
+        amount: u64,
+    ) -> Result<()> {
+        require!(!enabled || amount > 0, SeekError::InvalidConfig);
+
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.auto_fund_house = enabled;
+        global_state.auto_fund_threshold = threshold;
+        global_state.auto_fund_amount = amount;
+
+        msg!(
+            "Auto-fund: {} | below {} SKR, top up {} SKR",
+            enabled,
+            global_state.whole_skr(threshold),
+            global_state.whole_skr(amount)
+        );
+        Ok(())
+    }
+
+    /// Toggle compounding of loss-path protocol fees into the house vault
+    /// instead of the treasury. Cold authority only.
+    pub fn set_auto_compound_protocol(ctx: Context<UpdateConfig>, enabled: bool) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        global_state.auto_compound_protocol = enabled;
+
+        msg!("Auto-compound protocol fees: {}", enabled);
+        Ok(())
+    }
+
+    /// Rotate the protocol_treasury recipient. Cold authority only.
+    /// Used when the fees-wallet key is compromised, lost, or operationally rotated.
+    /// `new_treasury` must be a TokenAccount of SKR_MINT (validated in the
+    /// account context). Only redirects FUTURE inflows — funds already in the
+    /// old treasury stay under that account's owner.
+    pub fn set_treasury(ctx: Context<SetTreasury>) -> Result<()> {
+        let global_state = &mut ctx.accounts.global_state;
+        let old_treasury = global_state.protocol_treasury;
+        let new_treasury = ctx.accounts.new_treasury.key();
+
+        require!(new_treasury != old_treasury, SeekError::Unauthorized);
+
+        global_state.protocol_treasury = new_treasury;
+
+        emit!(TreasuryRotated {
+            authority: ctx.accounts.authority.key(),
+            old_treasury,
+            new_treasury,
+        });
+
+        msg!("Protocol treasury rotated: {} -> {}", old_treasury, new_treasury);
+        Ok(())
+    }
+
+    /// Recovery: add (`increase`) or remove `delta` from outstanding_liability
+    /// when it has drifted from the payouts actually reserved, e.g. after a
+    /// bounty left the books outside the normal flow. Can't go below zero.
+    /// Cold authority only.
+    pub fn adjust_liability(ctx: Context<UpdateConfig>, delta: u64, increase: bool) -> Result<()> {
+        require!(delta > 0, SeekError::InvalidAmount);
+
+        let global_state = &mut ctx.accounts.global_state;
+        let previous = global_state.outstanding_liability;
+        let new = if increase {
+            previous.checked_add(delta)
+        } else {
+            previous.checked_sub(delta)
+        }
+        .ok_or(SeekError::MathOverflow)?;
+        global_state.outstanding_liability = new;
+
+        emit!(LiabilityAdjusted {
+            authority: ctx.accounts.authority.key(),
+            previous,
+            new,
+            delta,
+            increase,
+        });
+
+        msg!("Outstanding liability adjusted: {} -> {}", previous, new);
+        Ok(())
+    }
+
+    /// Close a bounty account after it reaches a terminal state + 24h cooldown.
+    /// Refunds rent to whoever paid it at accept (bounty.rent_payer: the
+    /// player, or a relayer that funded the accept). The cooldown prevents PDA reuse races — the
+    /// bounty PDA seed is [b"bounty", player, timestamp] so after close, the
+    /// same (player, timestamp) can be re-init'd. 24h is plenty of slack for
+    /// any in-flight finalizer retries or downstream indexer catch-up.
+    pub fn close_bounty(ctx: Context<CloseBounty>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let bounty = &ctx.accounts.bounty;
+        require!(
+            now >= bounty.created_at.saturating_add(CLOSE_COOLDOWN),
+            SeekError::BountyCooldown
+        );
+        msg!("Bounty account closed, rent refunded to {}", ctx.accounts.rent_payer.key());
+        Ok(())
+    }
+
+    /// Batch close_bounty. Permissionless: `remaining_accounts` carries one
+    /// `[bounty, rent_payer]` pair per bounty and each closes to its own
+    /// rent payer, so the caller can't redirect any rent. Bounties that aren't terminal
+    /// or are still inside CLOSE_COOLDOWN are skipped.
+    pub fn close_bounties_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseBountiesBatch<'info>>,
+    ) -> Result<()> {
+        let remaining = ctx.remaining_accounts;
+        require!(
+            !remaining.is_empty() && remaining.len().is_multiple_of(2),
+            SeekError::BatchAccountsMismatch
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut closed: u32 = 0;
+
+        for pair in remaining.chunks_exact(2) {
+            let (bounty_info, payer_info) = (&pair[0], &pair[1]);
+            require!(
+                bounty_info.is_writable && payer_info.is_writable,
+                anchor_lang::error::ErrorCode::ConstraintMut
+            );
+            let bounty: Account<'info, Bounty> = Account::try_from(bounty_info)?;
+            require!(payer_info.key() == bounty.rent_recipient(), SeekError::Unauthorized);
+
+            if !bounty.is_terminal() || now < bounty.created_at.saturating_add(CLOSE_COOLDOWN) {
+                msg!("Skipping {}: not closable yet", bounty_info.key());
+                continue;
+            }
+
+            bounty.close(payer_info.clone())?;
+            closed += 1;
+        }
+
+        msg!("Closed {} of {} bounties, rent refunded to their payers",
+            closed,
+            remaining.len() / 2
+        );
+        Ok(())
+    }
+}
+
+/// Step 1: Initialize global state only (small stack footprint)
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    /// Authority who will manage the protocol. On mainnet, must match the
+    /// hardcoded `EXPECTED_INITIAL_AUTHORITY` (see top of file). Closes the
+    /// front-run vector between `anchor deploy` and the first `initialize` tx.
+    #[account(
+        mut,
+        constraint = is_expected_initial_authority(&authority.key()) @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        init,
+        payer = authority,
+        space = GlobalState::SIZE,
+        seeds = [b"global_state"],
+        bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// System program for account creation
+    pub system_program: Program<'info, System>,
+}
+
+/// Runtime check for the initialize-time authority. On mainnet, requires the
+/// hardcoded value AND rejects the System-Program placeholder so a forgotten
+/// edit fails fast. On devnet, permissive (no production value at risk).
+#[inline(always)]
+fn is_expected_initial_authority(_caller: &Pubkey) -> bool {
+    #[cfg(feature = "mainnet")]
+    {
+        // Reject the placeholder so a forgotten edit fails at init time.
+        if EXPECTED_INITIAL_AUTHORITY == Pubkey::default() {
+            return false;
+        }
+        *_caller == EXPECTED_INITIAL_AUTHORITY
+    }
+    #[cfg(not(feature = "mainnet"))]
+    {
+        true
+    }
+}
+
+/// Step 2: Initialize house vault
+#[derive(Accounts)]
+pub struct InitializeHouseVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump,
+        constraint = global_state.authority == authority.key() @ SeekError::Unauthorized
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = skr_mint,
+        token::authority = global_state,
+        seeds = [b"house_vault"],
+        bump
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = SKR_MINT @ SeekError::InvalidMint)]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+/// Step 3: Initialize singularity vault + set treasury
+#[derive(Accounts)]
+pub struct InitializeSingularityVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump,
+        constraint = global_state.authority == authority.key() @ SeekError::Unauthorized
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = skr_mint,
+        token::authority = global_state,
+        seeds = [b"singularity_vault"],
+        bump
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury - existing token account for protocol fees
+    #[account(
+        token::mint = skr_mint,
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = SKR_MINT @ SeekError::InvalidMint)]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(entry_amount: u64, timestamp: i64)]
+pub struct AcceptBounty<'info> {
+    /// Player accepting the bounty
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Pays the bounty account's rent and gets it back on close: the player
+    /// themselves, or a relayer funding the accept. Also pays for the
+    /// player's stats and loyalty escrow on their first accept, so a
+    /// relayed player needs no SOL at all.
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// Bounty PDA - unique per player + timestamp
+    #[account(
+        init,
+        payer = rent_payer,
+        space = Bounty::SIZE,
+        seeds = [b"bounty", player.key().as_ref(), &timestamp.to_le_bytes()],
+        bump
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals, created on their first bounty
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        space = PlayerStats::SIZE,
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// Player's loyalty rebates, created on their first bounty
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        space = LoyaltyEscrow::SIZE,
+        seeds = [b"loyalty_escrow", player.key().as_ref()],
+        bump
+    )]
+    pub loyalty_escrow: Box<Account<'info, LoyaltyEscrow>>,
+
+    /// Player's SKR token account — pinned to the canonical ATA.
+    /// Prevents passing a delegated/frozen/alt-ATA that could reroute winnings.
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&player.key(), &SKR_MINT) @ SeekError::Unauthorized
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// House vault to receive entry
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// The SKR token mint
+    #[account(
+        address = SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: SlotHashes sysvar (address-checked), read raw for the accept
+    /// ordering key
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// Optional pre-published pool to draw mission_commitment from
+    #[account(
+        mut,
+        seeds = [b"commitment_pool", commitment_pool.pool_id.to_le_bytes().as_ref()],
+        bump = commitment_pool.bump
+    )]
+    pub commitment_pool: Option<Box<Account<'info, CommitmentPool>>>,
+}
+
+/// Practice hunt: same bounty PDA as AcceptBounty, no token accounts.
+#[derive(Accounts)]
+#[instruction(tier: u8, timestamp: i64)]
+pub struct AcceptPracticeBounty<'info> {
+    /// Player starting the practice hunt
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// Pays the bounty's rent (the player, or a relayer), and the player's
+    /// stats on their first hunt
+    #[account(mut)]
+    pub rent_payer: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// Bounty PDA - unique per player + timestamp
+    #[account(
+        init,
+        payer = rent_payer,
+        space = Bounty::SIZE,
+        seeds = [b"bounty", player.key().as_ref(), &timestamp.to_le_bytes()],
+        bump
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals, created here if practice is their first
+    /// hunt: propose, finalize and mark_timeout all load it. Practice
+    /// settlements never change it.
+    #[account(
+        init_if_needed,
+        payer = rent_payer,
+        space = PlayerStats::SIZE,
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+// === NEW TRUST-MINIMIZATION ACCOUNT STRUCTS ===
+
+#[derive(Accounts)]
+pub struct SubmitPhoto<'info> {
+    /// Player submitting their photo
+    #[account(
+        constraint = player.key() == bounty.player @ SeekError::Unauthorized
+    )]
+    pub player: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// The bounty being submitted
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
+
+#[derive(Accounts)]
+#[instruction(mission_id: [u8; 32])]
+pub struct RevealMission<'info> {
+    /// Hot authority revealing the mission (backend-held). Pays rent for the
+    /// first counted reveal of each mission_id.
+    #[account(
+        mut,
+        constraint = hot_authority.key() == global_state.hot_authority @ SeekError::Unauthorized
+    )]
+    pub hot_authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// The bounty to reveal mission for
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// Reveal counter for this mission_id. Required while max_mission_reuse
+    /// is set; otherwise the reveal goes uncounted without it.
+    #[account(
+        init_if_needed,
+        payer = hot_authority,
+        space = MissionUsage::SIZE,
+        seeds = [b"mission_usage", mission_id.as_ref()],
+        bump
+    )]
+    pub mission_usage: Option<Box<Account<'info, MissionUsage>>>,
+
+    /// BlockedMission PDA for this mission_id. Must not exist.
+    /// CHECK: only its seeds and emptiness are checked.
+    #[account(
+        seeds = [b"blocked_mission", mission_id.as_ref()],
+        bump
+    )]
+    pub blocked_mission: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Same accounts as RevealMission; the usage PDA is keyed on field 0.
+#[derive(Accounts)]
+#[instruction(mission_id: [u8; 32])]
+pub struct RevealMissionFields<'info> {
+    /// Hot authority revealing the mission (backend-held). Pays rent for the
+    /// first counted reveal of each mission_id.
+    #[account(
+        mut,
+        constraint = hot_authority.key() == global_state.hot_authority @ SeekError::Unauthorized
+    )]
+    pub hot_authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// The bounty to reveal mission for
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// Reveal counter for field 0 (the mission_id). Required while
+    /// max_mission_reuse is set.
+    #[account(
+        init_if_needed,
+        payer = hot_authority,
+        space = MissionUsage::SIZE,
+        seeds = [b"mission_usage", mission_id.as_ref()],
+        bump
+    )]
+    pub mission_usage: Option<Box<Account<'info, MissionUsage>>>,
+
+    /// BlockedMission PDA for field 0. Must not exist.
+    /// CHECK: only its seeds and emptiness are checked.
+    #[account(
+        seeds = [b"blocked_mission", mission_id.as_ref()],
+        bump
+    )]
+    pub blocked_mission: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeResolution<'info> {
+    /// Hot authority proposing the resolution (backend-held)
+    #[account(
+        constraint = hot_authority.key() == global_state.hot_authority @ SeekError::Unauthorized
+    )]
+    pub hot_authority: Signer<'info>,
+
+    /// Global state PDA (mut for fast-resolve settlement)
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The bounty being resolved
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals
+    #[account(
+        mut,
+        seeds = [b"player_stats", bounty.player.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// Player's loyalty escrow. Without it a win is paid in full, with no
+    /// rebate withheld.
+    #[account(
+        mut,
+        seeds = [b"loyalty_escrow", bounty.player.as_ref()],
+        bump = loyalty_escrow.bump
+    )]
+    pub loyalty_escrow: Option<Box<Account<'info, LoyaltyEscrow>>>,
+
+    /// Optional loss oracle co-signer. Present = the proposed loss carries
+    /// the oracle's attestation and can't be disputed.
+    #[account(
+        constraint = global_state.loss_oracle != Pubkey::default() @ SeekError::Unauthorized,
+        constraint = loss_oracle.key() == global_state.loss_oracle @ SeekError::Unauthorized
+    )]
+    pub loss_oracle: Option<Signer<'info>>,
+
+    // The accounts below are only touched when the bounty's tier is in
+    // fast_resolve_tiers; they match finalize_bounty's.
+
+    /// Player's token account for payout. Required on a fast-resolved win;
+    /// a loss or a practice hunt settles without it.
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&bounty.player, &SKR_MINT) @ SeekError::Unauthorized,
+        constraint = player_token_account.key() != house_vault.key() @ SeekError::InvalidTokenAccount
+    )]
+    pub player_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// House vault
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault for jackpot
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury for fees
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Charity vault for its jackpot slice. Required only when a jackpot
+    /// can pay out with charity_jackpot_bps > 0.
+    #[account(
+        mut,
+        constraint = charity_vault.key() == global_state.charity_vault @ SeekError::Unauthorized,
+        constraint = charity_vault.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub charity_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The SKR token mint (supply decreases on a win burn)
+    #[account(
+        mut,
+        address = SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// ProposeResolution's accounts plus RevealMission's usage and block PDAs.
+#[derive(Accounts)]
+#[instruction(mission_id: [u8; 32])]
+pub struct RevealAndPropose<'info> {
+    /// Hot authority revealing and proposing (backend-held). Pays rent for
+    /// the first counted reveal of each mission_id.
+    #[account(
+        mut,
+        constraint = hot_authority.key() == global_state.hot_authority @ SeekError::Unauthorized
+    )]
+    pub hot_authority: Signer<'info>,
+
+    /// Global state PDA (mut for fast-resolve settlement)
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The bounty to reveal and resolve
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals
+    #[account(
+        mut,
+        seeds = [b"player_stats", bounty.player.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// Player's loyalty escrow. Without it a win is paid in full, with no
+    /// rebate withheld.
+    #[account(
+        mut,
+        seeds = [b"loyalty_escrow", bounty.player.as_ref()],
+        bump = loyalty_escrow.bump
+    )]
+    pub loyalty_escrow: Option<Box<Account<'info, LoyaltyEscrow>>>,
+
+    /// Optional loss oracle co-signer. Present = the proposed loss carries
+    /// the oracle's attestation and can't be disputed.
+    #[account(
+        constraint = global_state.loss_oracle != Pubkey::default() @ SeekError::Unauthorized,
+        constraint = loss_oracle.key() == global_state.loss_oracle @ SeekError::Unauthorized
+    )]
+    pub loss_oracle: Option<Signer<'info>>,
+
+    // The accounts below are only touched when the bounty's tier is in
+    // fast_resolve_tiers; they match finalize_bounty's.
+
+    /// Player's token account for payout. Required on a fast-resolved win;
+    /// a loss or a practice hunt settles without it.
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&bounty.player, &SKR_MINT) @ SeekError::Unauthorized,
+        constraint = player_token_account.key() != house_vault.key() @ SeekError::InvalidTokenAccount
+    )]
+    pub player_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// House vault
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault for jackpot
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury for fees
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Charity vault for its jackpot slice. Required only when a jackpot
+    /// can pay out with charity_jackpot_bps > 0.
+    #[account(
+        mut,
+        constraint = charity_vault.key() == global_state.charity_vault @ SeekError::Unauthorized,
+        constraint = charity_vault.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub charity_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The SKR token mint (supply decreases on a win burn)
+    #[account(
+        mut,
+        address = SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    /// Reveal counter for this mission_id. Required while max_mission_reuse
+    /// is set; otherwise the reveal goes uncounted without it.
+    #[account(
+        init_if_needed,
+        payer = hot_authority,
+        space = MissionUsage::SIZE,
+        seeds = [b"mission_usage", mission_id.as_ref()],
+        bump
+    )]
+    pub mission_usage: Option<Box<Account<'info, MissionUsage>>>,
+
+    /// BlockedMission PDA for this mission_id. Must not exist.
+    /// CHECK: only its seeds and emptiness are checked.
+    #[account(
+        seeds = [b"blocked_mission", mission_id.as_ref()],
+        bump
+    )]
+    pub blocked_mission: UncheckedAccount<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MarkTimeout<'info> {
+    /// Hot authority recording the timeout (backend-held)
+    #[account(
+        constraint = hot_authority.key() == global_state.hot_authority @ SeekError::Unauthorized
+    )]
+    pub hot_authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The expired bounty
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals
+    #[account(
+        mut,
+        seeds = [b"player_stats", bounty.player.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// House vault
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault (receives its loss share)
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury for fees
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// The SKR token mint
+    #[account(address = SKR_MINT @ SeekError::InvalidMint)]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct WaiveChallenge<'info> {
+    /// Player waiving their own challenge period
+    #[account(
+        constraint = player.key() == bounty.player @ SeekError::Unauthorized
+    )]
+    pub player: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// The bounty whose win the player wants paid now
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeBounty<'info> {
+    /// Anyone can finalize after challenge period (permissionless)
+    pub caller: Signer<'info>,
+
+    /// Global state PDA. The seeds admit exactly one global_state per
+    /// program, and the bounty must be recorded against it, so the vault
+    /// and treasury checks below are always against the bounty's own state.
+    /// Each vault is also bound to it by key, token owner and mint, so a
+    /// valid vault of another deployment fails with InvalidVault.
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The bounty being finalized
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals
+    #[account(
+        mut,
+        seeds = [b"player_stats", bounty.player.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// Player's loyalty escrow. Without it a win is paid in full, with no
+    /// rebate withheld.
+    #[account(
+        mut,
+        seeds = [b"loyalty_escrow", bounty.player.as_ref()],
+        bump = loyalty_escrow.bump
+    )]
+    pub loyalty_escrow: Option<Box<Account<'info, LoyaltyEscrow>>>,
+
+    /// Player's token account for payout. Required on a win; a loss can be
+    /// finalized without it (e.g. after the player closed the account).
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&bounty.player, &SKR_MINT) @ SeekError::Unauthorized,
+        constraint = player_token_account.key() != house_vault.key() @ SeekError::InvalidTokenAccount
+    )]
+    pub player_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// House vault
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault @ SeekError::InvalidVault,
+        constraint = house_vault.owner == bounty.global_state @ SeekError::InvalidVault,
+        constraint = house_vault.mint == skr_mint.key() @ SeekError::InvalidVault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault for jackpot
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault @ SeekError::InvalidVault,
+        constraint = singularity_vault.owner == bounty.global_state @ SeekError::InvalidVault,
+        constraint = singularity_vault.mint == skr_mint.key() @ SeekError::InvalidVault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury for fees
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury @ SeekError::InvalidVault,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Charity vault for its jackpot slice. Required only when a jackpot
+    /// can pay out with charity_jackpot_bps > 0.
+    #[account(
+        mut,
+        constraint = charity_vault.key() == global_state.charity_vault @ SeekError::InvalidVault,
+        constraint = charity_vault.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub charity_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The SKR token mint (supply decreases on a win burn)
+    #[account(
+        mut,
+        address = SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Read-only loss simulation. No signer required.
+#[derive(Accounts)]
+pub struct SimulateLoss<'info> {
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+/// Read-only PDA info. No signer required.
+#[derive(Accounts)]
+pub struct GetPdaInfo<'info> {
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+/// Read-only player at-risk view. No signer required.
+#[derive(Accounts)]
+pub struct GetPlayerAtRisk<'info> {
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// The bounty to report on
+    #[account(
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
+
+/// Read-only win verification view. No signer required.
+#[derive(Accounts)]
+pub struct VerifyWin<'info> {
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// The settled bounty to verify
+    #[account(
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
+
+/// Read-only player PnL view. No signer required.
+#[derive(Accounts)]
+pub struct GetPlayerPnl<'info> {
+    /// The player's lifetime totals
+    pub player_stats: Account<'info, PlayerStats>,
+}
+
+/// Read-only tier availability check. No signer required.
+#[derive(Accounts)]
+pub struct CanAcceptTier<'info> {
+    /// Global state PDA
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+#[derive(Accounts)]
+pub struct FundHouse<'info> {
+    /// Authority funding the house
+    #[account(
+        mut,
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// Authority's SKR token account
+    #[account(
+        mut,
+        constraint = authority_token_account.mint == SKR_MINT @ SeekError::InvalidMint,
+        constraint = authority_token_account.owner == authority.key() @ SeekError::Unauthorized
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    /// House vault to receive funds
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FundSingularity<'info> {
+    /// Authority funding the jackpot
+    #[account(
+        mut,
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// Authority's SKR token account
+    #[account(
+        mut,
+        constraint = authority_token_account.mint == SKR_MINT @ SeekError::InvalidMint,
+        constraint = authority_token_account.owner == authority.key() @ SeekError::Unauthorized
+    )]
+    pub authority_token_account: Account<'info, TokenAccount>,
+
+    /// Singularity vault to receive funds
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Permissionless epoch crank.
+#[derive(Accounts)]
+pub struct AdvanceEpoch<'info> {
+    /// Anyone can advance once the epoch is over
+    pub caller: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+#[derive(Accounts)]
+pub struct CloseProtocol<'info> {
+    /// Cold authority (receives GlobalState's rent)
+    #[account(
+        mut,
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA being closed
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// CHECK: house vault address; must already be closed by sweep_vaults
+    #[account(address = global_state.house_vault)]
+    pub house_vault: UncheckedAccount<'info>,
+
+    /// CHECK: singularity vault address; must already be closed by sweep_vaults
+    #[account(address = global_state.singularity_vault)]
+    pub singularity_vault: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SweepVaults<'info> {
+    /// Cold authority (receives the vaults' rent)
+    #[account(
+        mut,
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// House vault being swept and closed
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault being swept and closed
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Authority's SKR token account receiving both balances
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key() @ SeekError::Unauthorized,
+        constraint = authority_token_account.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub authority_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct DistributeJackpotOnShutdown<'info> {
+    /// Cold authority
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// House vault (destination under ReturnToHouse)
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault being emptied
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury (destination under SendToTreasury)
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct EscheatUnclaimedJackpot<'info> {
+    /// Cold authority
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The unclaimed win
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// House vault the payout leaves
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault (destination under ReturnToPool)
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury (destination under SendToTreasury)
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct BurnTreasury<'info> {
+    /// Cold authority approving the burn
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Owner of the protocol treasury token account (may equal authority)
+    pub treasury_owner: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// Protocol treasury to burn from
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.owner == treasury_owner.key() @ SeekError::Unauthorized
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// The SKR token mint (supply decreases)
+    #[account(
+        mut,
+        address = SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct DisputeBounty<'info> {
+    /// Player disputing the bounty
+    #[account(
+        mut,
+        constraint = player.key() == bounty.player @ SeekError::Unauthorized
+    )]
+    pub player: Signer<'info>,
+
+    /// Global state PDA (mut to track dispute stake)
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The bounty being disputed
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals
+    #[account(
+        mut,
+        seeds = [b"player_stats", bounty.player.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// Player's token account for stake — pinned to canonical ATA.
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&player.key(), &SKR_MINT) @ SeekError::Unauthorized
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// House vault to receive stake
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury to receive the dispute fee
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct JoinDispute<'info> {
+    /// Player backing someone else's dispute (pays the share's rent)
+    #[account(mut)]
+    pub disputer: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The disputed bounty
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// This disputer's stake record; one per disputer per bounty
+    #[account(
+        init,
+        payer = disputer,
+        space = DisputeShare::SIZE,
+        seeds = [b"dispute_share", bounty.key().as_ref(), disputer.key().as_ref()],
+        bump
+    )]
+    pub dispute_share: Box<Account<'info, DisputeShare>>,
+
+    /// Disputer's token account for the stake — canonical ATA, where any
+    /// payout is sent
+    #[account(
+        mut,
+        constraint = disputer_token_account.key() == get_associated_token_address(&disputer.key(), &SKR_MINT) @ SeekError::Unauthorized
+    )]
+    pub disputer_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// House vault to receive stake
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    /// Authority resolving the dispute
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The disputed bounty
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals
+    #[account(
+        mut,
+        seeds = [b"player_stats", bounty.player.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// Player's token account for refund — pinned to canonical ATA.
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&bounty.player, &SKR_MINT) @ SeekError::Unauthorized,
+        constraint = player_token_account.key() != house_vault.key() @ SeekError::InvalidTokenAccount
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// House vault
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault for loss distribution
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury for loss distribution
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Charity vault for its jackpot slice. Required only when a jackpot
+    /// can pay out with charity_jackpot_bps > 0.
+    #[account(
+        mut,
+        constraint = charity_vault.key() == global_state.charity_vault @ SeekError::Unauthorized,
+        constraint = charity_vault.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub charity_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> ResolveDispute<'info> {
+    /// Settle the disputed bounty with `ruling`. Shared by resolve_dispute
+    /// and resolve_dispute_with_evidence.
+    fn resolve(
+        &mut self,
+        ruling: DisputeRuling,
+        protocol_destinations: Vec<AccountInfo<'info>>,
+        co_disputers: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        // Verify bounty is disputed
+        require!(
+            self.bounty.status == BountyStatus::Disputed,
+            SeekError::ExpectedDisputed
+        );
+
+        let vaults = VaultAccounts {
+            global_state: self.global_state.to_account_info(),
+            house_vault: self.house_vault.to_account_info(),
+            singularity_vault: self.singularity_vault.to_account_info(),
+            protocol_treasury: self.protocol_treasury.to_account_info(),
+            charity_vault: self.charity_vault.as_ref().map(|c| c.to_account_info()),
+            protocol_destinations,
+            token_program: self.token_program.to_account_info(),
+            global_state_bump: self.global_state.bump,
+        };
+
+        settle_dispute(
+            &mut self.global_state,
+            &mut self.bounty,
+            ruling,
+            self.house_vault.amount,
+            &vaults,
+            &self.player_token_account.to_account_info(),
+            &mut self.player_stats,
+        )?;
+
+        settle_co_disputers(
+            &mut self.global_state,
+            &self.bounty,
+            self.bounty.key(),
+            if ruling.player_wins { CoDisputeOutcome::Won } else { CoDisputeOutcome::Lost },
+            &vaults,
+            co_disputers,
+        )?;
+
+        emit_balances_snapshot(&self.global_state)
+    }
+}
+
+/// Player-driven batch finalize. The player's bounty PDAs are passed in
+/// `remaining_accounts`; every payout goes to the single token account here.
+#[derive(Accounts)]
+pub struct FinalizeMyWins<'info> {
+    /// Player claiming their own wins
+    pub player: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// Player's lifetime totals
+    #[account(
+        mut,
+        seeds = [b"player_stats", player.key().as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// Player's loyalty escrow. Without it a win is paid in full, with no
+    /// rebate withheld.
+    #[account(
+        mut,
+        seeds = [b"loyalty_escrow", player.key().as_ref()],
+        bump = loyalty_escrow.bump
+    )]
+    pub loyalty_escrow: Option<Box<Account<'info, LoyaltyEscrow>>>,
+
+    /// Player's token account receiving the combined payout
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&player.key(), &SKR_MINT) @ SeekError::Unauthorized,
+        constraint = player_token_account.key() != house_vault.key() @ SeekError::InvalidTokenAccount
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// House vault
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault for jackpot
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury (unused on wins; shared settlement accounts)
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Charity vault for its jackpot slice. Required only when a jackpot
+    /// can pay out with charity_jackpot_bps > 0.
+    #[account(
+        mut,
+        constraint = charity_vault.key() == global_state.charity_vault @ SeekError::Unauthorized,
+        constraint = charity_vault.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub charity_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The SKR token mint (supply decreases on a win burn)
+    #[account(
+        mut,
+        address = SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Keeper sweep. Bounties and player token accounts are passed as
+/// `[bounty, player_token_account]` pairs in `remaining_accounts`.
+#[derive(Accounts)]
+pub struct KeeperSweep<'info> {
+    /// Anyone can crank the sweep (permissionless, like finalize_bounty)
+    pub caller: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// House vault
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault for jackpot and loss distribution
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury for loss distribution
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Charity vault for its jackpot slice. Required only when a jackpot
+    /// can pay out with charity_jackpot_bps > 0.
+    #[account(
+        mut,
+        constraint = charity_vault.key() == global_state.charity_vault @ SeekError::Unauthorized,
+        constraint = charity_vault.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub charity_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The SKR token mint (supply decreases on a win burn)
+    #[account(
+        mut,
+        address = SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Batch dispute resolution. Bounties and player token accounts are passed
+/// as `[bounty, player_token_account]` pairs in `remaining_accounts`.
+#[derive(Accounts)]
+pub struct ResolveDisputesBatch<'info> {
+    /// Authority resolving the disputes
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// House vault
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Singularity vault for loss distribution
+    #[account(
+        mut,
+        seeds = [b"singularity_vault"],
+        bump = global_state.singularity_vault_bump,
+        constraint = singularity_vault.key() == global_state.singularity_vault
+    )]
+    pub singularity_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Protocol treasury for loss distribution
+    #[account(
+        mut,
+        constraint = protocol_treasury.key() == global_state.protocol_treasury,
+        constraint = protocol_treasury.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub protocol_treasury: Box<Account<'info, TokenAccount>>,
+
+    /// Charity vault for its jackpot slice. Required only when a jackpot
+    /// can pay out with charity_jackpot_bps > 0.
+    #[account(
+        mut,
+        constraint = charity_vault.key() == global_state.charity_vault @ SeekError::Unauthorized,
+        constraint = charity_vault.mint == SKR_MINT @ SeekError::InvalidMint
+    )]
+    pub charity_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelBounty<'info> {
+    /// Player cancelling the bounty
+    #[account(
+        mut,
+        constraint = player.key() == bounty.player @ SeekError::Unauthorized
+    )]
+    pub player: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The bounty being cancelled
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals
+    #[account(
+        mut,
+        seeds = [b"player_stats", bounty.player.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// Player's token account for refund — pinned to canonical ATA.
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&player.key(), &SKR_MINT) @ SeekError::Unauthorized,
+        constraint = player_token_account.key() != house_vault.key() @ SeekError::InvalidTokenAccount
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// House vault to refund from
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimLoyalty<'info> {
+    /// Player claiming their rebates
+    pub player: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// Player's loyalty escrow
+    #[account(
+        mut,
+        seeds = [b"loyalty_escrow", player.key().as_ref()],
+        bump = loyalty_escrow.bump
+    )]
+    pub loyalty_escrow: Box<Account<'info, LoyaltyEscrow>>,
+
+    /// Player's token account for the rebate — pinned to canonical ATA.
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&player.key(), &SKR_MINT) @ SeekError::Unauthorized,
+        constraint = player_token_account.key() != house_vault.key() @ SeekError::InvalidTokenAccount
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// House vault holding the escrowed rebates
+    #[account(
+        mut,
+        seeds = [b"house_vault"],
+        bump = global_state.house_vault_bump,
+        constraint = house_vault.key() == global_state.house_vault
+    )]
+    pub house_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Step 1 of authority rotation: current authority proposes a new authority.
+#[derive(Accounts)]
+pub struct ProposeAuthorityTransfer<'info> {
+    /// Current authority
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+/// Step 2 of authority rotation: pending authority signs to accept.
+#[derive(Accounts)]
+pub struct AcceptAuthorityTransfer<'info> {
+    /// The pending authority (must match global_state.pending_authority)
+    pub new_authority: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+/// Cancel an in-flight authority transfer. Current authority only.
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+/// Rotate the hot authority. Cold authority only.
+#[derive(Accounts)]
+pub struct SetHotAuthority<'info> {
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+/// Block a mission_id. Cold authority only; pays the PDA's rent.
+#[derive(Accounts)]
+#[instruction(mission_id: [u8; 32])]
+pub struct BlockMission<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = BlockedMission::SIZE,
+        seeds = [b"blocked_mission", mission_id.as_ref()],
+        bump
+    )]
+    pub blocked_mission: Account<'info, BlockedMission>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(pool_id: u64)]
+pub struct PublishCommitmentPool<'info> {
+    /// Hot authority publishing the pool (backend-held); pays its rent
+    #[account(
+        mut,
+        constraint = hot_authority.key() == global_state.hot_authority @ SeekError::Unauthorized
+    )]
+    pub hot_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        init,
+        payer = hot_authority,
+        space = CommitmentPool::SIZE,
+        seeds = [b"commitment_pool", pool_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub commitment_pool: Box<Account<'info, CommitmentPool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(mission_id: [u8; 32])]
+pub struct ReclaimUnrevealed<'info> {
+    /// Anyone who can open the commitment (normally the backend)
+    pub caller: Signer<'info>,
+
+    /// Global state PDA
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// The bounty committed to the blocked mission
+    #[account(
+        mut,
+        constraint = bounty.global_state == global_state.key() @ SeekError::GlobalStateMismatch
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+
+    /// Player's lifetime totals
+    #[account(
+        mut,
+        seeds = [b"player_stats", bounty.player.as_ref()],
+        bump = player_stats.bump
+    )]
+    pub player_stats: Box<Account<'info, PlayerStats>>,
+
+    /// Proof the mission is blocked
+    #[account(
+        seeds = [b"blocked_mission", mission_id.as_ref()],
+        bump = blocked_mission.bump
+    )]
+    pub blocked_mission: Box<Account<'info, BlockedMission>>,
+
+    /// Player's token account for the refund — pinned to canonical ATA
+    #[account(
+        mut,
+        constraint = player_token_account.key() == get_associated_token_address(&bounty.player, &SKR_MINT) @ SeekError::Unauthorized,
+        constraint = player_token_account.key() != house_vault.key() @ SeekError::InvalidTokenAccount
+    )]
+    pub player_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// House vault holding the entry
+    #[account(
+        mut,
         seeds = [b"house_vault"],
-        bump,
+        bump = global_state.house_vault_bump,
         constraint = house_vault.key() == global_state.house_vault
     )]
     pub house_vault: Box<Account<'info, TokenAccount>>,
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
-}
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Update a protocol config value. Cold authority only.
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateGlobalState<'info> {
+    /// Cold authority (checked against the migrated state; pays the extra rent)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: legacy-size GlobalState, too short to load as
+    /// Account<GlobalState>; owner and discriminator are checked by
+    /// grow_account, the authority once it loads
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump
+    )]
+    pub global_state: UncheckedAccount<'info>,
+
+    /// The SKR token mint (its decimals fill GlobalState.skr_decimals)
+    #[account(address = SKR_MINT @ SeekError::InvalidMint)]
+    pub skr_mint: Box<Account<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateBounty<'info> {
+    /// Pays the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Global state PDA (already migrated)
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// CHECK: legacy-size bounty, too short to load as Account<Bounty>;
+    /// owner and discriminator are checked by grow_account, the global
+    /// state once it loads
+    #[account(mut)]
+    pub bounty: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rotate the protocol treasury recipient. Cold authority only.
+/// `new_treasury` must be an existing SKR TokenAccount (the rent-paying
+/// caller pre-creates the ATA off-chain — this instruction just records
+/// the new recipient on `GlobalState`).
+#[derive(Accounts)]
+pub struct SetTreasury<'info> {
+    #[account(
+        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        token::mint = skr_mint,
+    )]
+    pub new_treasury: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = SKR_MINT @ SeekError::InvalidMint)]
+    pub skr_mint: Box<Account<'info, Mint>>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBounty<'info> {
+    /// Player who owns the bounty
+    #[account(mut)]
+    pub player: Signer<'info>,
+
+    /// CHECK: receives the rent refund; pinned to whoever paid it at accept
+    #[account(
+        mut,
+        constraint = rent_payer.key() == bounty.rent_recipient() @ SeekError::Unauthorized
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    /// The bounty to close (must be terminal: Won/Lost/Cancelled)
+    #[account(
+        mut,
+        close = rent_payer,
+        constraint = bounty.player == player.key() @ SeekError::Unauthorized,
+        constraint = bounty.is_terminal() @ SeekError::ExpectedTerminal
+    )]
+    pub bounty: Box<Account<'info, Bounty>>,
+}
+
+/// Batch rent reclaim. Bounties and the rent payers their rent goes back to
+/// are passed as `[bounty, rent_payer]` pairs in `remaining_accounts`.
+#[derive(Accounts)]
+pub struct CloseBountiesBatch<'info> {
+    /// Anyone can crank the batch (rent only ever goes to each bounty's payer)
+    pub caller: Signer<'info>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::{
+        entrypoint::{ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+        instruction::Instruction,
+        program_stubs, system_program,
+    };
+    use std::cell::{Cell, RefCell};
+    use std::sync::Once;
+
+    const NOW: i64 = 1_700_000_000;
+
+    /// Fixture mint decimals (the mainnet SKR mint's) and the entry amounts
+    /// they give
+    const SKR_DECIMALS: u8 = 6;
+    const DECIMALS_MULTIPLIER: u64 = 10u64.pow(SKR_DECIMALS as u32);
+    const TIER_1_ENTRY: u64 = TIER_1_ENTRY_SKR * DECIMALS_MULTIPLIER;
+    const TIER_2_ENTRY: u64 = TIER_2_ENTRY_SKR * DECIMALS_MULTIPLIER;
+    const TIER_3_ENTRY: u64 = TIER_3_ENTRY_SKR * DECIMALS_MULTIPLIER;
+
+    thread_local! {
+        /// CPIs issued by the current test thread
+        static CPI_CALLS: Cell<usize> = const { Cell::new(0) };
+        /// Events (sol_log_data payloads) emitted by the current test thread
+        static EVENTS: RefCell<Vec<Vec<u8>>> = const { RefCell::new(Vec::new()) };
+        /// SPL token transfers (destination, amount) issued by the current
+        /// test thread
+        static TRANSFERS: RefCell<Vec<(Pubkey, u64)>> = const { RefCell::new(Vec::new()) };
+        /// System-program account creations (funder, new account) issued
+        /// by the current test thread
+        static CREATED: RefCell<Vec<(Pubkey, Pubkey)>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Off-chain syscalls: a fixed clock, CPIs counted instead of run, and
+    /// events recorded.
+    struct Stubs;
+
+    impl program_stubs::SyscallStubs for Stubs {
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            let clock = Clock {
+                slot: 1,
+                unix_timestamp: NOW,
+                ..Clock::default()
+            };
+            unsafe { *(var_addr as *mut Clock) = clock };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            anchor_lang::solana_program::entrypoint::SUCCESS
+        }
+
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            account_infos: &[AccountInfo],
+            _signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            CPI_CALLS.with(|c| c.set(c.get() + 1));
+            // system CreateAccount: tag 0, lamports, space, owner; accounts
+            // are [funder, new account]. Applied, so `init` can go on to
+            // deserialize what it created.
+            if instruction.program_id == system_program::ID
+                && instruction.data.get(..4) == Some(&[0; 4])
+            {
+                let data = &instruction.data;
+                let lamports = u64::from_le_bytes(data[4..12].try_into().unwrap());
+                let space = u64::from_le_bytes(data[12..20].try_into().unwrap());
+                let owner = Pubkey::try_from(&data[20..52]).unwrap();
+                let funder = instruction.accounts[0].pubkey;
+                let created = instruction.accounts[1].pubkey;
+                let info = account_infos.iter().find(|a| *a.key == created).unwrap();
+                **info.try_borrow_mut_lamports()? += lamports;
+                info.realloc(space as usize, true)?;
+                info.assign(&owner);
+                CREATED.with(|c| c.borrow_mut().push((funder, created)));
+            }
+            // spl_token Transfer: tag 3, then the amount; accounts are
+            // [source, destination, authority]
+            if instruction.program_id == token::ID && instruction.data.first() == Some(&3) {
+                let amount = u64::from_le_bytes(instruction.data[1..9].try_into().unwrap());
+                let destination = instruction.accounts[1].pubkey;
+                TRANSFERS.with(|t| t.borrow_mut().push((destination, amount)));
+            }
+            Ok(())
+        }
+
+        fn sol_log_data(&self, fields: &[&[u8]]) {
+            EVENTS.with(|e| e.borrow_mut().extend(fields.iter().map(|f| f.to_vec())));
+        }
+    }
+
+    fn install_stubs() {
+        static ONCE: Once = Once::new();
+        ONCE.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(Stubs));
+        });
+    }
+
+    fn cpi_calls() -> usize {
+        CPI_CALLS.with(|c| c.get())
+    }
+
+    /// Every token transfer issued on this thread so far, oldest first.
+    fn transfers() -> Vec<(Pubkey, u64)> {
+        TRANSFERS.with(|t| t.borrow().clone())
+    }
+
+    /// Every (funder, new account) created on this thread so far, oldest
+    /// first.
+    fn created() -> Vec<(Pubkey, Pubkey)> {
+        CREATED.with(|c| c.borrow().clone())
+    }
+
+    /// Every `E` emitted on this thread so far, oldest first.
+    fn emitted<E: Discriminator + AnchorDeserialize>() -> Vec<E> {
+        EVENTS.with(|e| {
+            e.borrow()
+                .iter()
+                .filter(|data| data.starts_with(&E::DISCRIMINATOR))
+                .map(|data| E::try_from_slice(&data[8..]).unwrap())
+                .collect()
+        })
+    }
+
+    /// A fresh account the way `init` leaves it: every field zeroed.
+    fn zeroed<T: AccountDeserialize>(size: usize) -> T {
+        T::try_deserialize_unchecked(&mut &vec![0u8; size][..]).unwrap()
+    }
+
+    /// Backing storage for one fake account. AccountInfo::realloc (and so
+    /// Anchor's close) reads the original data length from the 4 bytes
+    /// before the key and writes the new length to the 8 bytes before the
+    /// data, as the runtime serializes them; both are laid out here, along
+    /// with the runtime's spare room for the data to grow into.
+    #[repr(C)]
+    struct Slot {
+        original_data_len: u32,
+        key: Pubkey,
+        owner: Pubkey,
+        lamports: u64,
+        /// 8-byte length header, the account data, then growth room
+        buf: Vec<u8>,
+    }
+
+    impl Slot {
+        fn at(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+            let len = data.len();
+            let mut buf = (len as u64).to_le_bytes().to_vec();
+            buf.extend(data);
+            buf.resize(8 + len + MAX_PERMITTED_DATA_INCREASE, 0);
+            Slot {
+                original_data_len: len as u32,
+                key,
+                owner,
+                lamports: 1_000_000,
+                buf,
+            }
+        }
+
+        fn new(owner: Pubkey, data: Vec<u8>) -> Self {
+            Slot::at(Pubkey::new_unique(), owner, data)
+        }
+
+        fn of<T: AccountSerialize>(account: &T) -> Self {
+            let mut data = Vec::new();
+            account.try_serialize(&mut data).unwrap();
+            Slot::new(crate::ID, data)
+        }
+
+        fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                false,
+                true,
+                &mut self.lamports,
+                &mut self.buf[8..8 + self.original_data_len as usize],
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    /// Every vault in the bundle backed by the same dummy account; tests
+    /// count CPIs rather than inspect token balances.
+    fn vaults<'a>(info: &AccountInfo<'a>) -> VaultAccounts<'a> {
+        VaultAccounts {
+            global_state: info.clone(),
+            house_vault: info.clone(),
+            singularity_vault: info.clone(),
+            protocol_treasury: info.clone(),
+            charity_vault: None,
+            protocol_destinations: Vec::new(),
+            token_program: info.clone(),
+            global_state_bump: 0,
+        }
+    }
+
+    /// An account that lives for the rest of the test binary, so handler
+    /// tests can build Contexts, whose accounts are `'info`-bound.
+    fn leak(slot: Slot) -> AccountInfo<'static> {
+        Box::leak(Box::new(slot)).info()
+    }
+
+    fn pin(info: AccountInfo<'static>) -> &'static AccountInfo<'static> {
+        Box::leak(Box::new(info))
+    }
+
+    fn leaked<T: AccountSerialize + AccountDeserialize + Owner + Clone>(
+        account: &T,
+    ) -> Box<Account<'static, T>> {
+        Box::new(Account::try_from(pin(leak(Slot::of(account)))).unwrap())
+    }
+
+    /// A player's PlayerStats the way accept_bounty first creates it.
+    fn player_stats() -> Box<Account<'static, PlayerStats>> {
+        leaked(&zeroed::<PlayerStats>(PlayerStats::SIZE))
+    }
+
+    fn loyalty_escrow() -> Box<Account<'static, LoyaltyEscrow>> {
+        leaked(&zeroed::<LoyaltyEscrow>(LoyaltyEscrow::SIZE))
+    }
+
+    fn signer(key: Pubkey) -> Signer<'static> {
+        let mut info = leak(Slot::at(key, system_program::ID, Vec::new()));
+        info.is_signer = true;
+        Signer::try_from(pin(info)).unwrap()
+    }
+
+    fn token_program() -> Program<'static, Token> {
+        let mut info = leak(Slot::at(token::ID, Pubkey::default(), Vec::new()));
+        info.executable = true;
+        Program::try_from(pin(info)).unwrap()
+    }
+
+    fn system_program() -> Program<'static, System> {
+        let mut info = leak(Slot::at(system_program::ID, Pubkey::default(), Vec::new()));
+        info.executable = true;
+        Program::try_from(pin(info)).unwrap()
+    }
+
+    /// An initialized SKR token account holding `amount`.
+    fn token_account(key: Pubkey, owner: Pubkey, amount: u64) -> AccountInfo<'static> {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token::spl_token::state::{Account as SplAccount, AccountState};
+
+        let mut data = vec![0; SplAccount::LEN];
+        SplAccount {
+            mint: SKR_MINT,
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            ..SplAccount::default()
+        }
+        .pack_into_slice(&mut data);
+        leak(Slot::at(key, token::ID, data))
+    }
+
+    fn vault(amount: u64) -> Box<Account<'static, TokenAccount>> {
+        let info = token_account(Pubkey::new_unique(), Pubkey::new_unique(), amount);
+        Box::new(Account::try_from(pin(info)).unwrap())
+    }
+
+    fn finalize_accounts(
+        global_state: Box<Account<'static, GlobalState>>,
+        bounty: Box<Account<'static, Bounty>>,
+    ) -> FinalizeBounty<'static> {
+        FinalizeBounty {
+            caller: signer(Pubkey::new_unique()),
+            global_state,
+            bounty,
+            player_stats: player_stats(),
+            loyalty_escrow: None,
+            player_token_account: Some(vault(0)),
+            house_vault: vault(1_000_000),
+            singularity_vault: vault(0),
+            protocol_treasury: vault(0),
+            charity_vault: None,
+            skr_mint: skr_mint(),
+            token_program: token_program(),
+        }
+    }
+
+    fn finalize(accounts: &mut FinalizeBounty<'static>) -> Result<()> {
+        seek_protocol::finalize_bounty(Context::new(
+            &crate::ID,
+            accounts,
+            &[],
+            FinalizeBountyBumps::default(),
+        ))
+    }
+
+    fn skr_mint() -> Box<Account<'static, Mint>> {
+        use anchor_lang::solana_program::program_pack::Pack;
+        use anchor_spl::token::spl_token::state::Mint as SplMint;
+
+        let mut data = vec![0; SplMint::LEN];
+        SplMint {
+            decimals: SKR_DECIMALS,
+            is_initialized: true,
+            ..SplMint::default()
+        }
+        .pack_into_slice(&mut data);
+        Box::new(Account::try_from(pin(leak(Slot::at(SKR_MINT, token::ID, data)))).unwrap())
+    }
+
+    fn error_code<T>(result: Result<T>) -> Option<u32> {
+        match result {
+            Err(Error::AnchorError(e)) => Some(e.error_code_number),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn batch_resolves_three_disputes_in_one_call() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+
+        // Three disputed losses, one [bounty, player ATA, player stats] triple each
+        let mut items = Vec::new();
+        for _ in 0..3 {
+            let player = Pubkey::new_unique();
+            let mut bounty: Bounty = zeroed(Bounty::SIZE);
+            bounty.player = player;
+            bounty.global_state = global_state.key();
+            bounty.status = BountyStatus::Disputed;
+            bounty.is_disputed = true;
+            bounty.entry_amount = 1_000;
+            bounty.dispute_stake = 500;
+
+            items.push(leak(Slot::of(&bounty)));
+            items.push(leak(Slot::at(
+                get_associated_token_address(&player, &SKR_MINT),
+                token::ID,
+                Vec::new(),
+            )));
+            items.push(leak(Slot::of(&PlayerStats { player, ..zeroed(PlayerStats::SIZE) })));
+        }
+        let items: &'static [AccountInfo<'static>] = items.leak();
+
+        let mut accounts = ResolveDisputesBatch {
+            authority: signer(Pubkey::new_unique()),
+            global_state,
+            house_vault: vault(1_000_000),
+            singularity_vault: vault(0),
+            protocol_treasury: vault(0),
+            charity_vault: None,
+            token_program: token_program(),
+        };
+        let cpis_before = cpi_calls();
+        let ruling = |player_wins| DisputeRuling { player_wins, reason_code: 0 };
+        seek_protocol::resolve_disputes_batch(
+            Context::new(&crate::ID, &mut accounts, items, ResolveDisputesBatchBumps::default()),
+            vec![ruling(true), ruling(true), ruling(false)],
+        )
+        .unwrap();
+
+        let statuses: Vec<BountyStatus> = items
+            .chunks(3)
+            .map(|item| Account::<Bounty>::try_from(&item[0]).unwrap().status)
+            .collect();
+        assert!(statuses == [BountyStatus::Won, BountyStatus::Won, BountyStatus::Lost]);
+        // One refund per win; the loss pays the singularity and treasury
+        assert_eq!(cpi_calls(), cpis_before + 4);
+        let global_state = &accounts.global_state;
+        assert_eq!((global_state.total_bounties_won, global_state.total_bounties_lost), (2, 1));
+        assert_eq!(global_state.house_fund_balance, 1_000_000 - 2 * 1_500 - 300);
+        let stats: Vec<(u32, u32)> = items
+            .chunks(3)
+            .map(|item| Account::<PlayerStats>::try_from(&item[2]).unwrap())
+            .map(|stats| (stats.wins, stats.losses))
+            .collect();
+        assert_eq!(stats, [(1, 0), (1, 0), (0, 1)], "each player's own stats are updated");
+    }
+
+    const SALT: [u8; 32] = [7; 32];
+
+    /// A live Pending bounty committed to `mission_id` with SALT.
+    fn committed_bounty(mission_id: [u8; 32]) -> Bounty {
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.status = BountyStatus::Pending;
+        bounty.created_at = NOW - 60;
+        bounty.expires_at = NOW - 60 + TIER_1_DURATION;
+        bounty.mission_commitment =
+            anchor_lang::solana_program::hash::hashv(&[&mission_id, &SALT]).to_bytes();
+        bounty
+    }
+
+    /// The BlockedMission PDA slot: created by block_mission for
+    /// `Some(mission_id)`, empty otherwise.
+    fn blocked_mission(mission_id: Option<[u8; 32]>) -> UncheckedAccount<'static> {
+        let slot = match mission_id {
+            Some(mission_id) => Slot::of(&BlockedMission { mission_id, blocked_at: NOW, bump: 0 }),
+            None => Slot::new(system_program::ID, Vec::new()),
+        };
+        UncheckedAccount::try_from(pin(leak(slot)))
+    }
+
+    /// Reveal `mission_id` on `bounty` through reveal_mission, counted on
+    /// `usage` if passed, which is handed back for the next reveal.
+    fn reveal_bounty(
+        global_state: &GlobalState,
+        bounty: &Bounty,
+        mission_id: [u8; 32],
+        usage: Option<Account<'static, MissionUsage>>,
+    ) -> (Result<()>, Option<Account<'static, MissionUsage>>) {
+        install_stubs();
+
+        let mut accounts = RevealMission {
+            hot_authority: signer(global_state.hot_authority),
+            global_state: *leaked(global_state),
+            bounty: *leaked(bounty),
+            mission_usage: usage.map(Box::new),
+            blocked_mission: blocked_mission(None),
+            system_program: system_program(),
+        };
+        let bumps = RevealMissionBumps {
+            mission_usage: accounts.mission_usage.as_ref().map(|_| 254),
+            ..Default::default()
+        };
+        let result = seek_protocol::reveal_mission(
+            Context::new(&crate::ID, &mut accounts, &[], bumps),
+            mission_id,
+            SALT,
+            None,
+        );
+        (result, accounts.mission_usage.map(|usage| *usage))
+    }
+
+    /// Reveal a fresh bounty committed to `mission_id`.
+    fn reveal(
+        global_state: &GlobalState,
+        mission_id: [u8; 32],
+        usage: Option<Account<'static, MissionUsage>>,
+    ) -> (Result<()>, Option<Account<'static, MissionUsage>>) {
+        reveal_bounty(global_state, &committed_bounty(mission_id), mission_id, usage)
+    }
+
+    #[test]
+    fn reveals_past_max_reuse_fail() {
+        let mission_id = [3u8; 32];
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.max_mission_reuse = 2;
+        global_state.commitment_ttl = COMMITMENT_TTL;
+        let usage = Some(*leaked(&zeroed::<MissionUsage>(MissionUsage::SIZE)));
+
+        let (result, usage) = reveal(&global_state, mission_id, usage);
+        result.unwrap();
+        let (result, usage) = reveal(&global_state, mission_id, usage);
+        result.unwrap();
+        let counted = usage.as_ref().unwrap();
+        assert_eq!((counted.reveal_count, counted.mission_id, counted.bump), (2, mission_id, 254));
+
+        let (result, usage) = reveal(&global_state, mission_id, usage);
+        assert_eq!(error_code(result), Some(6000 + SeekError::MissionReuseExceeded as u32));
+        assert_eq!(usage.as_ref().unwrap().reveal_count, 2);
+
+        // Leaving the counter out can't dodge the cap
+        let (result, _) = reveal(&global_state, mission_id, None);
+        assert_eq!(error_code(result), Some(6000 + SeekError::MissionUsageRequired as u32));
+
+        // Zero lifts the cap
+        global_state.max_mission_reuse = 0;
+        let (result, usage) = reveal(&global_state, mission_id, usage);
+        result.unwrap();
+        assert_eq!(usage.unwrap().reveal_count, 3);
+    }
+
+    #[test]
+    fn uncapped_reveals_need_no_counter() {
+        let mission_id = [3u8; 32];
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.commitment_ttl = COMMITMENT_TTL;
+
+        let (result, usage) = reveal(&global_state, mission_id, None);
+        result.unwrap();
+        assert!(usage.is_none(), "nothing is created or counted");
+    }
+
+    /// `account` serialized and cut back to its first `struct_len` bytes
+    /// (discriminator included), zero-padded to `size`: an account written
+    /// by the original deployment, before the layout grew.
+    fn legacy_bytes<T: AccountSerialize>(account: &T, struct_len: usize, size: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        data.truncate(struct_len);
+        data.resize(size, 0);
+        data
+    }
+
+    /// migrate_global_state on a legacy-size GlobalState, signed by
+    /// `authority`.
+    fn migrate_global_state(
+        legacy: &GlobalState,
+        authority: Pubkey,
+    ) -> (Result<()>, &'static AccountInfo<'static>) {
+        install_stubs();
+
+        let info = pin(leak(Slot::at(
+            Pubkey::find_program_address(&[b"global_state"], &crate::ID).0,
+            crate::ID,
+            legacy_bytes(legacy, LEGACY_GLOBAL_STATE_SIZE, LEGACY_GLOBAL_STATE_SIZE),
+        )));
+        let mut accounts = MigrateGlobalState {
+            authority: signer(authority),
+            global_state: UncheckedAccount::try_from(info),
+            skr_mint: skr_mint(),
+            system_program: system_program(),
+        };
+        let result = seek_protocol::migrate_global_state(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            MigrateGlobalStateBumps::default(),
+        ));
+        (result, info)
+    }
+
+    #[test]
+    fn migrated_global_state_gets_initialize_defaults() {
+        let authority = Pubkey::new_unique();
+        let mut legacy: GlobalState = zeroed(GlobalState::SIZE);
+        legacy.authority = authority;
+        legacy.house_fund_balance = 500_000;
+        legacy.total_bounties_created = 40;
+        legacy.bump = 254;
+        legacy.max_mission_reuse = 9; // past the legacy layout; must not survive
+
+        let cpis_before = cpi_calls();
+        let (result, info) = migrate_global_state(&legacy, authority);
+        result.unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 1, "the authority tops up the rent");
+        assert_eq!(info.data_len(), GlobalState::SIZE);
+
+        let migrated = Account::<GlobalState>::try_from(info).unwrap();
+        assert_eq!(migrated.authority, authority);
+        assert_eq!(migrated.house_fund_balance, 500_000);
+        assert_eq!(migrated.total_bounties_created, 40);
+        assert_eq!(migrated.bump, 254);
+        assert_eq!(migrated.max_mission_reuse, 0, "zero defaults stay zeroed");
+        let derive = |seed: &[u8]| Pubkey::find_program_address(&[seed], &crate::ID).1;
+        assert_eq!(migrated.house_vault_bump, derive(b"house_vault"));
+        assert_eq!(migrated.singularity_vault_bump, derive(b"singularity_vault"));
+        assert_eq!(migrated.tier_durations, [TIER_1_DURATION, TIER_2_DURATION, TIER_3_DURATION]);
+        assert!(migrated.accepting_enabled, "a live deployment keeps accepting");
+        assert!(migrated.burn_enabled);
+        assert_eq!(migrated.max_duration, DEFAULT_MAX_DURATION);
+        assert_eq!(migrated.commitment_ttl, COMMITMENT_TTL);
+        assert_eq!(migrated.payout_multiplier_bps, DEFAULT_PAYOUT_MULTIPLIER_BPS);
+        assert_eq!(migrated.epoch_started_at, NOW);
+        assert_eq!(migrated.skr_decimals, SKR_DECIMALS, "read from the mint");
+        assert_eq!(migrated.epoch_opening_totals.bounties_created, 40, "no epoch-zero spike");
+
+        let system = leak(Slot::new(system_program::ID, Vec::new()));
+        assert_eq!(
+            error_code(grow_account(info, GlobalState::DISCRIMINATOR, GlobalState::SIZE, &system, &system)),
+            Some(6000 + SeekError::AlreadyMigrated as u32),
+            "a second migration is refused"
+        );
+    }
+
+    #[test]
+    fn migrate_global_state_checks_the_authority_and_account_type() {
+        let mut legacy: GlobalState = zeroed(GlobalState::SIZE);
+        legacy.authority = Pubkey::new_unique();
+        let (result, _) = migrate_global_state(&legacy, Pubkey::new_unique());
+        assert_eq!(error_code(result), Some(6000 + SeekError::Unauthorized as u32));
+
+        let mut slot = Slot::new(
+            crate::ID,
+            legacy_bytes(&committed_bounty([1; 32]), LEGACY_GLOBAL_STATE_SIZE, LEGACY_GLOBAL_STATE_SIZE),
+        );
+        let mut system_slot = Slot::new(system_program::ID, Vec::new());
+        let (info, system) = (slot.info(), system_slot.info());
+        assert_eq!(
+            error_code(grow_account(&info, GlobalState::DISCRIMINATOR, GlobalState::SIZE, &system, &system)),
+            Some(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch as u32),
+            "only the named account type is grown"
+        );
+    }
+
+    #[test]
+    fn waived_win_finalizes_in_the_same_slot() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        let player = Pubkey::new_unique();
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = player;
+        bounty.global_state = global_state.key();
+        bounty.status = BountyStatus::ChallengeWon;
+        bounty.proposed_win = true;
+        bounty.entry_amount = 1_000;
+        bounty.payout_amount = 2_000;
+        bounty.resolved_at = NOW;
+        bounty.challenge_ends_at = NOW + 3_600;
+        let bounty = leaked(&bounty);
+
+        let mut accounts = finalize_accounts(global_state.clone(), bounty.clone());
+        assert_eq!(
+            error_code(finalize(&mut accounts)),
+            Some(6000 + SeekError::ChallengePeriodActive as u32),
+            "not before the challenge period ends"
+        );
+
+        let mut waive = WaiveChallenge {
+            player: signer(player),
+            global_state: *global_state,
+            bounty: *bounty,
+        };
+        seek_protocol::waive_challenge(Context::new(
+            &crate::ID,
+            &mut waive,
+            &[],
+            WaiveChallengeBumps::default(),
+        ))
+        .unwrap();
+        assert_eq!(waive.bounty.challenge_ends_at, NOW);
+
+        let mut accounts = finalize_accounts(Box::new(waive.global_state), Box::new(waive.bounty));
+        finalize(&mut accounts).unwrap();
+        assert!(accounts.bounty.status == BountyStatus::Won);
+    }
+
+    #[test]
+    fn proposed_losses_cannot_be_waived() {
+        install_stubs();
+
+        let global_state = leaked(&zeroed::<GlobalState>(GlobalState::SIZE));
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.status = BountyStatus::ChallengeLost;
+        bounty.challenge_ends_at = NOW + 3_600;
+        let mut waive = WaiveChallenge {
+            player: signer(bounty.player),
+            global_state: *global_state,
+            bounty: *leaked(&bounty),
+        };
+
+        let result = seek_protocol::waive_challenge(Context::new(
+            &crate::ID,
+            &mut waive,
+            &[],
+            WaiveChallengeBumps::default(),
+        ));
+        assert_eq!(error_code(result), Some(6000 + SeekError::ChallengeNotWaivable as u32));
+        assert_eq!(waive.bounty.challenge_ends_at, NOW + 3_600);
+    }
+
+    #[test]
+    fn singularity_roll_event_carries_the_roll_and_odds_used() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.singularity_balance = 500;
+        let global_state = leaked(&global_state);
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = Pubkey::new_unique();
+        bounty.global_state = global_state.key();
+        bounty.status = BountyStatus::ChallengeWon;
+        bounty.proposed_win = true;
+        bounty.entry_amount = 1_000;
+        bounty.payout_amount = 2_000;
+        bounty.mission_commitment = [9; 32];
+        bounty.challenge_ends_at = NOW;
+        let bounty = leaked(&bounty);
+        let bounty_key = bounty.key();
+
+        let mut accounts = finalize_accounts(global_state, bounty);
+        finalize(&mut accounts).unwrap();
+
+        // Same entropy as the finalize roll: commitment, bounty, slot, time
+        let digest = anchor_lang::solana_program::hash::hashv(&[
+            &[9; 32],
+            bounty_key.as_ref(),
+            &1u64.to_le_bytes(),
+            &(NOW as u64).to_le_bytes(),
+        ]);
+        let roll_value =
+            u64::from_le_bytes(digest.to_bytes()[..8].try_into().unwrap()) % SINGULARITY_ODDS;
+
+        let rolls: Vec<SingularityRoll> = emitted();
+        let roll = rolls.iter().find(|r| r.bounty == bounty_key).unwrap();
+        assert_eq!((roll.roll_value, roll.odds), (roll_value, SINGULARITY_ODDS));
+        assert_eq!(roll.won, roll_value == 0);
+    }
+
+    #[test]
+    fn early_cancel_fee_scales_linearly_with_elapsed_hunt_time() {
+        let duration = TIER_1_DURATION;
+        assert_eq!(cancel_fee_bps(500, 2_500, 0, duration).unwrap(), 500);
+        assert_eq!(cancel_fee_bps(500, 2_500, 18, duration).unwrap(), 700); // 10% of the timer
+        assert_eq!(cancel_fee_bps(500, 2_500, 162, duration).unwrap(), 2_300); // 90%
+        // Clock skew and post-expiry cancels clamp to the ends of the ramp
+        assert_eq!(cancel_fee_bps(500, 2_500, -5, duration).unwrap(), 500);
+        assert_eq!(cancel_fee_bps(500, 2_500, 900, duration).unwrap(), 2_500);
+    }
+
+    #[test]
+    fn late_reveal_counts_only_after_an_on_time_submission() {
+        let mission_id = [5u8; 32];
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.commitment_ttl = COMMITMENT_TTL;
+
+        // The timer ran out a minute before the reveal
+        let mut bounty = committed_bounty(mission_id);
+        bounty.created_at = NOW - 900;
+        bounty.expires_at = NOW - 60;
+        let late = |submitted_at| {
+            let mut bounty = bounty.clone();
+            bounty.submitted_at = submitted_at;
+            let usage = *leaked(&zeroed::<MissionUsage>(MissionUsage::SIZE));
+            error_code(reveal_bounty(&global_state, &bounty, mission_id, Some(usage)).0)
+        };
+
+        assert_eq!(late(0), Some(6000 + SeekError::LateReveal as u32), "never submitted");
+        assert_eq!(
+            late(NOW - 30),
+            Some(6000 + SeekError::LateReveal as u32),
+            "submitted after the timer"
+        );
+        assert_eq!(late(bounty.expires_at - 1), None, "submitted on time, revealed late");
+    }
+
+    #[test]
+    fn loss_shares_leave_no_remainder_on_any_tier() {
+        for entry in [TIER_1_ENTRY, TIER_2_ENTRY, TIER_3_ENTRY] {
+            let shares = compute_loss_shares(entry).unwrap();
+            assert_eq!(shares.remainder, 0);
+            assert_eq!(shares.house_share, entry / 10 * 7);
+        }
+
+        // An off-tier amount rounds every share down; the remainder makes it whole
+        let shares = compute_loss_shares(1_009).unwrap();
+        assert_eq!(
+            (shares.house_share, shares.singularity_share, shares.protocol_share),
+            (706, 201, 100)
+        );
+        assert_eq!(shares.remainder, 2);
+    }
+
+    /// A proposed loss whose dispute window closes `left` seconds after NOW.
+    fn proposed_loss(left: i64) -> Bounty {
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = Pubkey::new_unique();
+        bounty.tier = 1;
+        bounty.status = BountyStatus::ChallengeLost;
+        bounty.entry_amount = 1_000;
+        bounty.resolved_at = NOW - 3_600;
+        bounty.challenge_ends_at = NOW + CLOCK_SKEW_GRACE + left;
+        bounty
+    }
+
+    fn dispute(
+        global_state: Box<Account<'static, GlobalState>>,
+        bounty: Box<Account<'static, Bounty>>,
+    ) -> (Result<()>, DisputeBounty<'static>) {
+        let mut accounts = DisputeBounty {
+            player: signer(bounty.player),
+            global_state,
+            bounty,
+            player_stats: player_stats(),
+            player_token_account: vault(10_000),
+            house_vault: vault(1_000_000),
+            protocol_treasury: vault(0),
+            token_program: token_program(),
+        };
+        let result = seek_protocol::dispute_bounty(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            DisputeBountyBumps::default(),
+        ));
+        (result, accounts)
+    }
+
+    #[test]
+    fn dispute_and_finalize_race_has_one_winner() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+
+        // Dispute lands first, inside the window: finalize then fails
+        let bounty = leaked(&proposed_loss(1));
+        let (result, disputed) = dispute(global_state.clone(), bounty);
+        result.unwrap();
+        assert!(disputed.bounty.status == BountyStatus::Disputed);
+        let mut accounts = finalize_accounts(disputed.global_state, disputed.bounty);
+        assert_eq!(
+            error_code(finalize(&mut accounts)),
+            Some(6000 + SeekError::AlreadyDisputed as u32)
+        );
+
+        // At the deadline the window is closed to disputes and open to
+        // finalize, never both
+        let bounty = leaked(&proposed_loss(0));
+        let (result, _) = dispute(global_state.clone(), bounty.clone());
+        assert_eq!(error_code(result), Some(6000 + SeekError::ChallengePeriodEnded as u32));
+
+        // Finalize lands first: the late dispute sees the settled bounty
+        let mut accounts = finalize_accounts(global_state, bounty);
+        finalize(&mut accounts).unwrap();
+        assert!(accounts.bounty.status == BountyStatus::Lost);
+        let (result, _) = dispute(accounts.global_state, accounts.bounty);
+        assert_eq!(error_code(result), Some(6000 + SeekError::BountyAlreadyFinalized as u32));
+    }
+
+    #[test]
+    fn finalize_waits_out_the_grace_after_the_challenge_ends() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.finalize_grace = 300;
+        let bounty = proposed_loss(-1);
+        assert!(bounty.challenge_ends_at - CLOCK_SKEW_GRACE < NOW, "challenge period over");
+
+        let mut accounts = finalize_accounts(leaked(&global_state), leaked(&bounty));
+        assert_eq!(
+            error_code(finalize(&mut accounts)),
+            Some(6000 + SeekError::ChallengePeriodActive as u32),
+            "still inside the grace"
+        );
+        let (result, _) = dispute(leaked(&global_state), leaked(&bounty));
+        result.expect("disputes stay open through the grace");
+
+        accounts.global_state.finalize_grace = 0;
+        finalize(&mut accounts).unwrap();
+        assert!(accounts.bounty.status == BountyStatus::Lost);
+    }
+
+    #[test]
+    fn pda_info_decodes_to_the_derived_addresses() {
+        let derive = |seed: &[u8]| Pubkey::find_program_address(&[seed], &crate::ID);
+        let (global_state_key, bump) = derive(b"global_state");
+        let (house_vault, house_vault_bump) = derive(b"house_vault");
+        let (singularity_vault, singularity_vault_bump) = derive(b"singularity_vault");
+
+        // As initialize records them
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.bump = bump;
+        global_state.house_vault = house_vault;
+        global_state.house_vault_bump = house_vault_bump;
+        global_state.singularity_vault = singularity_vault;
+        global_state.singularity_vault_bump = singularity_vault_bump;
+        let mut data = Vec::new();
+        global_state.try_serialize(&mut data).unwrap();
+        let infos: &'static [AccountInfo<'static>] =
+            vec![leak(Slot::at(global_state_key, crate::ID, data))].leak();
+
+        // Through the accounts checks, so the seeds constraint holds too
+        let mut bumps = GetPdaInfoBumps::default();
+        let mut accounts = GetPdaInfo::try_accounts(
+            &crate::ID,
+            &mut &infos[..],
+            &[],
+            &mut bumps,
+            &mut std::collections::BTreeSet::new(),
+        )
+        .unwrap();
+        let info =
+            seek_protocol::get_pda_info(Context::new(&crate::ID, &mut accounts, &[], bumps)).unwrap();
+
+        // What a client reads back from the return data
+        let decoded = PdaInfo::try_from_slice(&info.try_to_vec().unwrap()).unwrap();
+        assert_eq!(
+            decoded,
+            PdaInfo {
+                global_state: global_state_key,
+                global_state_bump: bump,
+                house_vault,
+                house_vault_bump,
+                singularity_vault,
+                singularity_vault_bump,
+            }
+        );
+    }
+
+    #[test]
+    fn rapid_accepts_hit_the_global_rate_limit_until_the_window_rolls() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.global_rate_limit = 3;
+        global_state.rate_limit_window = 60;
+
+        for second in 0..3 {
+            global_state.record_accept_for_rate_limit(NOW + second).unwrap();
+        }
+        assert_eq!(
+            error_code(global_state.record_accept_for_rate_limit(NOW + 59)),
+            Some(6000 + SeekError::GlobalRateLimited as u32)
+        );
+        assert_eq!(global_state.rate_window_count, 3, "the rejected accept isn't counted");
+
+        global_state.record_accept_for_rate_limit(NOW + 60).unwrap();
+        assert_eq!((global_state.rate_window_start, global_state.rate_window_count), (NOW + 60, 1));
+
+        // 0 disables the limit
+        global_state.global_rate_limit = 0;
+        for _ in 0..10 {
+            global_state.record_accept_for_rate_limit(NOW + 61).unwrap();
+        }
+    }
+
+    #[test]
+    fn burning_treasury_adds_to_total_burned() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.total_burned = 100;
+        global_state.burn_enabled = true;
+        let owner = Pubkey::new_unique();
+        let mut accounts = BurnTreasury {
+            authority: signer(Pubkey::new_unique()),
+            treasury_owner: signer(owner),
+            global_state: leaked(&global_state),
+            protocol_treasury: Box::new(
+                Account::try_from(pin(token_account(Pubkey::new_unique(), owner, 1_000))).unwrap(),
+            ),
+            skr_mint: skr_mint(),
+            token_program: token_program(),
+        };
+        let cpis_before = cpi_calls();
+
+        seek_protocol::burn_treasury(
+            Context::new(&crate::ID, &mut accounts, &[], BurnTreasuryBumps::default()),
+            400,
+        )
+        .unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 1, "one token::burn");
+        assert_eq!(accounts.global_state.total_burned, 500);
+        let burned: Vec<TreasuryBurned> = emitted();
+        assert_eq!(burned.last().map(|e| (e.amount, e.total_burned)), Some((400, 500)));
+
+        accounts.global_state.burn_enabled = false;
+        let result = seek_protocol::burn_treasury(
+            Context::new(&crate::ID, &mut accounts, &[], BurnTreasuryBumps::default()),
+            400,
+        );
+        assert_eq!(error_code(result), Some(6000 + SeekError::BurnDisabled as u32));
+        assert_eq!(accounts.global_state.total_burned, 500);
+    }
+
+    /// Settle a fresh dispute under `ruling`. Returns the result and the
+    /// bounty's key.
+    fn resolve(global_state: &mut GlobalState, ruling: DisputeRuling) -> (Result<()>, Pubkey) {
+        install_stubs();
+
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = Pubkey::new_unique();
+        bounty.status = BountyStatus::Disputed;
+        bounty.is_disputed = true;
+        bounty.entry_amount = 1_000;
+        bounty.dispute_stake = 500;
+        let mut bounty = leaked(&bounty);
+        let key = bounty.key();
+        let vault = leak(Slot::new(token::ID, Vec::new()));
+
+        let result = settle_dispute(
+            global_state,
+            &mut bounty,
+            ruling,
+            1_000_000,
+            &vaults(&vault),
+            &vault,
+            &mut player_stats(),
+        );
+        (result, key)
+    }
+
+    #[test]
+    fn dispute_wins_roll_the_jackpot_only_when_enabled() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.singularity_balance = 500;
+        let win = DisputeRuling { player_wins: true, reason_code: 0 };
+        let rolled = |key: Pubkey| {
+            emitted::<SingularityRoll>().iter().any(|roll| roll.bounty == key)
+        };
+
+        let (result, key) = resolve(&mut global_state, win);
+        result.unwrap();
+        assert!(!rolled(key), "off by default: a dispute win is a refund");
+
+        global_state.dispute_wins_roll_jackpot = true;
+        let (result, key) = resolve(&mut global_state, win);
+        result.unwrap();
+        assert!(rolled(key));
+    }
+
+    #[test]
+    fn dispute_reason_code_round_trips_into_the_event() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let reason_of = |key: Pubkey| {
+            emitted::<DisputeResolved>()
+                .iter()
+                .find(|e| e.bounty == key)
+                .map(|e| e.reason_code)
+        };
+
+        let rulings = [(true, DisputeReason::TechnicalError), (false, DisputeReason::Fraud)];
+        for (player_wins, reason) in rulings {
+            let ruling = DisputeRuling { player_wins, reason_code: reason as u8 };
+            let (result, key) = resolve(&mut global_state, ruling);
+            result.unwrap();
+            assert_eq!(reason_of(key), Some(reason as u8));
+        }
+
+        let unknown = DisputeRuling { player_wins: true, reason_code: 5 };
+        let (result, key) = resolve(&mut global_state, unknown);
+        assert_eq!(error_code(result), Some(6000 + SeekError::InvalidReasonCode as u32));
+        assert_eq!(reason_of(key), None);
+    }
+
+    /// A proposed win of 2_000 on a 1_000 entry, its challenge period over.
+    fn proposed_win(global_state: &Account<GlobalState>) -> Box<Account<'static, Bounty>> {
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = Pubkey::new_unique();
+        bounty.global_state = global_state.key();
+        bounty.status = BountyStatus::ChallengeWon;
+        bounty.proposed_win = true;
+        bounty.entry_amount = 1_000;
+        bounty.payout_amount = 2_000;
+        bounty.challenge_ends_at = NOW;
+        leaked(&bounty)
+    }
+
+    #[test]
+    fn win_burn_comes_out_of_the_player_payout() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.total_burned = 50;
+        global_state.win_burn_bps = MAX_WIN_BURN_BPS;
+        global_state.burn_enabled = true;
+        let global_state = leaked(&global_state);
+        let bounty = proposed_win(&global_state);
+        let bounty_key = bounty.key();
+        let won = |key: Pubkey| {
+            emitted::<BountyWon>()
+                .into_iter()
+                .find(|e| e.bounty == key)
+                .map(|e| (e.payout, e.burned))
+        };
+
+        let cpis_before = cpi_calls();
+        let mut accounts = finalize_accounts(global_state, bounty);
+        finalize(&mut accounts).unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 2, "token::burn, then the payout transfer");
+        assert_eq!(accounts.global_state.total_burned, 150);
+        assert_eq!(won(bounty_key), Some((1_900, 100)), "5% of 2_000 burned");
+        assert_eq!(accounts.global_state.house_fund_balance, 1_000_000 - 2_000);
+
+        // Off: no burn CPI, full payout
+        accounts.global_state.win_burn_bps = 0;
+        let bounty = proposed_win(&accounts.global_state);
+        let bounty_key = bounty.key();
+        let cpis_before = cpi_calls();
+        let mut accounts = finalize_accounts(accounts.global_state, bounty);
+        finalize(&mut accounts).unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 1);
+        assert_eq!(accounts.global_state.total_burned, 150);
+        assert_eq!(won(bounty_key), Some((2_000, 0)));
+
+        // Burning disabled: the slice is still withheld but stays in the house
+        accounts.global_state.win_burn_bps = MAX_WIN_BURN_BPS;
+        accounts.global_state.burn_enabled = false;
+        let house_before = accounts.global_state.house_fund_balance;
+        let bounty = proposed_win(&accounts.global_state);
+        let bounty_key = bounty.key();
+        let cpis_before = cpi_calls();
+        let mut accounts = finalize_accounts(accounts.global_state, bounty);
+        finalize(&mut accounts).unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 1, "payout transfer only");
+        assert_eq!(accounts.global_state.total_burned, 150);
+        assert_eq!(won(bounty_key), Some((1_900, 0)));
+        assert_eq!(accounts.global_state.house_fund_balance, house_before - 1_900);
+    }
+
+    #[test]
+    fn auto_compounded_protocol_fees_stay_in_the_house() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 50_000;
+        let settle_loss = |global_state: &GlobalState| {
+            let mut accounts = finalize_accounts(leaked(global_state), leaked(&proposed_loss(-1)));
+            let cpis_before = cpi_calls();
+            finalize(&mut accounts).unwrap();
+            (cpi_calls() - cpis_before, accounts.global_state)
+        };
+
+        // The 1_000 entry, already counted in the house, splits 700 house,
+        // 200 singularity, 100 protocol
+        let (cpis, paid_out) = settle_loss(&global_state);
+        assert_eq!(cpis, 2, "singularity and treasury transfers");
+        assert_eq!(paid_out.house_fund_balance, 49_700);
+
+        global_state.auto_compound_protocol = true;
+        let (cpis, compounded) = settle_loss(&global_state);
+        assert_eq!(cpis, 1, "only the singularity share leaves the vault");
+        assert_eq!(compounded.house_fund_balance, 49_800);
+
+        assert_eq!(paid_out.total_protocol_revenue, 100);
+        assert_eq!(compounded.total_protocol_revenue, 100, "counted either way");
+    }
+
+    #[test]
+    fn zero_amounts_are_rejected_before_any_transfer() {
+        install_stubs();
+
+        let global_state = leaked(&zeroed::<GlobalState>(GlobalState::SIZE));
+        let cpis_before = cpi_calls();
+        let funded_before = emitted::<HouseFunded>().len();
+
+        let mut house = FundHouse {
+            authority: signer(Pubkey::new_unique()),
+            global_state: *global_state.clone(),
+            authority_token_account: *vault(1_000),
+            house_vault: *vault(0),
+            token_program: token_program(),
+        };
+        let result = seek_protocol::fund_house(
+            Context::new(&crate::ID, &mut house, &[], FundHouseBumps::default()),
+            0,
+        );
+        assert_eq!(error_code(result), Some(6000 + SeekError::InvalidAmount as u32));
+
+        let owner = Pubkey::new_unique();
+        let mut burn = BurnTreasury {
+            authority: signer(Pubkey::new_unique()),
+            treasury_owner: signer(owner),
+            global_state,
+            protocol_treasury: Box::new(
+                Account::try_from(pin(token_account(Pubkey::new_unique(), owner, 1_000))).unwrap(),
+            ),
+            skr_mint: skr_mint(),
+            token_program: token_program(),
+        };
+        let result = seek_protocol::burn_treasury(
+            Context::new(&crate::ID, &mut burn, &[], BurnTreasuryBumps::default()),
+            0,
+        );
+        assert_eq!(error_code(result), Some(6000 + SeekError::InvalidAmount as u32));
+
+        assert_eq!(cpi_calls(), cpis_before, "no transfer or burn issued");
+        assert_eq!(emitted::<HouseFunded>().len(), funded_before, "no HouseFunded event");
+    }
+
+    #[test]
+    fn tier_3_daily_cap_blocks_tier_3_while_tier_1_stays_open() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.tier_daily_caps = [0, 0, 2];
+
+        global_state.record_accept_for_tier_cap(3, NOW).unwrap();
+        global_state.record_accept_for_tier_cap(3, NOW + 1).unwrap();
+        assert_eq!(
+            error_code(global_state.record_accept_for_tier_cap(3, NOW + 2)),
+            Some(6000 + SeekError::TierDailyCapReached as u32),
+            "third tier-3 accept hits the cap"
+        );
+        global_state.record_accept_for_tier_cap(1, NOW + 3).unwrap();
+        assert_eq!(global_state.tier_daily_counts, [1, 0, 2], "uncapped tiers still count");
+
+        // A day after the window opened, every tier starts over
+        global_state.record_accept_for_tier_cap(3, NOW + TIER_CAP_WINDOW).unwrap();
+        assert_eq!(global_state.tier_daily_counts, [0, 0, 1]);
+        assert_eq!(global_state.tier_day_start, NOW + TIER_CAP_WINDOW);
+    }
+
+    #[test]
+    fn a_prefunded_win_stays_payable_after_other_wins_drain_the_house() {
+        install_stubs();
+
+        // 6_000 reserved for one prefunded win, 1_500 free
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 7_500;
+        global_state.outstanding_liability = 6_000;
+        let global_state = leaked(&global_state);
+
+        // An ordinary 2_000 win would eat into the reservation
+        let mut accounts = finalize_accounts(global_state.clone(), proposed_win(&global_state));
+        accounts.house_vault = vault(7_500);
+        assert_eq!(
+            error_code(finalize(&mut accounts)),
+            Some(6000 + SeekError::InsufficientHouseFunds as u32)
+        );
+
+        // The prefunded win releases its own reservation and pays in full
+        let mut bounty = proposed_win(&global_state);
+        bounty.payout_amount = 6_000;
+        bounty.prefunded = true;
+        let mut accounts = finalize_accounts(global_state, bounty);
+        accounts.house_vault = vault(7_500);
+        finalize(&mut accounts).unwrap();
+        assert!(accounts.bounty.status == BountyStatus::Won);
+        assert!(!accounts.bounty.prefunded);
+        assert_eq!(accounts.global_state.outstanding_liability, 0);
+    }
+
+    fn can_accept_tier(global_state: &GlobalState, tier: u8) -> TierAvailability {
+        install_stubs();
+
+        let mut accounts = CanAcceptTier {
+            global_state: *leaked(global_state),
+        };
+        seek_protocol::can_accept_tier(
+            Context::new(&crate::ID, &mut accounts, &[], CanAcceptTierBumps::default()),
+            tier,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn can_accept_tier_reports_solvent_vs_over_exposed_houses() {
+        let reason = |global_state: &GlobalState, tier| {
+            let availability = can_accept_tier(global_state, tier);
+            assert_eq!(availability.can_accept, availability.reason_code == 0);
+            availability.reason_code
+        };
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 50_000 * DECIMALS_MULTIPLIER;
+        global_state.accepting_enabled = true;
+        global_state.payout_multiplier_bps = DEFAULT_PAYOUT_MULTIPLIER_BPS;
+        global_state.skr_decimals = SKR_DECIMALS;
+        assert_eq!(reason(&global_state, 3), AcceptBlockReason::None as u8, "solvent house");
+        assert_eq!(reason(&global_state, 4), AcceptBlockReason::InvalidTier as u8);
+
+        // House + entry must cover the reservations plus the new 3x payout
+        global_state.outstanding_liability = 45_000 * DECIMALS_MULTIPLIER;
+        assert_eq!(
+            reason(&global_state, 3),
+            AcceptBlockReason::InsufficientHouseFunds as u8,
+            "over-exposed house"
+        );
+        assert_eq!(reason(&global_state, 1), AcceptBlockReason::None as u8);
+
+        // Caps block ahead of solvency
+        global_state.tier_daily_caps = [1, 0, 0];
+        global_state.tier_daily_counts = [1, 0, 0];
+        global_state.tier_day_start = NOW - 60;
+        assert_eq!(reason(&global_state, 1), AcceptBlockReason::TierDailyCapReached as u8);
+        global_state.global_rate_limit = 1;
+        global_state.rate_limit_window = 60;
+        global_state.rate_window_start = NOW - 30;
+        global_state.rate_window_count = 1;
+        assert_eq!(reason(&global_state, 1), AcceptBlockReason::GlobalRateLimited as u8);
+    }
+
+    /// migrate_bounty on `legacy` cut back to the original Bounty layout
+    /// (199 bytes of fields in a 216-byte account), under `global_state`.
+    fn migrate_bounty(
+        legacy: &Bounty,
+        global_state: Box<Account<'static, GlobalState>>,
+    ) -> (Result<()>, &'static AccountInfo<'static>) {
+        install_stubs();
+
+        let info = pin(leak(Slot::new(
+            crate::ID,
+            legacy_bytes(legacy, 8 + 199, LEGACY_BOUNTY_SIZE),
+        )));
+        let mut accounts = MigrateBounty {
+            payer: signer(Pubkey::new_unique()),
+            global_state,
+            bounty: UncheckedAccount::try_from(info),
+            system_program: system_program(),
+        };
+        let result = seek_protocol::migrate_bounty(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            MigrateBountyBumps::default(),
+        ));
+        (result, info)
+    }
+
+    #[test]
+    fn migrated_bounty_keeps_its_fields_and_zeroes_the_new_ones() {
+        let global_state = leaked(&zeroed::<GlobalState>(GlobalState::SIZE));
+        let player = Pubkey::new_unique();
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = player;
+        bounty.global_state = global_state.key();
+        bounty.entry_amount = 2_000;
+        bounty.payout_amount = 6_000;
+        bounty.status = BountyStatus::ChallengeLost;
+        bounty.tier = 2;
+        bounty.mission_revealed = true;
+        // Set past the legacy layout; must not survive
+        bounty.submitted_at = NOW;
+        bounty.prefunded = true;
+        bounty.dispute_disabled = true;
+        bounty.photo_hash = [7u8; 32];
+
+        let cpis_before = cpi_calls();
+        let (result, info) = migrate_bounty(&bounty, global_state);
+        result.unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 1, "payer tops up the rent");
+        assert_eq!(info.data_len(), Bounty::SIZE);
+
+        let migrated = Account::<Bounty>::try_from(info).unwrap();
+        assert_eq!(migrated.player, player);
+        assert_eq!(migrated.payout_amount, 6_000);
+        assert!(migrated.status == BountyStatus::ChallengeLost && migrated.mission_revealed);
+        assert_eq!(migrated.submitted_at, 0);
+        assert!(!migrated.prefunded);
+        assert!(!migrated.dispute_disabled, "a legacy loss stays disputable");
+        assert_eq!(migrated.photo_hash, [0u8; 32]);
+
+        let (result, _) = migrate_bounty(&bounty, leaked(&zeroed(GlobalState::SIZE)));
+        assert_eq!(
+            error_code(result),
+            Some(6000 + SeekError::GlobalStateMismatch as u32),
+            "only bounties of this deployment"
+        );
+    }
+
+    #[test]
+    fn oracle_attested_losses_cannot_be_disputed() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        let (result, _) = dispute(global_state.clone(), leaked(&proposed_loss(60)));
+        result.expect("a proposed loss is disputable by default");
+
+        let mut attested = proposed_loss(60);
+        attested.dispute_disabled = true;
+        let (result, accounts) = dispute(global_state, leaked(&attested));
+        assert_eq!(error_code(result), Some(6000 + SeekError::DisputeNotAllowed as u32));
+        assert!(accounts.bounty.status == BountyStatus::ChallengeLost);
+    }
+
+    #[test]
+    fn tier_duration_setter_rejects_timers_below_the_floor() {
+        let authority = Pubkey::new_unique();
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.authority = authority;
+        let mut accounts = UpdateConfig {
+            authority: signer(authority),
+            global_state: leaked(&global_state),
+        };
+        let mut set = |tier, duration| {
+            seek_protocol::set_tier_duration(
+                Context::new(&crate::ID, &mut accounts, &[], UpdateConfigBumps::default()),
+                tier,
+                duration,
+            )
+        };
+
+        let too_short = Some(6000 + SeekError::TierDurationTooShort as u32);
+        assert_eq!(error_code(set(1, 1)), too_short, "a 1-second timer is abusive");
+        assert_eq!(error_code(set(3, MIN_TIER_DURATION - 1)), too_short);
+        set(3, MIN_TIER_DURATION).unwrap();
+        set(2, 600).unwrap();
+        assert_eq!(error_code(set(4, 600)), Some(6000 + SeekError::InvalidConfig as u32));
+        assert_eq!(accounts.global_state.tier_durations, [0, 600, MIN_TIER_DURATION]);
+    }
+
+    fn player_at_risk(
+        global_state: Box<Account<'static, GlobalState>>,
+        bounty: Box<Account<'static, Bounty>>,
+    ) -> PlayerAtRisk {
+        let mut accounts = GetPlayerAtRisk {
+            global_state: *global_state,
+            bounty: *bounty,
+        };
+        seek_protocol::get_player_at_risk(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            GetPlayerAtRiskBumps::default(),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn player_at_risk_counts_the_dispute_stake_once_disputed() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        let bounty = leaked(&proposed_loss(60));
+        assert_eq!(
+            player_at_risk(global_state.clone(), bounty.clone()),
+            PlayerAtRisk { bet: 1_000, dispute_stake: 0, potential_refund: 0 }
+        );
+
+        let (result, disputed) = dispute(global_state, bounty);
+        result.unwrap();
+        assert_eq!(
+            player_at_risk(disputed.global_state.clone(), disputed.bounty.clone()),
+            PlayerAtRisk { bet: 1_000, dispute_stake: 500, potential_refund: 1_500 }
+        );
+
+        // Quoted as settle_dispute pays it: a 10% bonus all to the sole
+        // disputer, half of it once a co-disputer stakes as much
+        let mut global_state = disputed.global_state;
+        global_state.dispute_win_bonus_bps = 1_000;
+        let mut bounty = disputed.bounty;
+        assert_eq!(player_at_risk(global_state.clone(), bounty.clone()).potential_refund, 1_600);
+        bounty.co_dispute_stake = 500;
+        assert_eq!(player_at_risk(global_state, bounty).potential_refund, 1_550);
+    }
+
+    /// distribute_jackpot_on_shutdown with 40 in the singularity vault.
+    fn distribute_on_shutdown(global_state: &GlobalState) -> (Result<()>, GlobalState) {
+        install_stubs();
+
+        let mut accounts = DistributeJackpotOnShutdown {
+            authority: signer(global_state.authority),
+            global_state: leaked(global_state),
+            house_vault: vault(100),
+            singularity_vault: vault(40),
+            protocol_treasury: vault(7),
+            token_program: token_program(),
+        };
+        let result = seek_protocol::distribute_jackpot_on_shutdown(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            DistributeJackpotOnShutdownBumps::default(),
+        ));
+        (result, (**accounts.global_state).clone())
+    }
+
+    #[test]
+    fn shutdown_jackpot_policy_decides_where_the_pool_goes() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 100;
+        global_state.singularity_balance = 40;
+        let (result, _) = distribute_on_shutdown(&global_state);
+        assert_eq!(error_code(result), Some(6000 + SeekError::ProtocolNotShutdown as u32));
+
+        global_state.shutdown = true;
+
+        global_state.shutdown_jackpot_policy = ShutdownJackpotPolicy::ReturnToHouse as u8;
+        let (result, returned) = distribute_on_shutdown(&global_state);
+        result.unwrap();
+        assert_eq!((returned.house_fund_balance, returned.singularity_balance), (140, 0));
+
+        global_state.shutdown_jackpot_policy = ShutdownJackpotPolicy::SendToTreasury as u8;
+        let (result, sent) = distribute_on_shutdown(&global_state);
+        result.unwrap();
+        assert_eq!((sent.house_fund_balance, sent.singularity_balance), (100, 0));
+        assert!(emitted::<JackpotDistributedOnShutdown>()
+            .iter()
+            .any(|e| e.policy == 1 && e.amount == 40));
+
+        global_state.shutdown_jackpot_policy = 2;
+        let (result, _) = distribute_on_shutdown(&global_state);
+        assert_eq!(error_code(result), Some(6000 + SeekError::InvalidConfig as u32));
+    }
+
+    #[test]
+    fn u128_intermediates_keep_large_bet_times_multiplier_math_exact() {
+        let big_bet = u64::MAX / 4;
+        assert!(big_bet.checked_mul(10).is_none(), "the raw product overflows u64");
+        assert_eq!(
+            mul_div(big_bet, 10, 8).unwrap(),
+            (u128::from(big_bet) * 10 / 8) as u64,
+            "10x then /8 fits in u64"
+        );
+        assert_eq!(
+            bps_of(big_bet, HOUSE_SHARE_BPS).unwrap(),
+            (u128::from(big_bet) * 7_000 / 10_000) as u64
+        );
+        let shares = compute_loss_shares(big_bet).unwrap();
+        assert_eq!(
+            shares.house_share + shares.singularity_share + shares.protocol_share + shares.remainder,
+            big_bet
+        );
+
+        let overflow = Some(6000 + SeekError::MathOverflow as u32);
+        assert_eq!(error_code(mul_div(big_bet, 10, 1)), overflow, "a result past u64 is an error");
+        assert_eq!(error_code(compute_payout(u64::MAX / 2, DEFAULT_PAYOUT_MULTIPLIER_BPS)), overflow);
+        assert_eq!(error_code(mul_div(1, 1, 0)), overflow);
+    }
+
+    fn reclaim_during_pause(
+        global_state: &GlobalState,
+        bounty: &Bounty,
+    ) -> (Result<()>, CancelBounty<'static>) {
+        install_stubs();
+
+        let mut accounts = CancelBounty {
+            player: signer(bounty.player),
+            global_state: leaked(global_state),
+            bounty: leaked(bounty),
+            player_stats: player_stats(),
+            player_token_account: vault(0),
+            house_vault: vault(1_000_000),
+            token_program: token_program(),
+        };
+        let result = seek_protocol::reclaim_during_pause(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            CancelBountyBumps::default(),
+        ));
+        (result, accounts)
+    }
+
+    #[test]
+    fn reclaim_opens_once_the_pause_outlasts_the_timeout() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let mut bounty = proposed_loss(60);
+        bounty.status = BountyStatus::Disputed;
+        bounty.is_disputed = true;
+        bounty.dispute_stake = 500;
+
+        let (result, _) = reclaim_during_pause(&global_state, &bounty);
+        assert_eq!(error_code(result), Some(6000 + SeekError::ProtocolNotPaused as u32));
+
+        global_state.paused = true;
+        global_state.paused_at = NOW - 60;
+        let (result, _) = reclaim_during_pause(&global_state, &bounty);
+        assert_eq!(error_code(result), Some(6000 + SeekError::PauseRefundNotReady as u32));
+
+        global_state.paused_at = NOW - PAUSE_REFUND_TIMEOUT;
+        let cpis_before = cpi_calls();
+        let (result, reclaimed) = reclaim_during_pause(&global_state, &bounty);
+        result.unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 1);
+        assert!(reclaimed.bounty.status == BountyStatus::Cancelled);
+        assert_eq!(reclaimed.global_state.house_fund_balance, 1_000_000 - 1_500, "entry + stake");
+        let refunds: Vec<BountyReclaimed> = emitted();
+        assert!(refunds.iter().any(|e| e.bounty == reclaimed.bounty.key() && e.refund_amount == 1_500));
+
+        let (result, _) = reclaim_during_pause(&global_state, &reclaimed.bounty);
+        assert_eq!(error_code(result), Some(6000 + SeekError::BountyAlreadyFinalized as u32));
+    }
+
+    /// Accounts for a dispute on a bounty whose player recorded `recorded`
+    /// through submit_photo.
+    fn dispute_accounts(global_state: &GlobalState, recorded: [u8; 32]) -> ResolveDispute<'static> {
+        install_stubs();
+
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = Pubkey::new_unique();
+        bounty.status = BountyStatus::Disputed;
+        bounty.is_disputed = true;
+        bounty.entry_amount = 1_000;
+        bounty.dispute_stake = 500;
+        bounty.photo_hash = recorded;
+
+        ResolveDispute {
+            authority: signer(global_state.authority),
+            global_state: leaked(global_state),
+            bounty: leaked(&bounty),
+            player_stats: player_stats(),
+            player_token_account: vault(0),
+            house_vault: vault(1_000_000),
+            singularity_vault: vault(0),
+            protocol_treasury: vault(0),
+            charity_vault: None,
+            token_program: token_program(),
+        }
+    }
+
+    fn resolve_with_evidence(
+        global_state: &GlobalState,
+        recorded: [u8; 32],
+        attested: [u8; 32],
+    ) -> Result<()> {
+        let mut accounts = dispute_accounts(global_state, recorded);
+        seek_protocol::resolve_dispute_with_evidence(
+            Context::new(&crate::ID, &mut accounts, &[], ResolveDisputeBumps::default()),
+            true,
+            DisputeReason::TechnicalError as u8,
+            attested,
+        )
+    }
+
+    fn resolve_without_evidence(global_state: &GlobalState, recorded: [u8; 32]) -> Result<()> {
+        let mut accounts = dispute_accounts(global_state, recorded);
+        seek_protocol::resolve_dispute(
+            Context::new(&crate::ID, &mut accounts, &[], ResolveDisputeBumps::default()),
+            true,
+            DisputeReason::TechnicalError as u8,
+        )
+    }
+
+    #[test]
+    fn evidence_resolution_requires_the_recorded_photo_hash() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let photo = [7u8; 32];
+        let mismatch = Some(6000 + SeekError::PhotoHashMismatch as u32);
+
+        resolve_with_evidence(&global_state, photo, photo).unwrap();
+        resolve_without_evidence(&global_state, photo).unwrap();
+        assert_eq!(error_code(resolve_with_evidence(&global_state, photo, [8u8; 32])), mismatch);
+        assert_eq!(
+            error_code(resolve_with_evidence(&global_state, [0u8; 32], [0u8; 32])),
+            mismatch,
+            "nothing recorded, nothing to attest"
+        );
+
+        global_state.require_dispute_evidence = true;
+        resolve_with_evidence(&global_state, photo, photo).unwrap();
+        assert_eq!(
+            error_code(resolve_without_evidence(&global_state, photo)),
+            Some(6000 + SeekError::EvidenceRequired as u32)
+        );
+    }
+
+    #[test]
+    fn disputes_the_house_could_not_refund_are_rejected() {
+        install_stubs();
+
+        let house = |balance: u64| {
+            let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+            global_state.house_fund_balance = balance;
+            leaked(&global_state)
+        };
+
+        // 1_000 entry, 500 stake: a winning dispute refunds 1_500
+        assert_eq!(dispute_win_refund(1_000, 500, 0).unwrap(), 1_500);
+        let (result, _) = dispute(house(999), leaked(&proposed_loss(60)));
+        assert_eq!(error_code(result), Some(6000 + SeekError::InsufficientHouseFunds as u32));
+
+        let (result, disputed) = dispute(house(1_000), leaked(&proposed_loss(60)));
+        result.unwrap();
+        assert_eq!(disputed.global_state.house_fund_balance, 1_500, "stake counted in");
+    }
+
+    #[test]
+    fn dispute_win_refund_adds_only_the_configured_bonus() {
+        let entry = 2_000 * DECIMALS_MULTIPLIER;
+        let stake = bps_of(entry, DISPUTE_STAKE_BPS).unwrap();
+        assert_eq!(dispute_win_refund(entry, stake, 0).unwrap(), 3_000 * DECIMALS_MULTIPLIER);
+        assert_eq!(dispute_win_refund(entry, stake, 2_500).unwrap(), 3_500 * DECIMALS_MULTIPLIER);
+        assert_eq!(
+            dispute_win_refund(entry, stake, MAX_DISPUTE_WIN_BONUS_BPS).unwrap(),
+            5_000 * DECIMALS_MULTIPLIER,
+            "max bonus = one extra entry"
+        );
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.dispute_win_bonus_bps = 2_500;
+        let win = DisputeRuling { player_wins: true, reason_code: 0 };
+        let (result, _) = resolve(&mut global_state, win);
+        result.unwrap();
+        assert_eq!(global_state.house_fund_balance, 1_000_000 - 1_750, "entry + stake + 25%");
+    }
+
+    #[test]
+    fn dispute_counters_track_filed_then_resolved_disputes() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let (result, disputed) = dispute(leaked(&global_state), leaked(&proposed_loss(60)));
+        result.unwrap();
+        let mut global_state = (**disputed.global_state).clone();
+        assert_eq!(global_state.total_disputes_filed, 1);
+
+        let win = DisputeRuling { player_wins: true, reason_code: 0 };
+        let loss = DisputeRuling { player_wins: false, reason_code: 0 };
+        resolve(&mut global_state, win).0.unwrap();
+        resolve(&mut global_state, loss).0.unwrap();
+
+        let mut accounts = GetPdaInfo { global_state: *leaked(&global_state) };
+        let stats = seek_protocol::get_protocol_stats(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            GetPdaInfoBumps::default(),
+        ))
+        .unwrap();
+        assert_eq!(stats.total_disputes_filed, 1);
+        assert_eq!(stats.total_disputes_won_by_player, 1);
+        assert_eq!(stats.total_dispute_stake_forfeited, 500);
+        assert_eq!(stats.house_fund_balance, global_state.house_fund_balance);
+    }
+
+    fn fund_house(global_state: Box<Account<'static, GlobalState>>, amount: u64) -> FundHouse<'static> {
+        install_stubs();
+
+        let mut house = FundHouse {
+            authority: signer(Pubkey::new_unique()),
+            global_state: *global_state,
+            authority_token_account: *vault(amount),
+            house_vault: *vault(0),
+            token_program: token_program(),
+        };
+        seek_protocol::fund_house(
+            Context::new(&crate::ID, &mut house, &[], FundHouseBumps::default()),
+            amount,
+        )
+        .unwrap();
+        house
+    }
+
+    #[test]
+    fn accepting_opens_once_funding_reaches_the_launch_balance() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.min_launch_balance = 100_000;
+        global_state.house_fund_balance = 50_000 * DECIMALS_MULTIPLIER;
+        assert_eq!(
+            can_accept_tier(&global_state, 1).reason_code,
+            AcceptBlockReason::AcceptingDisabled as u8,
+            "a fresh deployment is closed"
+        );
+        global_state.house_fund_balance = 0;
+
+        let funded = fund_house(leaked(&global_state), 60_000);
+        assert!(!funded.global_state.accepting_enabled, "below the threshold");
+        let funded = fund_house(Box::new(funded.global_state), 40_000);
+        assert!(funded.global_state.accepting_enabled);
+
+        // Latched: draining the house later doesn't close accepting again
+        let mut global_state = (*funded.global_state).clone();
+        global_state.house_fund_balance = 0;
+        global_state.min_launch_balance = u64::MAX;
+        assert!(fund_house(leaked(&global_state), 1).global_state.accepting_enabled);
+    }
+
+    #[test]
+    fn multi_field_commitment_reveals_only_in_the_defined_order() {
+        install_stubs();
+
+        let (target, band, window) = ([1u8; 32], [2u8; 32], [3u8; 32]);
+        let mut bounty = committed_bounty(target);
+        bounty.mission_commitment =
+            anchor_lang::solana_program::hash::hashv(&[&target, &band, &window, &SALT]).to_bytes();
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.commitment_ttl = COMMITMENT_TTL;
+
+        let reveal_fields = |mission_id: [u8; 32], other_fields: [[u8; 32]; 2]| {
+            let mut accounts = RevealMissionFields {
+                hot_authority: signer(global_state.hot_authority),
+                global_state: *leaked(&global_state),
+                bounty: *leaked(&bounty),
+                mission_usage: Some(leaked(&zeroed::<MissionUsage>(MissionUsage::SIZE))),
+                blocked_mission: blocked_mission(None),
+                system_program: system_program(),
+            };
+            let bumps = RevealMissionFieldsBumps {
+                mission_usage: Some(254),
+                ..Default::default()
+            };
+            let result = seek_protocol::reveal_mission_fields(
+                Context::new(&crate::ID, &mut accounts, &[], bumps),
+                mission_id,
+                other_fields,
+                SALT,
+                None,
+            );
+            (result, accounts)
+        };
+
+        let (result, _) = reveal_fields(band, [target, window]);
+        assert_eq!(error_code(result), Some(6000 + SeekError::InvalidMissionHash as u32));
+
+        let (result, revealed) = reveal_fields(target, [band, window]);
+        result.unwrap();
+        assert_eq!(revealed.bounty.mission_id, target, "field 0 is the mission_id");
+        let usage = revealed.mission_usage.unwrap();
+        assert_eq!((usage.mission_id, usage.reveal_count), (target, 1));
+    }
+
+    /// A revealed, submitted tier-`tier` hunt: 1_000 entry, 3_000 payout.
+    fn submitted_bounty(global_state: &Account<GlobalState>, tier: u8) -> Bounty {
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = Pubkey::new_unique();
+        bounty.global_state = global_state.key();
+        bounty.tier = tier;
+        bounty.status = BountyStatus::Submitted;
+        bounty.mission_revealed = true;
+        bounty.entry_amount = 1_000;
+        bounty.payout_amount = 3_000;
+        bounty
+    }
+
+    fn propose(
+        global_state: Box<Account<'static, GlobalState>>,
+        bounty: &Bounty,
+        success: bool,
+    ) -> (Result<()>, ProposeResolution<'static>) {
+        propose_attested(global_state, bounty, success, None)
+    }
+
+    /// propose_resolution, co-signed by `loss_oracle` when given.
+    fn propose_attested(
+        global_state: Box<Account<'static, GlobalState>>,
+        bounty: &Bounty,
+        success: bool,
+        loss_oracle: Option<Pubkey>,
+    ) -> (Result<()>, ProposeResolution<'static>) {
+        install_stubs();
+
+        let mut accounts = ProposeResolution {
+            hot_authority: signer(global_state.hot_authority),
+            global_state,
+            bounty: leaked(bounty),
+            player_stats: player_stats(),
+            loyalty_escrow: None,
+            loss_oracle: loss_oracle.map(signer),
+            player_token_account: Some(vault(0)),
+            house_vault: vault(1_000_000),
+            singularity_vault: vault(0),
+            protocol_treasury: vault(0),
+            charity_vault: None,
+            skr_mint: skr_mint(),
+            token_program: token_program(),
+        };
+        let result = seek_protocol::propose_resolution(
+            Context::new(&crate::ID, &mut accounts, &[], ProposeResolutionBumps::default()),
+            success,
+        );
+        (result, accounts)
+    }
+
+    #[test]
+    fn fast_resolve_tiers_settle_in_the_proposing_call() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.fast_resolve_tiers = 0b001;
+        let global_state = leaked(&global_state);
+
+        let cpis_before = cpi_calls();
+        let (result, won) = propose(global_state.clone(), &submitted_bounty(&global_state, 1), true);
+        result.unwrap();
+        assert!(won.bounty.status == BountyStatus::Won, "tier 1 wins pay at once");
+        assert_eq!(cpi_calls(), cpis_before + 1, "the payout transfer");
+        assert_eq!(won.global_state.house_fund_balance, 1_000_000 - 3_000);
+
+        let (result, challenged) = propose(global_state.clone(), &submitted_bounty(&global_state, 2), true);
+        result.unwrap();
+        assert!(challenged.bounty.status == BountyStatus::ChallengeWon, "tier 2 isn't in the mask");
+        assert_eq!(challenged.bounty.challenge_ends_at, NOW + CHALLENGE_PERIOD);
+        assert_eq!(challenged.global_state.house_fund_balance, 1_000_000);
+
+        let mut global_state = (**global_state).clone();
+        global_state.fast_resolve_tiers = 0;
+        let global_state = leaked(&global_state);
+        let (result, lost) = propose(global_state.clone(), &submitted_bounty(&global_state, 1), false);
+        result.unwrap();
+        assert!(lost.bounty.status == BountyStatus::ChallengeLost, "an empty mask settles nothing");
+    }
+
+    /// Run FinalizeBounty's account checks over `accounts`, with
+    /// global_state and player_stats re-homed at their PDAs so the seeds
+    /// constraints hold.
+    fn check_finalize_accounts(accounts: &FinalizeBounty<'static>) -> Result<()> {
+        let (key, bump) = Pubkey::find_program_address(&[b"global_state"], &crate::ID);
+        let mut global_state = (**accounts.global_state).clone();
+        global_state.bump = bump;
+        let mut data = Vec::new();
+        global_state.try_serialize(&mut data).unwrap();
+
+        let (stats_key, stats_bump) = Pubkey::find_program_address(
+            &[b"player_stats", accounts.bounty.player.as_ref()],
+            &crate::ID,
+        );
+        let mut player_stats = (**accounts.player_stats).clone();
+        player_stats.bump = stats_bump;
+        let mut stats_data = Vec::new();
+        player_stats.try_serialize(&mut stats_data).unwrap();
+
+        // An absent optional account is passed as the program id
+        let absent = || leak(Slot::at(crate::ID, Pubkey::default(), Vec::new()));
+        let infos: &'static [AccountInfo<'static>] = vec![
+            accounts.caller.to_account_info(),
+            leak(Slot::at(key, crate::ID, data)),
+            accounts.bounty.to_account_info(),
+            leak(Slot::at(stats_key, crate::ID, stats_data)),
+            accounts.loyalty_escrow.as_ref().map_or_else(absent, |e| e.to_account_info()),
+            accounts.player_token_account.as_ref().map_or_else(absent, |p| p.to_account_info()),
+            accounts.house_vault.to_account_info(),
+            accounts.singularity_vault.to_account_info(),
+            accounts.protocol_treasury.to_account_info(),
+            accounts.charity_vault.as_ref().map_or_else(absent, |c| c.to_account_info()),
+            accounts.skr_mint.to_account_info(),
+            accounts.token_program.to_account_info(),
+        ]
+        .leak();
+        FinalizeBounty::try_accounts(
+            &crate::ID,
+            &mut &infos[..],
+            &[],
+            &mut FinalizeBountyBumps::default(),
+            &mut std::collections::BTreeSet::new(),
+        )
+        .map(drop)
+    }
+
+    #[test]
+    fn finalize_rejects_a_bounty_of_another_global_state() {
+        let (key, _) = Pubkey::find_program_address(&[b"global_state"], &crate::ID);
+        let global_state = leaked(&zeroed::<GlobalState>(GlobalState::SIZE));
+
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.global_state = Pubkey::new_unique();
+        let accounts = finalize_accounts(global_state, leaked(&bounty));
+        assert_eq!(
+            error_code(check_finalize_accounts(&accounts)),
+            Some(6000 + SeekError::GlobalStateMismatch as u32)
+        );
+
+        // Recorded against the canonical state, the bounty check passes and
+        // the next constraint, the player's token account, is what fails
+        bounty.global_state = key;
+        let accounts = finalize_accounts(accounts.global_state, leaked(&bounty));
+        assert_eq!(
+            error_code(check_finalize_accounts(&accounts)),
+            Some(6000 + SeekError::Unauthorized as u32)
+        );
+    }
+
+    /// A GlobalState open for accepts, as initialize leaves it once the
+    /// house is funded.
+    fn open_global_state() -> GlobalState {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000 * DECIMALS_MULTIPLIER;
+        global_state.accepting_enabled = true;
+        global_state.tier_durations = [TIER_1_DURATION, TIER_2_DURATION, TIER_3_DURATION];
+        global_state.max_duration = DEFAULT_MAX_DURATION;
+        global_state.payout_multiplier_bps = DEFAULT_PAYOUT_MULTIPLIER_BPS;
+        global_state.skr_decimals = SKR_DECIMALS;
+        global_state
+    }
+
+    /// The SlotHashes sysvar holding one entry: slot 1 with `hash`.
+    fn slot_hashes(hash: [u8; 32]) -> UncheckedAccount<'static> {
+        let mut data = 1u64.to_le_bytes().to_vec();
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.extend_from_slice(&hash);
+        let info = leak(Slot::at(
+            anchor_lang::solana_program::sysvar::slot_hashes::ID,
+            anchor_lang::solana_program::sysvar::ID,
+            data,
+        ));
+        UncheckedAccount::try_from(pin(info))
+    }
+
+    fn accept(
+        global_state: &GlobalState,
+        entry_amount: u64,
+    ) -> (Result<AcceptReceipt>, AcceptBounty<'static>) {
+        accept_drawing(global_state, entry_amount, None)
+    }
+
+    /// accept_bounty with mission commitment `[1u8; 32]`, drawn from
+    /// `commitment_pool` when one is passed.
+    fn accept_drawing(
+        global_state: &GlobalState,
+        entry_amount: u64,
+        commitment_pool: Option<Box<Account<'static, CommitmentPool>>>,
+    ) -> (Result<AcceptReceipt>, AcceptBounty<'static>) {
+        install_stubs();
+
+        let player = Pubkey::new_unique();
+        let mut accounts = AcceptBounty {
+            player: signer(player),
+            rent_payer: signer(player),
+            global_state: leaked(global_state),
+            bounty: leaked(&zeroed::<Bounty>(Bounty::SIZE)),
+            player_stats: player_stats(),
+            loyalty_escrow: loyalty_escrow(),
+            player_token_account: vault(entry_amount),
+            house_vault: vault(0),
+            skr_mint: skr_mint(),
+            slot_hashes: slot_hashes([7u8; 32]),
+            system_program: system_program(),
+            token_program: token_program(),
+            commitment_pool,
+        };
+        let result = seek_protocol::accept_bounty(
+            Context::new(&crate::ID, &mut accounts, &[], AcceptBountyBumps::default()),
+            entry_amount,
+            NOW,
+            [1u8; 32],
+            [0u8; 32],
+        );
+        (result, accounts)
+    }
+
+    #[test]
+    fn accept_rejects_hunts_longer_than_max_duration() {
+        let mut global_state = open_global_state();
+        for entry in [TIER_1_ENTRY, TIER_2_ENTRY, TIER_3_ENTRY] {
+            let (result, accepted) = accept(&global_state, entry);
+            result.unwrap();
+            assert!(accepted.bounty.expires_at - accepted.bounty.created_at <= DEFAULT_MAX_DURATION);
+        }
+
+        global_state.tier_durations[0] = DEFAULT_MAX_DURATION;
+        accept(&global_state, TIER_1_ENTRY).0.expect("the boundary is inclusive");
+
+        global_state.tier_durations[0] = 30 * 86_400;
+        assert_eq!(
+            error_code(accept(&global_state, TIER_1_ENTRY).0),
+            Some(6000 + SeekError::DurationTooLong as u32)
+        );
+    }
+
+    fn finalize_my_wins(
+        global_state: Box<Account<'static, GlobalState>>,
+        player: Pubkey,
+        bounties: &'static [AccountInfo<'static>],
+    ) -> (Result<()>, FinalizeMyWins<'static>) {
+        install_stubs();
+
+        let mut accounts = FinalizeMyWins {
+            player: signer(player),
+            global_state,
+            player_stats: player_stats(),
+            loyalty_escrow: None,
+            player_token_account: vault(0),
+            house_vault: vault(1_000_000),
+            singularity_vault: vault(0),
+            protocol_treasury: vault(0),
+            charity_vault: None,
+            skr_mint: skr_mint(),
+            token_program: token_program(),
+        };
+        let result = seek_protocol::finalize_my_wins(Context::new(
+            &crate::ID,
+            &mut accounts,
+            bounties,
+            FinalizeMyWinsBumps::default(),
+        ));
+        (result, accounts)
+    }
+
+    #[test]
+    fn finalize_my_wins_settles_only_the_players_finalizable_wins() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        let player = Pubkey::new_unique();
+        let bounty = |status: BountyStatus, payout_amount: u64| {
+            let mut bounty: Bounty = zeroed(Bounty::SIZE);
+            bounty.player = player;
+            bounty.global_state = global_state.key();
+            bounty.status = status;
+            bounty.proposed_win = status == BountyStatus::ChallengeWon;
+            bounty.entry_amount = 1_000;
+            bounty.payout_amount = payout_amount;
+            bounty.challenge_ends_at = NOW - 10;
+            leak(Slot::of(&bounty))
+        };
+        let bounties: &'static [AccountInfo<'static>] = vec![
+            bounty(BountyStatus::ChallengeWon, 2_000),
+            bounty(BountyStatus::ChallengeLost, 0),
+            bounty(BountyStatus::ChallengeWon, 3_000),
+        ]
+        .leak();
+        let claimed = |player: Pubkey| {
+            emitted::<WinsClaimed>()
+                .into_iter()
+                .rev()
+                .find(|e| e.player == player)
+                .map(|e| (e.bounties_finalized, e.total_payout))
+        };
+
+        let (result, accounts) = finalize_my_wins(global_state, player, bounties);
+        result.unwrap();
+        assert_eq!(claimed(player), Some((2, 5_000)));
+        let status = |i: usize| Account::<Bounty>::try_from(&bounties[i]).unwrap().status;
+        assert!(status(0) == BountyStatus::Won && status(2) == BountyStatus::Won);
+        assert!(status(1) == BountyStatus::ChallengeLost, "losses are left for the keeper");
+
+        let (result, accounts) = finalize_my_wins(accounts.global_state, player, bounties);
+        result.unwrap();
+        assert_eq!(claimed(player), Some((0, 0)), "no double pay");
+
+        let stranger = Pubkey::new_unique();
+        let (result, _) = finalize_my_wins(accounts.global_state, stranger, bounties);
+        assert_eq!(error_code(result), Some(6000 + SeekError::Unauthorized as u32));
+    }
+
+    #[test]
+    fn dispute_fee_goes_to_the_treasury_and_is_not_refunded() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.dispute_fee = 25;
+        let fee_of = |key: Pubkey| {
+            emitted::<BountyDisputed>()
+                .into_iter()
+                .find(|e| e.bounty == key)
+                .map(|e| (e.dispute_stake, e.dispute_fee))
+        };
+
+        let cpis_before = cpi_calls();
+        let (result, disputed) = dispute(leaked(&global_state), leaked(&proposed_loss(60)));
+        result.unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 2, "fee to the treasury, stake to the house");
+        assert_eq!(fee_of(disputed.bounty.key()), Some((500, 25)));
+        assert_eq!(disputed.global_state.house_fund_balance, 1_000_500, "only the stake");
+
+        // A won dispute refunds entry + stake; the fee stays with the treasury
+        let mut global_state = (**disputed.global_state).clone();
+        let win = DisputeRuling { player_wins: true, reason_code: 0 };
+        resolve(&mut global_state, win).0.unwrap();
+        assert_eq!(global_state.house_fund_balance, 1_000_500 - 1_500);
+
+        global_state.dispute_fee = 0;
+        let cpis_before = cpi_calls();
+        let (result, disputed) = dispute(leaked(&global_state), leaked(&proposed_loss(60)));
+        result.unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 1, "no fee transfer when unset");
+        assert_eq!(fee_of(disputed.bounty.key()), Some((500, 0)));
+    }
+
+    #[test]
+    fn a_proposed_result_cannot_be_proposed_again() {
+        let global_state = leaked(&zeroed::<GlobalState>(GlobalState::SIZE));
+        let (result, proposed) = propose(global_state.clone(), &submitted_bounty(&global_state, 1), true);
+        result.unwrap();
+        assert_eq!(proposed.bounty.resolved_at, NOW);
+
+        let already = Some(6000 + SeekError::ResolutionAlreadyProposed as u32);
+        let (result, _) = propose(global_state.clone(), &proposed.bounty, false);
+        assert_eq!(error_code(result), already);
+
+        // Even with the status rolled back, the stamp locks the win in
+        let mut reset = (**proposed.bounty).clone();
+        reset.status = BountyStatus::Submitted;
+        let (result, again) = propose(global_state, &reset, false);
+        assert_eq!(error_code(result), already);
+        assert!(again.bounty.proposed_win, "the win stands");
+    }
+
+    fn advance_epoch(global_state: &GlobalState) -> (Result<()>, AdvanceEpoch<'static>) {
+        install_stubs();
+
+        let mut accounts = AdvanceEpoch {
+            caller: signer(Pubkey::new_unique()),
+            global_state: *leaked(global_state),
+        };
+        let result = seek_protocol::advance_epoch(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            AdvanceEpochBumps::default(),
+        ));
+        (result, accounts)
+    }
+
+    #[test]
+    fn epoch_loss_breaker_trips_on_settled_wins_and_resets_next_epoch() {
+        install_stubs();
+
+        let mut global_state = open_global_state();
+        global_state.max_epoch_house_loss = 2_500;
+        global_state.epoch_started_at = NOW - 60;
+        let settle = |global_state: Box<Account<'static, GlobalState>>, bounty| {
+            let mut accounts = finalize_accounts(global_state, bounty);
+            finalize(&mut accounts).unwrap();
+            accounts.global_state
+        };
+
+        // Each 2_000 win on a 1_000 entry costs the house 1_000
+        let global_state = leaked(&global_state);
+        let global_state = settle(global_state.clone(), proposed_win(&global_state));
+        let global_state = settle(global_state.clone(), proposed_win(&global_state));
+        assert_eq!(global_state.house_net_this_epoch, -2_000);
+        assert!(!global_state.epoch_loss_cap_reached());
+
+        // A loss the house keeps 700 of, then a third win: 2_300 lost, still open
+        let global_state = settle(global_state, leaked(&proposed_loss(-1)));
+        let global_state = settle(global_state.clone(), proposed_win(&global_state));
+        assert_eq!(global_state.house_net_this_epoch, -2_300);
+        accept(&global_state, TIER_1_ENTRY).0.unwrap();
+
+        let global_state = settle(global_state.clone(), proposed_win(&global_state));
+        assert!(global_state.epoch_loss_cap_reached(), "3_300 lost");
+        assert!(emitted::<EpochLossCapReached>().iter().any(|e| e.house_net_this_epoch == -3_300));
+        assert_eq!(
+            error_code(accept(&global_state, TIER_1_ENTRY).0),
+            Some(6000 + SeekError::EpochLossCapReached as u32)
+        );
+
+        let (result, _) = advance_epoch(&global_state);
+        assert_eq!(error_code(result), Some(6000 + SeekError::EpochNotOver as u32));
+
+        let mut global_state = (*global_state).clone();
+        global_state.epoch_started_at = NOW - EPOCH_DURATION;
+        let (result, advanced) = advance_epoch(&global_state);
+        result.unwrap();
+        assert_eq!(advanced.global_state.epoch, 1);
+        assert_eq!(advanced.global_state.epoch_started_at, NOW);
+        assert_eq!(advanced.global_state.house_net_this_epoch, 0);
+        accept(&advanced.global_state, TIER_1_ENTRY).0.expect("accepts reopen");
+    }
+
+    /// A mission commitment that makes `bounty_key`'s jackpot roll come up
+    /// 0 at the stub clock (slot 1, NOW).
+    fn winning_commitment(bounty_key: Pubkey) -> [u8; 32] {
+        (0u32..)
+            .map(|i| anchor_lang::solana_program::hash::hash(&i.to_le_bytes()).to_bytes())
+            .find(|commitment| {
+                let digest = anchor_lang::solana_program::hash::hashv(&[
+                    commitment,
+                    bounty_key.as_ref(),
+                    &1u64.to_le_bytes(),
+                    &(NOW as u64).to_le_bytes(),
+                ]);
+                u64::from_le_bytes(digest.to_bytes()[..8].try_into().unwrap()) % SINGULARITY_ODDS == 0
+            })
+            .unwrap()
+    }
+
+    /// Roll the jackpot for a bounty whose roll wins, with `charity_vault`
+    /// passed (or not).
+    fn roll_winning_jackpot(global_state: &mut GlobalState, charity_vault: bool) -> Result<u64> {
+        install_stubs();
+
+        let bounty_key = Pubkey::new_unique();
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.mission_commitment = winning_commitment(bounty_key);
+        let vault = leak(Slot::new(token::ID, Vec::new()));
+        let mut vaults = vaults(&vault);
+        vaults.charity_vault = charity_vault.then(|| vault.clone());
+        let clock = Clock { slot: 1, unix_timestamp: NOW, ..Clock::default() };
+
+        roll_singularity(
+            global_state,
+            &mut bounty,
+            bounty_key,
+            &vaults,
+            &vault,
+            &clock,
+            SINGULARITY_ODDS,
+        )
+    }
+
+    #[test]
+    fn charity_share_comes_off_the_top_of_a_won_jackpot() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.singularity_balance = 123_456 * DECIMALS_MULTIPLIER;
+        global_state.charity_vault = Pubkey::new_unique();
+        global_state.charity_jackpot_bps = 1_000;
+
+        let cpis_before = cpi_calls();
+        let player_amount = roll_winning_jackpot(&mut global_state.clone(), true).unwrap();
+        assert_eq!(cpi_calls(), cpis_before + 2, "charity, then the player");
+        let roll = emitted::<SingularityRoll>().into_iter().last().unwrap();
+        assert!(roll.won);
+        assert_eq!(roll.charity_amount, 12_345_600_000);
+        assert_eq!(player_amount + roll.charity_amount, 123_456 * DECIMALS_MULTIPLIER);
+
+        assert_eq!(
+            error_code(roll_winning_jackpot(&mut global_state.clone(), false)),
+            Some(6000 + SeekError::CharityVaultMissing as u32)
+        );
+
+        global_state.charity_jackpot_bps = 0;
+        let player_amount = roll_winning_jackpot(&mut global_state, false).unwrap();
+        assert_eq!(player_amount, 123_456 * DECIMALS_MULTIPLIER, "all to the player");
+        assert_eq!(global_state.singularity_balance, 0);
+    }
+
+    #[test]
+    fn a_loss_finalizes_without_the_player_token_account() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+
+        let mut accounts = finalize_accounts(global_state.clone(), leaked(&proposed_loss(-1)));
+        accounts.player_token_account = None;
+        finalize(&mut accounts).unwrap();
+        assert!(accounts.bounty.status == BountyStatus::Lost);
+
+        let mut accounts = finalize_accounts(global_state.clone(), proposed_win(&global_state));
+        accounts.player_token_account = None;
+        assert_eq!(
+            error_code(finalize(&mut accounts)),
+            Some(6000 + SeekError::PlayerTokenAccountRequired as u32)
+        );
+    }
+
+    #[test]
+    fn keeper_sweep_routes_a_mixed_batch() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        let bounty = |status: BountyStatus, expires_at: i64, challenge_ends_at: i64| {
+            let mut bounty: Bounty = zeroed(Bounty::SIZE);
+            bounty.player = Pubkey::new_unique();
+            bounty.global_state = global_state.key();
+            bounty.status = status;
+            bounty.is_disputed = status == BountyStatus::Disputed;
+            bounty.proposed_win = status == BountyStatus::ChallengeWon;
+            bounty.entry_amount = 1_000;
+            bounty.payout_amount = 2_000;
+            bounty.expires_at = expires_at;
+            bounty.challenge_ends_at = challenge_ends_at;
+            bounty
+        };
+        let batch = [
+            bounty(BountyStatus::ChallengeWon, NOW - 5_000, NOW - 10),
+            bounty(BountyStatus::ChallengeLost, NOW - 5_000, NOW - 10),
+            bounty(BountyStatus::Pending, NOW - EXPIRED_REFUND_GRACE - 1, 0),
+            bounty(BountyStatus::Pending, NOW - 10, 0),
+            bounty(BountyStatus::ChallengeLost, NOW - 5_000, NOW + CLOCK_SKEW_GRACE + 1),
+            bounty(BountyStatus::Disputed, NOW - 5_000, NOW - 10),
+            bounty(BountyStatus::Won, NOW - 5_000, NOW - 10),
+        ];
+        let mut items = Vec::new();
+        for bounty in &batch {
+            items.push(leak(Slot::of(bounty)));
+            items.push(leak(Slot::at(
+                get_associated_token_address(&bounty.player, &SKR_MINT),
+                token::ID,
+                Vec::new(),
+            )));
+            let stats = PlayerStats { player: bounty.player, ..zeroed(PlayerStats::SIZE) };
+            items.push(leak(Slot::of(&stats)));
+        }
+        let items: &'static [AccountInfo<'static>] = items.leak();
+
+        let mut accounts = KeeperSweep {
+            caller: signer(Pubkey::new_unique()),
+            global_state,
+            house_vault: vault(1_000_000),
+            singularity_vault: vault(0),
+            protocol_treasury: vault(0),
+            charity_vault: None,
+            skr_mint: skr_mint(),
+            token_program: token_program(),
+        };
+        seek_protocol::keeper_sweep(Context::new(
+            &crate::ID,
+            &mut accounts,
+            items,
+            KeeperSweepBumps::default(),
+        ))
+        .unwrap();
+
+        let statuses: Vec<BountyStatus> = items
+            .chunks(3)
+            .map(|item| Account::<Bounty>::try_from(&item[0]).unwrap().status)
+            .collect();
+        assert!(
+            statuses
+                == [
+                    BountyStatus::Won,
+                    BountyStatus::Lost,
+                    BountyStatus::Cancelled,
+                    BountyStatus::Pending,
+                    BountyStatus::ChallengeLost,
+                    BountyStatus::Disputed,
+                    BountyStatus::Won,
+                ]
+        );
+    }
+
+    #[test]
+    fn accept_rejects_payouts_below_min_payout() {
+        let mut global_state = open_global_state();
+        global_state.min_payout = compute_payout(TIER_1_ENTRY, DEFAULT_PAYOUT_MULTIPLIER_BPS).unwrap() + 1;
+        assert_eq!(
+            error_code(accept(&global_state, TIER_1_ENTRY).0),
+            Some(6000 + SeekError::PayoutBelowMinimum as u32)
+        );
+        accept(&global_state, TIER_2_ENTRY).0.unwrap();
+
+        global_state.min_payout = compute_payout(TIER_1_ENTRY, DEFAULT_PAYOUT_MULTIPLIER_BPS).unwrap();
+        accept(&global_state, TIER_1_ENTRY).0.expect("the boundary is inclusive");
+    }
+
+    #[test]
+    fn blocked_missions_refuse_reveals_and_refund_through_reclaim() {
+        install_stubs();
+
+        let mission_id = [5u8; 32];
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let mut bounty = committed_bounty(mission_id);
+        bounty.player = Pubkey::new_unique();
+        bounty.entry_amount = 2_000;
+
+        let mut reveal = RevealMission {
+            hot_authority: signer(global_state.hot_authority),
+            global_state: *leaked(&global_state),
+            bounty: *leaked(&bounty),
+            mission_usage: None,
+            blocked_mission: blocked_mission(Some(mission_id)),
+            system_program: system_program(),
+        };
+        let result = seek_protocol::reveal_mission(
+            Context::new(&crate::ID, &mut reveal, &[], RevealMissionBumps::default()),
+            mission_id,
+            SALT,
+            None,
+        );
+        assert_eq!(error_code(result), Some(6000 + SeekError::MissionBlocked as u32));
+
+        let reclaim = |salt: [u8; 32]| {
+            let mut accounts = ReclaimUnrevealed {
+                caller: signer(Pubkey::new_unique()),
+                global_state: leaked(&global_state),
+                bounty: leaked(&bounty),
+                player_stats: player_stats(),
+                blocked_mission: leaked(&BlockedMission { mission_id, blocked_at: NOW, bump: 0 }),
+                player_token_account: vault(0),
+                house_vault: vault(1_000_000),
+                token_program: token_program(),
+            };
+            let result = seek_protocol::reclaim_unrevealed(
+                Context::new(&crate::ID, &mut accounts, &[], ReclaimUnrevealedBumps::default()),
+                mission_id,
+                salt,
+            );
+            (result, accounts)
+        };
+
+        let (result, _) = reclaim([1u8; 32]);
+        assert_eq!(error_code(result), Some(6000 + SeekError::InvalidMissionHash as u32));
+
+        let (result, reclaimed) = reclaim(SALT);
+        result.unwrap();
+        assert!(reclaimed.bounty.status == BountyStatus::Cancelled);
+        assert_eq!(reclaimed.global_state.house_fund_balance, 1_000_000 - 2_000, "full entry back");
+        let refunds: Vec<BountyReclaimed> = emitted();
+        assert!(refunds.iter().any(|e| e.bounty == reclaimed.bounty.key() && e.refund_amount == 2_000));
+    }
+
+    #[test]
+    fn claim_required_wins_pay_only_when_the_player_finalizes() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.player_claim_required = true;
+        let global_state = leaked(&global_state);
+
+        let mut pushed = finalize_accounts(global_state.clone(), proposed_win(&global_state));
+        assert_eq!(
+            error_code(finalize(&mut pushed)),
+            Some(6000 + SeekError::PlayerClaimRequired as u32),
+            "a keeper can't push the win"
+        );
+
+        let mut claimed = finalize_accounts(global_state.clone(), proposed_win(&global_state));
+        claimed.caller = signer(claimed.bounty.player);
+        finalize(&mut claimed).unwrap();
+        assert!(claimed.bounty.status == BountyStatus::Won);
+
+        let mut loss = finalize_accounts(global_state.clone(), leaked(&proposed_loss(-1)));
+        finalize(&mut loss).unwrap();
+        assert!(loss.bounty.status == BountyStatus::Lost, "losses stay permissionless");
+    }
+
+    #[test]
+    fn practice_hunts_run_the_lifecycle_without_moving_tokens() {
+        install_stubs();
+
+        let player = Pubkey::new_unique();
+        let mut accepted = AcceptPracticeBounty {
+            player: signer(player),
+            rent_payer: signer(player),
+            global_state: leaked(&open_global_state()),
+            bounty: leaked(&zeroed::<Bounty>(Bounty::SIZE)),
+            player_stats: player_stats(),
+            system_program: system_program(),
+        };
+        seek_protocol::accept_practice_bounty(
+            Context::new(&crate::ID, &mut accepted, &[], AcceptPracticeBountyBumps::default()),
+            1,
+            NOW,
+            [1u8; 32],
+        )
+        .unwrap();
+        assert!(accepted.bounty.practice && accepted.bounty.dispute_disabled);
+        assert_eq!((accepted.bounty.entry_amount, accepted.bounty.payout_amount), (0, 0));
+        let stats = &accepted.player_stats;
+        assert_eq!(stats.player, player, "stats exist for settlement to load");
+        assert_eq!((stats.total_wagered, stats.wins, stats.losses), (0, 0, 0));
+
+        let calls = cpi_calls();
+        let mut bounty: Bounty = (**accepted.bounty).clone();
+        bounty.status = BountyStatus::Submitted;
+        bounty.mission_revealed = true;
+        let (result, proposed) = propose(accepted.global_state.clone(), &bounty, true);
+        result.unwrap();
+        assert!(proposed.bounty.status == BountyStatus::ChallengeWon);
+
+        let mut bounty: Bounty = (**proposed.bounty).clone();
+        bounty.challenge_ends_at = NOW;
+        let mut finalized = finalize_accounts(proposed.global_state.clone(), leaked(&bounty));
+        finalize(&mut finalized).unwrap();
+        assert!(finalized.bounty.status == BountyStatus::Won);
+        assert_eq!(cpi_calls(), calls, "no transfer or burn");
+        assert_eq!(
+            finalized.global_state.house_fund_balance,
+            accepted.global_state.house_fund_balance
+        );
+        let finalized_event: Vec<BountyFinalized> = emitted();
+        assert!(finalized_event.iter().any(|e| e.bounty == finalized.bounty.key() && e.practice));
+    }
+
+    #[test]
+    fn reveals_are_refused_once_the_commitment_ttl_has_passed() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.commitment_ttl = COMMITMENT_TTL;
+        let mission_id = [8u8; 32];
+        let usage = || *leaked(&zeroed::<MissionUsage>(MissionUsage::SIZE));
+
+        let mut bounty = committed_bounty(mission_id);
+        bounty.created_at = NOW - COMMITMENT_TTL;
+        let (result, _) = reveal_bounty(&global_state, &bounty, mission_id, Some(usage()));
+        result.expect("the boundary itself is still valid");
+
+        bounty.created_at = NOW - COMMITMENT_TTL - 1;
+        let (result, _) = reveal_bounty(&global_state, &bounty, mission_id, Some(usage()));
+        assert_eq!(error_code(result), Some(6000 + SeekError::CommitmentExpired as u32));
+    }
+
+    #[test]
+    fn accept_returns_the_bounty_receipt() {
+        let (result, accepted) = accept(&open_global_state(), TIER_2_ENTRY);
+        let receipt = result.unwrap();
+        assert_eq!(receipt.bounty, accepted.bounty.key());
+        assert_eq!(receipt.expires_at, accepted.bounty.expires_at);
+        assert_eq!(receipt.tier, 2);
+        assert_eq!(receipt.payout_amount, accepted.bounty.payout_amount);
+    }
+
+    #[test]
+    fn protocol_share_splits_by_weight_with_the_dust_to_the_last_destination() {
+        install_stubs();
+
+        let destinations: Vec<AccountInfo<'static>> =
+            (0..3).map(|_| vault(0).to_account_info()).collect();
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        for (i, destination) in destinations.iter().enumerate() {
+            global_state.protocol_destinations[i] = destination.key();
+        }
+        global_state.protocol_weights[..3].copy_from_slice(&[3333, 3333, 3334]);
+
+        let house = vault(1_000_000).to_account_info();
+        let (taken, rest) = take_protocol_destinations(&global_state, &destinations).unwrap();
+        assert!(rest.is_empty());
+        let mut accounts = vaults(&house);
+        accounts.protocol_destinations = taken;
+
+        let before = transfers().len();
+        accounts.pay_protocol_share(&global_state, 101).unwrap();
+        let paid: Vec<u64> = transfers()[before..].iter().map(|(_, amount)| *amount).collect();
+        assert_eq!(paid, [33, 33, 35]);
+        let keys: Vec<Pubkey> = transfers()[before..].iter().map(|(key, _)| *key).collect();
+        assert_eq!(keys, global_state.protocol_destinations[..3]);
+
+        let reordered = [destinations[1].clone(), destinations[0].clone(), destinations[2].clone()];
+        assert_eq!(
+            error_code(take_protocol_destinations(&global_state, &reordered)),
+            Some(6000 + SeekError::ProtocolDestinationsMismatch as u32)
+        );
+        assert_eq!(
+            error_code(take_protocol_destinations(&global_state, &destinations[..2])),
+            Some(6000 + SeekError::ProtocolDestinationsMismatch as u32)
+        );
+
+        // No split configured: the whole share goes to protocol_treasury
+        let before = transfers().len();
+        vaults(&house).pay_protocol_share(&zeroed(GlobalState::SIZE), 101).unwrap();
+        assert_eq!(transfers()[before..], [(house.key(), 101)]);
+    }
+
+    #[test]
+    fn protocol_split_weights_must_sum_to_the_whole_share() {
+        let authority = Pubkey::new_unique();
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.authority = authority;
+        let mut accounts = UpdateConfig {
+            authority: signer(authority),
+            global_state: leaked(&global_state),
+        };
+        let split = |accounts: &mut UpdateConfig<'static>, weights: Vec<u16>| {
+            let destinations = weights.iter().map(|_| Pubkey::new_unique()).collect();
+            seek_protocol::set_protocol_split(
+                Context::new(&crate::ID, accounts, &[], UpdateConfigBumps::default()),
+                destinations,
+                weights,
+            )
+        };
+
+        let invalid = Some(6000 + SeekError::InvalidConfig as u32);
+        assert_eq!(error_code(split(&mut accounts, vec![5000, 3000, 1000])), invalid);
+        assert_eq!(error_code(split(&mut accounts, vec![2000; 5])), invalid, "at most four destinations");
+        assert_eq!(error_code(split(&mut accounts, vec![10_000, 0])), invalid);
+        split(&mut accounts, vec![5000, 3000, 2000]).unwrap();
+        assert_eq!(accounts.global_state.protocol_destination_count(), 3);
+        split(&mut accounts, Vec::new()).expect("empty restores the single treasury");
+        assert_eq!(accounts.global_state.protocol_destination_count(), 0);
+    }
+
+    #[test]
+    fn mark_timeout_settles_only_expired_unsubmitted_hunts_as_losses() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        let timeout = |expires_at: i64, submitted_at: i64| {
+            let mut bounty: Bounty = zeroed(Bounty::SIZE);
+            bounty.player = Pubkey::new_unique();
+            bounty.global_state = global_state.key();
+            bounty.tier = 1;
+            bounty.status = BountyStatus::Pending;
+            bounty.entry_amount = 1_000;
+            bounty.expires_at = expires_at;
+            bounty.submitted_at = submitted_at;
+            let mut accounts = MarkTimeout {
+                hot_authority: signer(global_state.hot_authority),
+                global_state: global_state.clone(),
+                bounty: leaked(&bounty),
+                player_stats: player_stats(),
+                house_vault: vault(1_000_000),
+                singularity_vault: vault(0),
+                protocol_treasury: vault(0),
+                skr_mint: skr_mint(),
+                token_program: token_program(),
+            };
+            let result = seek_protocol::mark_timeout(Context::new(
+                &crate::ID,
+                &mut accounts,
+                &[],
+                MarkTimeoutBumps::default(),
+            ));
+            (result, accounts)
+        };
+        let code = |e: SeekError| Some(6000 + e as u32);
+
+        assert_eq!(error_code(timeout(NOW, 0).0), code(SeekError::BountyNotExpired));
+        assert_eq!(error_code(timeout(NOW - 60, NOW - 90).0), code(SeekError::SubmittedInTime));
+        assert_eq!(
+            error_code(timeout(NOW - EXPIRED_REFUND_GRACE - 1, 0).0),
+            code(SeekError::TimeoutWindowClosed),
+            "past the grace the player's refund takes precedence"
+        );
+
+        let before = transfers().len();
+        let (result, timed_out) = timeout(NOW - 60, 0);
+        result.unwrap();
+        assert!(timed_out.bounty.status == BountyStatus::Lost && timed_out.bounty.timeout);
+        assert_eq!(
+            transfers()[before..],
+            [(timed_out.singularity_vault.key(), 200), (timed_out.protocol_treasury.key(), 100)],
+            "distributed as a 70/20/10 loss"
+        );
+    }
+
+    #[test]
+    fn a_lost_dispute_refunds_the_configured_share_of_the_stake() {
+        let loss = DisputeRuling { player_wins: false, reason_code: 0 };
+        let refunded = |global_state: &mut GlobalState| {
+            let (result, key) = resolve(global_state, loss);
+            result.unwrap();
+            emitted::<DisputeResolved>().into_iter().find(|e| e.bounty == key).unwrap().stake_refund
+        };
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        assert_eq!(refunded(&mut global_state), 0, "the whole stake is forfeited by default");
+        let forfeited_in_full = global_state.house_fund_balance;
+
+        global_state.dispute_loss_refund_bps = 5_000;
+        let before = transfers().len();
+        assert_eq!(refunded(&mut global_state), 250);
+        assert!(transfers()[before..].iter().any(|(_, amount)| *amount == 250));
+        let settled = |after: u64, before: u64| i128::from(after) - i128::from(before);
+        assert_eq!(
+            settled(global_state.house_fund_balance, forfeited_in_full),
+            settled(forfeited_in_full, 1_000_000) - 250,
+            "the house keeps only the unrefunded half"
+        );
+    }
+
+    #[test]
+    fn player_stats_track_wagers_and_settlements_into_the_pnl() {
+        let (result, accepted) = accept(&open_global_state(), TIER_1_ENTRY);
+        result.unwrap();
+        assert_eq!(accepted.player_stats.player, accepted.player.key());
+        assert_eq!(accepted.player_stats.total_wagered, TIER_1_ENTRY);
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        let mut won = finalize_accounts(global_state.clone(), proposed_win(&global_state));
+        finalize(&mut won).unwrap();
+        assert_eq!((won.player_stats.wins, won.player_stats.total_won), (1, 2_000));
+        let mut lost = finalize_accounts(global_state, leaked(&proposed_loss(-1)));
+        lost.player_stats = won.player_stats.clone();
+        finalize(&mut lost).unwrap();
+        assert_eq!((lost.player_stats.losses, lost.player_stats.total_won), (1, 2_000));
+
+        let pnl = |stats: &PlayerStats| {
+            let mut accounts = GetPlayerPnl { player_stats: *leaked(stats) };
+            seek_protocol::get_player_pnl(Context::new(
+                &crate::ID,
+                &mut accounts,
+                &[],
+                GetPlayerPnlBumps::default(),
+            ))
+            .unwrap()
+        };
+        let mut stats: PlayerStats = zeroed(PlayerStats::SIZE);
+        assert_eq!(pnl(&stats).win_rate_bps, 0, "nothing settled yet");
+
+        // A 300 win and two losses on 100 entries, plus a refunded hunt
+        for won in [true, false, false] {
+            stats.record_wager(100).unwrap();
+            stats.record_result(won, if won { 300 } else { 0 }).unwrap();
+        }
+        stats.record_wager(100).unwrap();
+        stats.record_refund(100);
+        assert_eq!(
+            pnl(&stats),
+            PlayerPnl {
+                total_wagered: 300,
+                total_won: 300,
+                net_pnl: 0,
+                net_negative: false,
+                win_rate_bps: 3_333,
+            }
+        );
+
+        stats.record_wager(100).unwrap();
+        stats.record_result(false, 0).unwrap();
+        let down = pnl(&stats);
+        assert_eq!((down.net_pnl, down.net_negative, down.win_rate_bps), (100, true, 2_500));
+    }
+
+    #[test]
+    fn accept_order_keys_break_same_slot_ties_reproducibly() {
+        let sysvar = slot_hashes([7u8; 32]);
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (slot, key_a) = accept_order_key(&sysvar, &a).unwrap();
+        let (_, key_b) = accept_order_key(&sysvar, &b).unwrap();
+        assert_eq!(slot, 1);
+        assert_ne!(key_a, key_b, "same-slot accepts get distinct keys");
+        assert_eq!(accept_order_key(&sysvar, &a).unwrap().1, key_a, "anyone can recompute it");
+        let digest = anchor_lang::solana_program::hash::hashv(&[&[7u8; 32], a.as_ref()]);
+        assert_eq!(key_a.to_le_bytes(), digest.to_bytes()[..8]);
+        assert_ne!(accept_order_key(&slot_hashes([8u8; 32]), &a).unwrap().1, key_a);
+
+        let mut global_state = open_global_state();
+        let (result, accepted) = accept(&global_state, TIER_1_ENTRY);
+        result.unwrap();
+        assert_eq!((accepted.bounty.accept_slot, accepted.bounty.accept_order_key), (0, 0));
+
+        global_state.accept_ordering = true;
+        let (result, accepted) = accept(&global_state, TIER_1_ENTRY);
+        result.unwrap();
+        assert_eq!(
+            (accepted.bounty.accept_slot, accepted.bounty.accept_order_key),
+            accept_order_key(&sysvar, &accepted.bounty.key()).unwrap()
+        );
+    }
+
+    #[test]
+    fn geofences_open_only_against_their_commitment() {
+        install_stubs();
+
+        // Lower Manhattan, 250 m
+        let geofence = Geofence {
+            lat_e6: 40_712_776,
+            lon_e6: -74_005_974,
+            radius_m: 250,
+            salt: SALT,
+        };
+        let commitment = anchor_lang::solana_program::hash::hashv(&[
+            &geofence.lat_e6.to_le_bytes(),
+            &geofence.lon_e6.to_le_bytes(),
+            &geofence.radius_m.to_le_bytes(),
+            &geofence.salt,
+        ])
+        .to_bytes();
+        let mission_id = [4u8; 32];
+        let mut bounty = committed_bounty(mission_id);
+        bounty.geo_commitment = commitment;
+        let verify = |bounty: &Bounty, geofence: Option<Geofence>| {
+            let mut bounty = leaked(bounty);
+            verify_geofence(&mut bounty, geofence).map(|()| bounty)
+        };
+        let code = |e: SeekError| Some(6000 + e as u32);
+
+        let revealed = verify(&bounty, Some(geofence)).unwrap();
+        assert!(revealed.geo_revealed);
+        assert_eq!(
+            (revealed.geo_lat_e6, revealed.geo_lon_e6, revealed.geo_radius_m),
+            (40_712_776, -74_005_974, 250)
+        );
+
+        let moved = Geofence { lat_e6: 40_712_777, ..geofence };
+        let widened = Geofence { radius_m: 251, ..geofence };
+        let resalted = Geofence { salt: [1u8; 32], ..geofence };
+        for wrong in [moved, widened, resalted] {
+            assert_eq!(error_code(verify(&bounty, Some(wrong))), code(SeekError::InvalidGeofenceHash));
+        }
+
+        // The mission reveal must carry the geofence
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.commitment_ttl = COMMITMENT_TTL;
+        let usage = *leaked(&zeroed::<MissionUsage>(MissionUsage::SIZE));
+        let (result, _) = reveal_bounty(&global_state, &bounty, mission_id, Some(usage));
+        assert_eq!(error_code(result), code(SeekError::GeofenceRequired));
+
+        bounty.geo_commitment = [0u8; 32];
+        assert_eq!(error_code(verify(&bounty, Some(geofence))), code(SeekError::GeofenceNotCommitted));
+        assert!(!verify(&bounty, None).unwrap().geo_revealed, "no commitment, nothing to open");
+    }
+
+    #[test]
+    fn jackpots_stop_rolling_once_the_epoch_cap_is_reached() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.max_jackpot_per_epoch = 10_000;
+        let roll = |global_state: &mut GlobalState, pool: u64| {
+            global_state.singularity_balance = pool;
+            roll_winning_jackpot(global_state, false).unwrap()
+        };
+
+        assert_eq!(roll(&mut global_state, 8_000), 8_000, "first jackpot pays");
+        assert_eq!(roll(&mut global_state, 9_000), 9_000, "8_000 paid is under the cap");
+        assert_eq!(global_state.singularity_paid_this_epoch, 17_000);
+        assert_eq!(roll(&mut global_state, 5_000), 0, "past the cap: payout only");
+        assert_eq!(global_state.singularity_balance, 5_000, "pool untouched");
+
+        global_state.epoch_started_at = NOW - EPOCH_DURATION;
+        let (result, advanced) = advance_epoch(&global_state);
+        result.unwrap();
+        let mut global_state = (*advanced.global_state).clone();
+        assert_eq!(global_state.singularity_paid_this_epoch, 0);
+        assert_eq!(roll(&mut global_state, 5_000), 5_000);
+    }
+
+    #[test]
+    fn wrong_states_name_the_state_the_instruction_expected() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        let code = |e: SeekError| Some(6000 + e as u32);
+        let statuses = [
+            BountyStatus::Pending,
+            BountyStatus::Submitted,
+            BountyStatus::ChallengeWon,
+            BountyStatus::ChallengeLost,
+            BountyStatus::Disputed,
+            BountyStatus::Won,
+            BountyStatus::Lost,
+            BountyStatus::Cancelled,
+        ];
+        let in_status = |status: BountyStatus| {
+            let mut bounty = submitted_bounty(&global_state, 1);
+            bounty.status = status;
+            bounty.is_disputed = status == BountyStatus::Disputed;
+            bounty
+        };
+
+        for status in statuses {
+            let bounty = in_status(status);
+            if status != BountyStatus::Submitted {
+                let (result, _) = propose(global_state.clone(), &bounty, false);
+                assert_eq!(error_code(result), code(SeekError::ExpectedSubmitted));
+            }
+            if !matches!(status, BountyStatus::ChallengeWon | BountyStatus::ChallengeLost) {
+                // Disputed bounties are turned away before the state check
+                let expected = match status {
+                    BountyStatus::Disputed => SeekError::AlreadyDisputed,
+                    _ => SeekError::ExpectedChallenge,
+                };
+                let mut accounts = finalize_accounts(global_state.clone(), leaked(&bounty));
+                assert_eq!(error_code(finalize(&mut accounts)), code(expected));
+            }
+            // Settled bounties report the finalize race instead
+            let settled = matches!(
+                status,
+                BountyStatus::Won | BountyStatus::Lost | BountyStatus::Cancelled
+            );
+            if status != BountyStatus::ChallengeLost && !settled {
+                let (result, _) = dispute(global_state.clone(), leaked(&bounty));
+                assert_eq!(error_code(result), code(SeekError::ExpectedChallenge));
+            }
+            if status != BountyStatus::Disputed {
+                let mut accounts = dispute_accounts(&global_state, [0u8; 32]);
+                accounts.bounty = leaked(&bounty);
+                let result = seek_protocol::resolve_dispute(
+                    Context::new(&crate::ID, &mut accounts, &[], ResolveDisputeBumps::default()),
+                    true,
+                    DisputeReason::TechnicalError as u8,
+                );
+                assert_eq!(error_code(result), code(SeekError::ExpectedDisputed));
+            }
+        }
+    }
+
+    #[test]
+    fn win_jackpot_slice_moves_from_the_payout_into_the_pool() {
+        install_stubs();
+
+        let settle = |win_jackpot_bps: u16| {
+            let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+            global_state.house_fund_balance = 1_000_000;
+            global_state.singularity_balance = 500;
+            global_state.win_jackpot_bps = win_jackpot_bps;
+            // Epoch jackpot cap spent, so the roll can't pay out the pool
+            global_state.max_jackpot_per_epoch = 1;
+            global_state.singularity_paid_this_epoch = 1;
+            let global_state = leaked(&global_state);
+            let mut accounts = finalize_accounts(global_state.clone(), proposed_win(&global_state));
+            let before = transfers().len();
+            finalize(&mut accounts).unwrap();
+            (accounts, transfers()[before..].to_vec())
+        };
+
+        let (won, moved) = settle(100);
+        let (singularity, player) = (
+            won.singularity_vault.key(),
+            won.player_token_account.as_ref().unwrap().key(),
+        );
+        assert_eq!(moved, [(singularity, 20), (player, 1_980)], "1% of the 2_000 payout");
+        assert_eq!(won.global_state.singularity_balance, 520);
+        assert_eq!(won.global_state.house_fund_balance, 1_000_000 - 2_000);
+        let event = emitted::<BountyWon>().into_iter().find(|e| e.bounty == won.bounty.key());
+        assert_eq!(event.unwrap().jackpot_contribution, 20);
+
+        let (unset, moved) = settle(0);
+        assert_eq!(moved, [(unset.player_token_account.as_ref().unwrap().key(), 2_000)]);
+        assert_eq!(unset.global_state.singularity_balance, 500);
+    }
+
+    #[test]
+    fn reveal_and_propose_matches_the_two_step_flow() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.commitment_ttl = COMMITMENT_TTL;
+        let global_state = leaked(&global_state);
+        let mission_id = [6u8; 32];
+        let mut bounty = committed_bounty(mission_id);
+        bounty.global_state = global_state.key();
+        bounty.tier = 1;
+        let usage = || *leaked(&zeroed::<MissionUsage>(MissionUsage::SIZE));
+
+        let (result, _) = reveal_bounty(&global_state, &bounty, mission_id, Some(usage()));
+        result.unwrap();
+        let mut revealed = bounty.clone();
+        revealed.status = BountyStatus::Submitted;
+        revealed.mission_revealed = true;
+        revealed.mission_id = mission_id;
+        let (result, two_step) = propose(global_state.clone(), &revealed, false);
+        result.unwrap();
+
+        let combined = |salt: [u8; 32]| {
+            let mut accounts = RevealAndPropose {
+                hot_authority: signer(global_state.hot_authority),
+                global_state: global_state.clone(),
+                bounty: leaked(&bounty),
+                player_stats: player_stats(),
+                loyalty_escrow: None,
+                loss_oracle: None,
+                player_token_account: Some(vault(0)),
+                house_vault: vault(1_000_000),
+                singularity_vault: vault(0),
+                protocol_treasury: vault(0),
+                charity_vault: None,
+                skr_mint: skr_mint(),
+                mission_usage: Some(Box::new(usage())),
+                blocked_mission: blocked_mission(None),
+                token_program: token_program(),
+                system_program: system_program(),
+            };
+            let result = seek_protocol::reveal_and_propose(
+                Context::new(
+                    &crate::ID,
+                    &mut accounts,
+                    &[],
+                    RevealAndProposeBumps { mission_usage: Some(254), ..Default::default() },
+                ),
+                mission_id,
+                salt,
+                None,
+                false,
+            );
+            (result, accounts)
+        };
+
+        let (result, one_step) = combined(SALT);
+        result.unwrap();
+        let state = |b: &Bounty| {
+            (
+                b.status,
+                b.mission_revealed,
+                b.mission_id,
+                b.resolved_at,
+                b.challenge_ends_at,
+                b.proposed_win,
+            )
+        };
+        assert!(state(&one_step.bounty) == state(&two_step.bounty), "same end state");
+        assert!(one_step.bounty.status == BountyStatus::ChallengeLost);
+
+        let (result, failed) = combined([1u8; 32]);
+        assert_eq!(error_code(result), Some(6000 + SeekError::InvalidMissionHash as u32));
+        assert!(state(&failed.bounty) == state(&bounty), "nothing was recorded");
+    }
+
+    #[test]
+    fn fractional_multipliers_round_down_and_finalize_pays_the_recorded_payout() {
+        // 2.5x of an odd entry is 2_500_000_002.5: the half unit stays with the house
+        let odd_entry = 1_000_000_001;
+        assert_eq!(compute_payout(odd_entry, 25_000).unwrap(), 2_500_000_002);
+        assert_eq!(
+            compute_payout(TIER_1_ENTRY, DEFAULT_PAYOUT_MULTIPLIER_BPS).unwrap(),
+            3 * TIER_1_ENTRY,
+            "the default 3x is exact"
+        );
+
+        let mut global_state = open_global_state();
+        global_state.payout_multiplier_bps = 25_000;
+        let (result, accepted) = accept(&global_state, TIER_1_ENTRY);
+        assert_eq!(result.unwrap().payout_amount, TIER_1_ENTRY * 5 / 2);
+
+        // A later change doesn't touch the recorded payout
+        global_state.payout_multiplier_bps = DEFAULT_PAYOUT_MULTIPLIER_BPS;
+        let global_state = leaked(&global_state);
+        let mut bounty = (**proposed_win(&global_state)).clone();
+        bounty.entry_amount = accepted.bounty.entry_amount;
+        bounty.payout_amount = accepted.bounty.payout_amount;
+        let mut won = finalize_accounts(global_state, leaked(&bounty));
+        won.house_vault = vault(10 * TIER_1_ENTRY);
+        let before = transfers().len();
+        finalize(&mut won).unwrap();
+        let player = won.player_token_account.as_ref().unwrap().key();
+        assert_eq!(transfers()[before..], [(player, TIER_1_ENTRY * 5 / 2)]);
+    }
+
+    #[test]
+    fn payout_multiplier_setter_keeps_the_entry_whole_and_caps_at_5x() {
+        let authority = Pubkey::new_unique();
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.authority = authority;
+        let mut accounts = UpdateConfig {
+            authority: signer(authority),
+            global_state: leaked(&global_state),
+        };
+        let mut set = |multiplier_bps| {
+            seek_protocol::set_payout_multiplier(
+                Context::new(&crate::ID, &mut accounts, &[], UpdateConfigBumps::default()),
+                multiplier_bps,
+            )
+        };
+
+        let invalid = Some(6000 + SeekError::InvalidConfig as u32);
+        assert_eq!(error_code(set(9_999)), invalid, "a win must return the entry");
+        assert_eq!(error_code(set(MAX_PAYOUT_MULTIPLIER_BPS + 1)), invalid);
+        set(10_000).unwrap();
+        set(MAX_PAYOUT_MULTIPLIER_BPS).unwrap();
+        assert_eq!(accounts.global_state.payout_multiplier_bps, MAX_PAYOUT_MULTIPLIER_BPS);
+    }
+
+    #[test]
+    fn close_protocol_waits_for_a_full_wind_down() {
+        install_stubs();
+
+        let (result, accepted) = accept(&open_global_state(), TIER_1_ENTRY);
+        result.unwrap();
+        assert_eq!(accepted.global_state.active_bounties, 1);
+
+        let mut global_state = (**accepted.global_state).clone();
+        global_state.active_bounties = 2;
+        // A vault token account, still live or already closed by sweep_vaults
+        let vault = |live: bool| {
+            let mut slot = Slot::new(token::ID, vec![0; TokenAccount::LEN]);
+            if !live {
+                slot.lamports = 0;
+            }
+            UncheckedAccount::try_from(pin(leak(slot)))
+        };
+        let close = |global_state: &GlobalState, house_live: bool, singularity_live: bool| {
+            let mut accounts = CloseProtocol {
+                authority: signer(global_state.authority),
+                global_state: leaked(global_state),
+                house_vault: vault(house_live),
+                singularity_vault: vault(singularity_live),
+            };
+            seek_protocol::close_protocol(Context::new(
+                &crate::ID,
+                &mut accounts,
+                &[],
+                CloseProtocolBumps::default(),
+            ))
+        };
+        let code = |e: SeekError| Some(6000 + e as u32);
+        assert_eq!(
+            error_code(close(&global_state, false, false)),
+            code(SeekError::ProtocolNotShutdown)
+        );
+
+        // A settled win is retired; a practice hunt was never counted
+        let global_state = leaked(&global_state);
+        let mut won = finalize_accounts(global_state.clone(), proposed_win(&global_state));
+        finalize(&mut won).unwrap();
+        let mut global_state = (**won.global_state).clone();
+        assert_eq!(global_state.active_bounties, 1);
+        let mut practice: Bounty = zeroed(Bounty::SIZE);
+        practice.practice = true;
+        retire_bounty(&mut global_state, &mut practice).unwrap();
+        assert_eq!(global_state.active_bounties, 1);
+
+        global_state.shutdown = true;
+        assert_eq!(
+            error_code(close(&global_state, false, false)),
+            code(SeekError::BountiesStillOpen)
+        );
+        retire_bounty(&mut global_state, &mut zeroed(Bounty::SIZE)).unwrap();
+        assert_eq!(error_code(close(&global_state, true, false)), code(SeekError::VaultsNotEmpty));
+        assert_eq!(error_code(close(&global_state, false, true)), code(SeekError::VaultsNotEmpty));
+        close(&global_state, false, false).unwrap();
+    }
+
+    #[test]
+    fn daily_wager_cap_blocks_past_the_cap_until_the_window_rolls() {
+        let cap = 5_000;
+        let mut stats: PlayerStats = zeroed(PlayerStats::SIZE);
+        stats.record_daily_wager(3_000, cap, NOW).unwrap();
+        stats.record_daily_wager(2_000, cap, NOW + 60).expect("exactly at the cap");
+        assert_eq!(
+            error_code(stats.record_daily_wager(1_000, cap, NOW + 120)),
+            Some(6000 + SeekError::DailyLimitReached as u32)
+        );
+        assert_eq!(stats.wagered_today, 5_000, "the rejected bet isn't counted");
+
+        stats.record_daily_wager(3_000, cap, NOW + WAGER_CAP_WINDOW).unwrap();
+        assert_eq!((stats.wagered_today, stats.day_start), (3_000, NOW + WAGER_CAP_WINDOW));
+        stats.record_daily_wager(10_000, 0, NOW + WAGER_CAP_WINDOW + 1).expect("0 only tracks");
+
+        let mut global_state = open_global_state();
+        global_state.daily_wager_cap = TIER_1_ENTRY;
+        let (result, accepted) = accept(&global_state, TIER_1_ENTRY);
+        result.unwrap();
+        assert_eq!(accepted.player_stats.wagered_today, TIER_1_ENTRY);
+        assert_eq!(
+            error_code(accept(&global_state, TIER_2_ENTRY).0),
+            Some(6000 + SeekError::DailyLimitReached as u32)
+        );
+    }
+
+    #[test]
+    fn disputable_losses_never_fast_finalize() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.fast_resolve_tiers = 0b001;
+        let global_state = leaked(&global_state);
+
+        let (result, lost) = propose(global_state.clone(), &submitted_bounty(&global_state, 1), false);
+        result.unwrap();
+        assert!(lost.bounty.status == BountyStatus::ChallengeLost, "the player keeps the window");
+        assert_eq!(lost.bounty.challenge_ends_at, NOW + CHALLENGE_PERIOD);
+
+        let (result, attested) = propose_attested(
+            global_state.clone(),
+            &submitted_bounty(&global_state, 1),
+            false,
+            Some(global_state.loss_oracle),
+        );
+        result.unwrap();
+        assert!(
+            attested.bounty.status == BountyStatus::Lost,
+            "an oracle-attested loss has no dispute window to protect"
+        );
+
+        assert!(global_state.settles_at_proposal(1, true, false).unwrap());
+        assert!(!global_state.settles_at_proposal(2, false, true).unwrap(), "tier 2 isn't in the mask");
+    }
+
+    #[test]
+    fn get_config_reflects_setter_calls_and_fits_in_return_data() {
+        let authority = Pubkey::new_unique();
+        let mut global_state = open_global_state();
+        global_state.authority = authority;
+        let mut accounts = UpdateConfig {
+            authority: signer(authority),
+            global_state: leaked(&global_state),
+        };
+        fn ctx<'a>(
+            accounts: &'a mut UpdateConfig<'static>,
+        ) -> Context<'a, 'a, 'a, 'static, UpdateConfig<'static>> {
+            Context::new(&crate::ID, accounts, &[], UpdateConfigBumps::default())
+        }
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        seek_protocol::set_protocol_split(ctx(&mut accounts), vec![first, second], vec![6000, 4000])
+            .unwrap();
+        seek_protocol::set_payout_multiplier(ctx(&mut accounts), 25_000).unwrap();
+        seek_protocol::set_daily_wager_cap(ctx(&mut accounts), 10_000 * TIER_1_ENTRY).unwrap();
+        seek_protocol::set_fast_resolve_tiers(ctx(&mut accounts), 0b001).unwrap();
+        seek_protocol::set_accept_ordering(ctx(&mut accounts), true).unwrap();
+
+        let mut view = GetPdaInfo { global_state: *accounts.global_state };
+        let config = seek_protocol::get_config(Context::new(
+            &crate::ID,
+            &mut view,
+            &[],
+            GetPdaInfoBumps::default(),
+        ))
+        .unwrap();
+        assert_eq!(config.authority, authority);
+        assert_eq!(config.protocol_destinations[..2], [first, second]);
+        assert_eq!(config.protocol_weights, [6000, 4000, 0, 0]);
+        assert_eq!(config.payout_multiplier_bps, 25_000);
+        assert_eq!(config.daily_wager_cap, 10_000 * TIER_1_ENTRY);
+        assert_eq!(config.fast_resolve_tiers, 0b001);
+        assert!(config.accept_ordering);
+        assert_eq!(config.tier_durations, view.global_state.tier_durations);
+        assert!(config.try_to_vec().unwrap().len() <= 1024, "return data is capped at 1 KiB");
+    }
+
+    #[test]
+    fn proposed_losses_hold_the_minimum_dispute_window() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+
+        // A zero-length configured window, proposed this second
+        let mut loss = proposed_loss(0);
+        loss.resolved_at = NOW;
+        assert_eq!(finalize_deadline(&loss, &global_state).unwrap(), NOW + MIN_CHALLENGE_PERIOD);
+        let mut accounts = finalize_accounts(leaked(&global_state), leaked(&loss));
+        assert_eq!(
+            error_code(finalize(&mut accounts)),
+            Some(6000 + SeekError::ChallengePeriodActive as u32)
+        );
+        let (result, _) = dispute(leaked(&global_state), leaked(&loss));
+        result.expect("the floor keeps disputes open");
+
+        loss.resolved_at = NOW - MIN_CHALLENGE_PERIOD;
+        let mut accounts = finalize_accounts(leaked(&global_state), leaked(&loss));
+        finalize(&mut accounts).unwrap();
+        assert!(accounts.bounty.status == BountyStatus::Lost);
+
+        // Longer configured windows and wins are untouched
+        global_state.finalize_grace = 60;
+        let mut loss = proposed_loss(300);
+        loss.resolved_at = NOW;
+        assert_eq!(finalize_deadline(&loss, &global_state).unwrap(), NOW + 360);
+        loss.status = BountyStatus::ChallengeWon;
+        loss.challenge_ends_at = NOW;
+        assert_eq!(finalize_deadline(&loss, &global_state).unwrap(), NOW - CLOCK_SKEW_GRACE);
+    }
+
+    #[test]
+    fn a_singularity_top_up_is_paid_by_the_next_jackpot() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.authority = Pubkey::new_unique();
+        global_state.singularity_balance = 40_000;
+        let mut accounts = FundSingularity {
+            authority: signer(global_state.authority),
+            global_state: *leaked(&global_state),
+            authority_token_account: *vault(10_000),
+            singularity_vault: *vault(40_000),
+            token_program: token_program(),
+        };
+        let fund = |accounts: &mut FundSingularity<'static>, amount| {
+            seek_protocol::fund_singularity(
+                Context::new(&crate::ID, accounts, &[], FundSingularityBumps::default()),
+                amount,
+            )
+        };
+
+        assert_eq!(
+            error_code(fund(&mut accounts, 0)),
+            Some(6000 + SeekError::InvalidAmount as u32)
+        );
+        fund(&mut accounts, 10_000).unwrap();
+        assert_eq!(accounts.global_state.singularity_balance, 50_000);
+        assert_eq!(transfers().last(), Some(&(accounts.singularity_vault.key(), 10_000)));
+
+        let mut global_state = (*accounts.global_state).clone();
+        assert_eq!(roll_winning_jackpot(&mut global_state, false).unwrap(), 50_000);
+        assert_eq!(global_state.singularity_balance, 0);
+
+        accounts.global_state.shutdown = true;
+        assert_eq!(
+            error_code(fund(&mut accounts, 1)),
+            Some(6000 + SeekError::ProtocolShutdown as u32)
+        );
+    }
+
+    #[test]
+    fn co_disputers_split_the_bonus_pro_rata_by_stake() {
+        let entry = 2_000 * DECIMALS_MULTIPLIER;
+        let player_stake = entry / 2;
+        let (a, b) = (3_000 * DECIMALS_MULTIPLIER, 1_000 * DECIMALS_MULTIPLIER);
+        let total = player_stake + a + b;
+        let paid = |stake| dispute_stake_return(entry, 5_000, stake, total).unwrap();
+
+        // 1_000 SKR bonus over 5_000 SKR of stake
+        assert_eq!(paid(player_stake), player_stake + 200 * DECIMALS_MULTIPLIER);
+        assert_eq!(paid(a), a + 600 * DECIMALS_MULTIPLIER);
+        assert_eq!(paid(b), b + 200 * DECIMALS_MULTIPLIER);
+
+        assert_eq!(dispute_stake_return(1_000, 1_000, 1, 3).unwrap(), 1 + 33, "rounds down");
+        assert_eq!(
+            dispute_stake_return(1_000, 1_000, 500, 500).unwrap(),
+            500 + 100,
+            "alone, the player takes the whole bonus"
+        );
+    }
+
+    #[test]
+    fn jackpot_seed_takes_extreme_clocks_and_rejects_negative_ones() {
+        install_stubs();
+
+        let vault = leak(Slot::new(token::ID, Vec::new()));
+        let roll = |slot, unix_timestamp| {
+            let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+            let mut bounty: Bounty = zeroed(Bounty::SIZE);
+            let clock = Clock { slot, unix_timestamp, ..Clock::default() };
+            roll_singularity(
+                &mut global_state,
+                &mut bounty,
+                Pubkey::new_unique(),
+                &vaults(&vault),
+                &vault,
+                &clock,
+                SINGULARITY_ODDS,
+            )
+        };
+
+        roll(u64::MAX, i64::MAX).unwrap();
+        roll(0, 0).unwrap();
+        assert_eq!(
+            error_code(roll(1, -1)),
+            Some(6000 + SeekError::InvalidTimestamp as u32),
+            "a negative clock doesn't wrap"
+        );
+    }
+
+    #[test]
+    fn a_cancel_can_be_undone_within_the_grace_on_the_original_timer() {
+        let mut global_state = open_global_state();
+        global_state.min_cancel_fee_bps = 500;
+        global_state.max_cancel_fee_bps = 2_000;
+        let (result, accepted) = accept(&global_state, TIER_1_ENTRY);
+        result.unwrap();
+        let mut accounts = CancelBounty {
+            player: signer(accepted.bounty.player),
+            global_state: accepted.global_state,
+            bounty: accepted.bounty,
+            player_stats: player_stats(),
+            player_token_account: vault(TIER_1_ENTRY),
+            house_vault: vault(1_000_000 * DECIMALS_MULTIPLIER),
+            token_program: token_program(),
+        };
+        let expires_at = accounts.bounty.expires_at;
+        fn ctx<'a>(
+            accounts: &'a mut CancelBounty<'static>,
+        ) -> Context<'a, 'a, 'a, 'static, CancelBounty<'static>> {
+            Context::new(&crate::ID, accounts, &[], CancelBountyBumps::default())
+        }
+        let not_allowed = Some(6000 + SeekError::ReopenNotAllowed as u32);
+
+        seek_protocol::cancel_bounty(ctx(&mut accounts)).unwrap();
+        let fee = accounts.bounty.cancel_fee;
+        assert_eq!(fee, TIER_1_ENTRY / 20, "the minimum fee, kept on reopen");
+        assert_eq!(accounts.bounty.cancelled_at, NOW);
+        let house = accounts.global_state.house_fund_balance;
+        let active = accounts.global_state.active_bounties;
+
+        let before = transfers().len();
+        seek_protocol::reopen_bounty(ctx(&mut accounts)).unwrap();
+        assert_eq!(transfers()[before..], [(accounts.house_vault.key(), TIER_1_ENTRY - fee)]);
+        assert!(accounts.bounty.status == BountyStatus::Pending);
+        assert_eq!(accounts.bounty.expires_at, expires_at, "original timer");
+        assert_eq!(accounts.global_state.house_fund_balance, house + TIER_1_ENTRY - fee);
+        assert_eq!(accounts.global_state.active_bounties, active + 1);
+        assert_eq!(error_code(seek_protocol::reopen_bounty(ctx(&mut accounts))), not_allowed);
+
+        // Past the grace, past the hunt timer, or once revealed
+        let cancelled = |edit: fn(&mut Bounty)| {
+            let mut bounty = (**accounts.bounty).clone();
+            bounty.status = BountyStatus::Cancelled;
+            bounty.cancelled_at = NOW;
+            edit(&mut bounty);
+            let mut accounts = CancelBounty {
+                player: signer(bounty.player),
+                global_state: accounts.global_state.clone(),
+                bounty: leaked(&bounty),
+                player_stats: player_stats(),
+                player_token_account: vault(TIER_1_ENTRY),
+                house_vault: vault(0),
+                token_program: token_program(),
+            };
+            error_code(seek_protocol::reopen_bounty(Context::new(
+                &crate::ID,
+                &mut accounts,
+                &[],
+                CancelBountyBumps::default(),
+            )))
+        };
+        assert_eq!(cancelled(|_| {}), None);
+        assert_eq!(cancelled(|b| b.cancelled_at = NOW - REOPEN_GRACE - 1), not_allowed);
+        assert_eq!(cancelled(|b| b.expires_at = NOW - CLOCK_SKEW_GRACE - 1), not_allowed);
+        assert_eq!(cancelled(|b| b.mission_revealed = true), not_allowed);
+    }
+
+    #[test]
+    fn payouts_refuse_the_house_vault_as_the_destination() {
+        install_stubs();
+
+        let (key, _) = Pubkey::find_program_address(&[b"global_state"], &crate::ID);
+        let player = Pubkey::new_unique();
+        let player_ata = get_associated_token_address(&player, &SKR_MINT);
+        let as_house = || {
+            let info = token_account(player_ata, player, 1_000_000);
+            Box::new(Account::<TokenAccount>::try_from(pin(info)).unwrap())
+        };
+        let invalid = Some(6000 + SeekError::InvalidTokenAccount as u32);
+
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = player;
+        bounty.global_state = key;
+        let mut accounts = finalize_accounts(leaked(&zeroed(GlobalState::SIZE)), leaked(&bounty));
+        accounts.player_token_account = Some(as_house());
+        accounts.house_vault = as_house();
+        assert_eq!(error_code(check_finalize_accounts(&accounts)), invalid);
+
+        // Batches check each item's destination at run time
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        bounty.global_state = global_state.key();
+        bounty.status = BountyStatus::Disputed;
+        bounty.is_disputed = true;
+        bounty.entry_amount = 1_000;
+        let items: &'static [AccountInfo<'static>] = vec![
+            leak(Slot::of(&bounty)),
+            leak(Slot::at(player_ata, token::ID, Vec::new())),
+            leak(Slot::of(&PlayerStats { player, ..zeroed(PlayerStats::SIZE) })),
+        ]
+        .leak();
+        let mut accounts = ResolveDisputesBatch {
+            authority: signer(Pubkey::new_unique()),
+            global_state,
+            house_vault: as_house(),
+            singularity_vault: vault(0),
+            protocol_treasury: vault(0),
+            charity_vault: None,
+            token_program: token_program(),
+        };
+        let result = seek_protocol::resolve_disputes_batch(
+            Context::new(&crate::ID, &mut accounts, items, ResolveDisputesBatchBumps::default()),
+            vec![DisputeRuling { player_wins: true, reason_code: 0 }],
+        );
+        assert_eq!(error_code(result), invalid);
+    }
+
+    #[test]
+    fn required_house_funding_reports_the_shortfall_for_a_target() {
+        let mut global_state = open_global_state();
+        global_state.payout_multiplier_bps = 30_000;
+        global_state.outstanding_liability = 4_000 * DECIMALS_MULTIPLIER;
+        global_state.house_fund_balance = 50_000 * DECIMALS_MULTIPLIER;
+        let mut accounts = GetPdaInfo { global_state: *leaked(&global_state) };
+        let mut required = |tier, count| {
+            seek_protocol::required_house_funding(
+                Context::new(&crate::ID, &mut accounts, &[], GetPdaInfoBumps::default()),
+                tier,
+                count,
+            )
+        };
+
+        let funding = required(3, 10).unwrap();
+        assert_eq!(funding.target_liability, 90_000 * DECIMALS_MULTIPLIER);
+        assert_eq!(funding.total_liability, 94_000 * DECIMALS_MULTIPLIER);
+        assert_eq!(funding.shortfall, 44_000 * DECIMALS_MULTIPLIER);
+        assert_eq!(required(1, 2).unwrap().shortfall, 0, "already covered");
+        assert_eq!(
+            error_code(required(4, 1)),
+            Some(6000 + SeekError::InvalidEntryAmount as u32)
+        );
+    }
+
+    #[test]
+    fn loyalty_rebates_accrue_across_wins_and_are_claimed_in_one_go() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.loyalty_rebate_bps = 200;
+        let global_state = leaked(&global_state);
+        let player = Pubkey::new_unique();
+        let win = |global_state: Box<Account<'static, GlobalState>>, escrow| {
+            let mut bounty = (**proposed_win(&global_state)).clone();
+            bounty.player = player;
+            let mut accounts = finalize_accounts(global_state, leaked(&bounty));
+            accounts.loyalty_escrow = escrow;
+            let before = transfers().len();
+            finalize(&mut accounts).unwrap();
+            let paid = transfers()[before..].iter().map(|&(_, amount)| amount).sum::<u64>();
+            (paid, accounts)
+        };
+
+        // 2% of each 2_000 payout stays behind in the escrow
+        let (paid, first) = win(global_state.clone(), Some(loyalty_escrow()));
+        assert_eq!(paid, 1_960);
+        let (paid, second) = win(first.global_state, first.loyalty_escrow);
+        assert_eq!(paid, 1_960);
+        let escrow = second.loyalty_escrow.unwrap();
+        assert_eq!((escrow.balance, escrow.total_accrued), (80, 80));
+        assert_eq!(second.global_state.loyalty_escrowed, 80);
+
+        let mut accounts = ClaimLoyalty {
+            player: signer(player),
+            global_state: second.global_state,
+            loyalty_escrow: escrow,
+            player_token_account: vault(0),
+            house_vault: vault(1_000_000),
+            token_program: token_program(),
+        };
+        let mut claim = || {
+            seek_protocol::claim_loyalty(Context::new(
+                &crate::ID,
+                &mut accounts,
+                &[],
+                ClaimLoyaltyBumps::default(),
+            ))
+        };
+        let before = transfers().len();
+        claim().unwrap();
+        assert_eq!(transfers()[before..].iter().map(|&(_, amount)| amount).sum::<u64>(), 80);
+        assert_eq!(error_code(claim()), Some(6000 + SeekError::NothingToClaim as u32));
+        assert_eq!(accounts.global_state.loyalty_escrowed, 0);
+        assert_eq!(accounts.loyalty_escrow.total_accrued, 80);
+
+        // Settled without the escrow, a win is paid in full
+        assert_eq!(win(global_state.clone(), None).0, 2_000);
+
+        // Unclaimed rebates are the players', not the house's to pay wins with
+        let mut global_state = (**global_state).clone();
+        global_state.loyalty_escrowed = 100;
+        let bounty = proposed_win(&leaked(&global_state));
+        let mut accounts = finalize_accounts(leaked(&global_state), bounty);
+        accounts.house_vault = vault(2_050);
+        assert_eq!(
+            error_code(finalize(&mut accounts)),
+            Some(6000 + SeekError::InsufficientHouseFunds as u32)
+        );
+    }
+
+    #[test]
+    fn only_the_player_opens_a_dispute_and_others_join_only_when_enabled() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let loss = proposed_loss(60);
+
+        // DisputeBounty pins the signer to the bounty's player
+        let (result, mut opened) = dispute(leaked(&global_state), leaked(&loss));
+        result.unwrap();
+        let signed_by = |key: Pubkey| {
+            let mut infos = opened.to_account_infos();
+            infos[0] = signer(key).to_account_info();
+            let infos: &'static [AccountInfo<'static>] = infos.leak();
+            error_code(DisputeBounty::try_accounts(
+                &crate::ID,
+                &mut &infos[..],
+                &[],
+                &mut DisputeBountyBumps::default(),
+                &mut std::collections::BTreeSet::new(),
+            ))
+        };
+        let unauthorized = Some(6000 + SeekError::Unauthorized as u32);
+        assert_eq!(signed_by(Pubkey::new_unique()), unauthorized);
+        assert_ne!(signed_by(loss.player), unauthorized, "past the signer pin");
+
+        let join = |opened: &mut DisputeBounty<'static>, disputer: Pubkey| {
+            let mut accounts = JoinDispute {
+                disputer: signer(disputer),
+                global_state: opened.global_state.clone(),
+                bounty: opened.bounty.clone(),
+                dispute_share: leaked(&zeroed::<DisputeShare>(DisputeShare::SIZE)),
+                disputer_token_account: vault(1_000),
+                house_vault: vault(1_000_000),
+                token_program: token_program(),
+                system_program: system_program(),
+            };
+            let result = seek_protocol::join_dispute(
+                Context::new(&crate::ID, &mut accounts, &[], JoinDisputeBumps::default()),
+                500,
+            );
+            error_code(result)
+        };
+        let code = |e: SeekError| Some(6000 + e as u32);
+        let stranger = Pubkey::new_unique();
+        assert_eq!(join(&mut opened, stranger), code(SeekError::CoDisputesDisabled));
+        opened.global_state.co_disputes_enabled = true;
+        assert_eq!(join(&mut opened, stranger), None);
+        assert_eq!(join(&mut opened, loss.player), code(SeekError::CannotJoinOwnDispute));
+        opened.bounty.status = BountyStatus::ChallengeLost;
+        assert_eq!(join(&mut opened, stranger), code(SeekError::ExpectedDisputed));
+    }
+
+    #[test]
+    fn wins_inside_the_jackpot_cooldown_pay_but_skip_the_roll() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.jackpot_cooldown = 3_600;
+        let global_state = leaked(&global_state);
+        let rolls = || emitted::<SingularityRoll>().len();
+        let win = |player_stats: Box<Account<'static, PlayerStats>>| {
+            let bounty = proposed_win(&global_state);
+            let mut accounts = finalize_accounts(global_state.clone(), bounty);
+            accounts.player_stats = player_stats;
+            let (before, paid_before) = (rolls(), transfers().len());
+            finalize(&mut accounts).unwrap();
+            assert_eq!(transfers().len(), paid_before + 1, "the payout is made either way");
+            (rolls() > before, accounts.player_stats)
+        };
+
+        let (rolled, stats) = win(player_stats());
+        assert!(rolled);
+        assert_eq!(stats.last_jackpot_roll_at, NOW);
+        let (rolled, mut stats) = win(stats);
+        assert!(!rolled, "inside the cooldown");
+        assert_eq!(stats.last_jackpot_roll_at, NOW, "a skipped roll doesn't restart it");
+
+        stats.last_jackpot_roll_at = NOW - 3_600;
+        assert!(win(stats).0);
+    }
+
+    #[test]
+    fn a_loss_ends_with_a_snapshot_matching_the_tracked_balances() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 51_000;
+        global_state.singularity_balance = 2_000;
+        global_state.total_burned = 10;
+        let mut accounts = finalize_accounts(leaked(&global_state), leaked(&proposed_loss(-1)));
+        finalize(&mut accounts).unwrap();
+
+        let snapshot = emitted::<BalancesSnapshot>().into_iter().last().unwrap();
+        let global_state = &accounts.global_state;
+        assert_eq!(snapshot.house_fund_balance, global_state.house_fund_balance);
+        assert_eq!(snapshot.singularity_balance, global_state.singularity_balance);
+        assert_eq!(snapshot.total_burned, 10, "a loss burns nothing");
+        assert_eq!(snapshot.at, NOW);
+        assert_eq!(
+            (snapshot.house_fund_balance, snapshot.singularity_balance),
+            (51_000 - 1_000 + 700, 2_200)
+        );
+    }
+
+    #[test]
+    fn reveals_wait_out_the_minimum_hunt_slots() {
+        let (result, accepted) = accept(&open_global_state(), TIER_1_ENTRY);
+        result.unwrap();
+        assert_eq!(accepted.bounty.accepted_slot, 1, "the stub clock's slot");
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.commitment_ttl = COMMITMENT_TTL;
+        let mission_id = [9u8; 32];
+        let usage = || *leaked(&zeroed::<MissionUsage>(MissionUsage::SIZE));
+        let mut reveal_at = |accepted_slot, min_hunt_slots| {
+            let mut bounty = committed_bounty(mission_id);
+            bounty.accepted_slot = accepted_slot;
+            global_state.min_hunt_slots = min_hunt_slots;
+            error_code(reveal_bounty(&global_state, &bounty, mission_id, Some(usage())).0)
+        };
+
+        assert_eq!(reveal_at(1, 0), None, "no minimum");
+        assert_eq!(reveal_at(1, 1), Some(6000 + SeekError::RevealTooEarly as u32));
+        assert_eq!(reveal_at(0, 1), None, "one slot later");
+    }
+
+    #[test]
+    fn adjust_liability_corrects_a_stale_reservation_within_bounds() {
+        let authority = Pubkey::new_unique();
+        let payout = 9_000 * DECIMALS_MULTIPLIER;
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.authority = authority;
+        global_state.outstanding_liability = 2 * payout;
+        let mut accounts = UpdateConfig {
+            authority: signer(authority),
+            global_state: leaked(&global_state),
+        };
+        let mut adjust = |delta, increase| {
+            seek_protocol::adjust_liability(
+                Context::new(&crate::ID, &mut accounts, &[], UpdateConfigBumps::default()),
+                delta,
+                increase,
+            )
+        };
 
-/// Step 1 of authority rotation: current authority proposes a new authority.
-#[derive(Accounts)]
-pub struct ProposeAuthorityTransfer<'info> {
-    /// Current authority
-    #[account(
-        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
+        // One of two reservations is stale after a force-close
+        adjust(payout, false).unwrap();
+        let adjusted = emitted::<LiabilityAdjusted>().into_iter().last().unwrap();
+        assert_eq!((adjusted.previous, adjusted.new), (2 * payout, payout));
+        assert_eq!(error_code(adjust(0, true)), Some(6000 + SeekError::InvalidAmount as u32));
+        assert_eq!(
+            error_code(adjust(payout + 1, false)),
+            Some(6000 + SeekError::MathOverflow as u32),
+            "never below zero"
+        );
+        adjust(50, true).unwrap();
+        assert_eq!(accounts.global_state.outstanding_liability, payout + 50);
+    }
 
-    /// Global state PDA
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
-}
+    #[test]
+    fn disputed_bounties_never_settle_through_finalize() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        let global_state = leaked(&global_state);
+        let finalize_in = |status: BountyStatus, is_disputed: bool| {
+            let mut bounty = proposed_loss(-1);
+            bounty.status = status;
+            bounty.is_disputed = is_disputed;
+            let mut accounts = finalize_accounts(global_state.clone(), leaked(&bounty));
+            error_code(finalize(&mut accounts))
+        };
 
-/// Step 2 of authority rotation: pending authority signs to accept.
-#[derive(Accounts)]
-pub struct AcceptAuthorityTransfer<'info> {
-    /// The pending authority (must match global_state.pending_authority)
-    pub new_authority: Signer<'info>,
+        let already = Some(6000 + SeekError::AlreadyDisputed as u32);
+        assert_eq!(finalize_in(BountyStatus::Disputed, true), already);
+        assert_eq!(finalize_in(BountyStatus::Disputed, false), already);
+        assert_eq!(finalize_in(BountyStatus::ChallengeLost, true), already);
+        assert_eq!(finalize_in(BountyStatus::ChallengeLost, false), None);
+        assert_eq!(
+            finalize_in(BountyStatus::Submitted, false),
+            Some(6000 + SeekError::ExpectedChallenge as u32)
+        );
+    }
 
-    /// Global state PDA
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
-}
+    #[test]
+    fn unproposed_reveals_become_reclaimable_at_the_propose_deadline() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.active_bounties = 1;
+        let global_state = leaked(&global_state);
+        let revealed = |revealed_at| {
+            let mut bounty = submitted_bounty(&global_state, 1);
+            bounty.revealed_at = revealed_at;
+            bounty
+        };
+        let reclaim = |bounty: &Bounty| {
+            let mut accounts = CancelBounty {
+                player: signer(bounty.player),
+                global_state: global_state.clone(),
+                bounty: leaked(bounty),
+                player_stats: player_stats(),
+                player_token_account: vault(0),
+                house_vault: vault(1_000_000),
+                token_program: token_program(),
+            };
+            let result = seek_protocol::reclaim_unproposed(Context::new(
+                &crate::ID,
+                &mut accounts,
+                &[],
+                CancelBountyBumps::default(),
+            ));
+            (error_code(result), accounts)
+        };
+        let code = |e: SeekError| Some(6000 + e as u32);
+
+        // One second early: still the backend's to propose
+        let early = revealed(NOW - PROPOSE_TIMEOUT + 1);
+        assert_eq!(reclaim(&early).0, code(SeekError::ProposeDeadlineNotReached));
+        propose(global_state.clone(), &early, false).0.unwrap();
+
+        // At the deadline the two swap
+        let due = revealed(NOW - PROPOSE_TIMEOUT);
+        let (result, _) = propose(global_state.clone(), &due, false);
+        assert_eq!(error_code(result), code(SeekError::ProposeDeadlinePassed));
+        let before = transfers().len();
+        let (result, reclaimed) = reclaim(&due);
+        assert_eq!(result, None);
+        assert_eq!(transfers()[before..], [(reclaimed.player_token_account.key(), 1_000)]);
+        assert!(reclaimed.bounty.status == BountyStatus::Cancelled);
+        assert_eq!(reclaimed.global_state.house_fund_balance, 1_000_000 - 1_000);
+
+        // Unrevealed and already-proposed bounties are left alone
+        let mut unrevealed = revealed(0);
+        assert_eq!(reclaim(&unrevealed).0, code(SeekError::ProposeDeadlineNotReached));
+        unrevealed.mission_revealed = false;
+        assert_eq!(reclaim(&unrevealed).0, code(SeekError::MissionNotRevealed));
+        let mut proposed = due;
+        proposed.status = BountyStatus::ChallengeLost;
+        assert_eq!(reclaim(&proposed).0, code(SeekError::ExpectedSubmitted));
+    }
 
-/// Cancel an in-flight authority transfer. Current authority only.
-#[derive(Accounts)]
-pub struct CancelAuthorityTransfer<'info> {
-    #[account(
-        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
+    #[test]
+    fn forfeited_stakes_split_between_house_and_treasury() {
+        let loss = DisputeRuling { player_wins: false, reason_code: 0 };
+        let settle = |treasury_bps, refund_bps| {
+            let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+            global_state.house_fund_balance = 1_000_000;
+            global_state.forfeit_stake_treasury_bps = treasury_bps;
+            global_state.dispute_loss_refund_bps = refund_bps;
+            let before = transfers().len();
+            resolve(&mut global_state, loss).0.unwrap();
+            (global_state, transfers()[before..].to_vec())
+        };
 
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
-}
+        let (house_only, _) = settle(0, 0);
+        assert_eq!(house_only.total_protocol_revenue, 100, "just the entry's 10%");
 
-/// Rotate the hot authority. Cold authority only.
-#[derive(Accounts)]
-pub struct SetHotAuthority<'info> {
-    #[account(
-        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
+        // 30% of the 500 stake leaves the house for the treasury
+        let (split, moved) = settle(3_000, 0);
+        assert!(moved.iter().any(|&(_, amount)| amount == 150));
+        assert_eq!(split.total_protocol_revenue, 100 + 150);
+        assert_eq!(split.house_fund_balance, house_only.house_fund_balance - 150);
+        assert_eq!(split.total_dispute_stake_forfeited, 500);
 
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
-}
+        // Only what the player doesn't get back is split
+        let (refunded, moved) = settle(3_000, 5_000);
+        assert!(moved.iter().any(|&(_, amount)| amount == 75));
+        assert_eq!(refunded.total_protocol_revenue, 100 + 75);
+    }
 
-/// Rotate the protocol treasury recipient. Cold authority only.
-/// `new_treasury` must be an existing SKR TokenAccount (the rent-paying
-/// caller pre-creates the ATA off-chain — this instruction just records
-/// the new recipient on `GlobalState`).
-#[derive(Accounts)]
-pub struct SetTreasury<'info> {
-    #[account(
-        constraint = authority.key() == global_state.authority @ SeekError::Unauthorized
-    )]
-    pub authority: Signer<'info>,
+    #[test]
+    fn each_resolution_path_records_its_source() {
+        install_stubs();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.loss_oracle = Pubkey::new_unique();
+        let global_state = leaked(&global_state);
+        let submitted = || submitted_bounty(&global_state, 1);
+
+        let (result, proposed) = propose(global_state.clone(), &submitted(), false);
+        result.unwrap();
+        assert_eq!(proposed.bounty.resolution_source, ResolutionSource::Authority as u8);
+        let oracle = Some(global_state.loss_oracle);
+        let (result, attested) = propose_attested(global_state.clone(), &submitted(), false, oracle);
+        result.unwrap();
+        assert_eq!(attested.bounty.resolution_source, ResolutionSource::Proof as u8);
+
+        // Finalize echoes the proposal's source
+        let mut loss = (**proposed.bounty).clone();
+        loss.resolved_at = NOW - MIN_CHALLENGE_PERIOD;
+        loss.challenge_ends_at = NOW - MIN_CHALLENGE_PERIOD;
+        let mut accounts = finalize_accounts(global_state.clone(), leaked(&loss));
+        finalize(&mut accounts).unwrap();
+        let finalized = emitted::<BountyFinalized>().into_iter().last().unwrap();
+        assert_eq!(finalized.resolution_source, ResolutionSource::Authority as u8);
+
+        let mut expired = submitted();
+        expired.status = BountyStatus::Pending;
+        expired.expires_at = NOW - 60;
+        let mut accounts = MarkTimeout {
+            hot_authority: signer(global_state.hot_authority),
+            global_state: global_state.clone(),
+            bounty: leaked(&expired),
+            player_stats: player_stats(),
+            house_vault: vault(1_000_000),
+            singularity_vault: vault(0),
+            protocol_treasury: vault(0),
+            skr_mint: skr_mint(),
+            token_program: token_program(),
+        };
+        seek_protocol::mark_timeout(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            MarkTimeoutBumps::default(),
+        ))
+        .unwrap();
+        assert_eq!(accounts.bounty.resolution_source, ResolutionSource::Timeout as u8);
+
+        let ruling = DisputeRuling { player_wins: true, reason_code: 0 };
+        let (result, key) = resolve(&mut (**global_state).clone(), ruling);
+        result.unwrap();
+        let resolved = emitted::<DisputeResolved>().into_iter().find(|e| e.bounty == key).unwrap();
+        assert_eq!(resolved.resolution_source, ResolutionSource::DisputeArbiter as u8);
+    }
+    #[test]
+    fn batch_close_refunds_each_player_and_skips_live_or_recent_bounties() {
+        install_stubs();
+
+        let bounty_in = |player: Pubkey, status: BountyStatus, created_at: i64| {
+            let mut bounty: Bounty = zeroed(Bounty::SIZE);
+            bounty.player = player;
+            bounty.status = status;
+            bounty.created_at = created_at;
+            leak(Slot::of(&bounty))
+        };
+        let wallet = |player: Pubkey| leak(Slot::at(player, system_program::ID, Vec::new()));
+        let close = |items: Vec<AccountInfo<'static>>| {
+            let items: &'static [AccountInfo<'static>] = items.leak();
+            let mut accounts = CloseBountiesBatch { caller: signer(Pubkey::new_unique()) };
+            let result = seek_protocol::close_bounties_batch(Context::new(
+                &crate::ID,
+                &mut accounts,
+                items,
+                CloseBountiesBatchBumps::default(),
+            ));
+            (result, items)
+        };
 
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Box<Account<'info, GlobalState>>,
+        let aged = NOW - CLOSE_COOLDOWN;
+        let mut items = Vec::new();
+        for status in [BountyStatus::Won, BountyStatus::Lost, BountyStatus::Cancelled] {
+            let player = Pubkey::new_unique();
+            items.push(bounty_in(player, status, aged));
+            items.push(wallet(player));
+        }
+        let (result, items) = close(items);
+        result.unwrap();
+        for pair in items.chunks(2) {
+            assert_eq!(pair[0].lamports(), 0);
+            assert_eq!(pair[1].lamports(), 2_000_000, "the bounty's rent, to its own player");
+        }
 
-    #[account(
-        token::mint = skr_mint,
-    )]
-    pub new_treasury: Box<Account<'info, TokenAccount>>,
+        let player = Pubkey::new_unique();
+        let live = bounty_in(player, BountyStatus::Disputed, aged);
+        let recent = bounty_in(player, BountyStatus::Lost, aged + 1);
+        let (result, items) = close(vec![live, wallet(player), recent, wallet(player)]);
+        result.unwrap();
+        assert!(items.iter().all(|info| info.lamports() == 1_000_000), "both skipped");
+
+        let redirected = vec![bounty_in(player, BountyStatus::Lost, aged), wallet(Pubkey::new_unique())];
+        assert_eq!(
+            error_code(close(redirected).0),
+            Some(6000 + SeekError::Unauthorized as u32)
+        );
+        for items in [Vec::new(), vec![bounty_in(player, BountyStatus::Lost, aged)]] {
+            assert_eq!(
+                error_code(close(items).0),
+                Some(6000 + SeekError::BatchAccountsMismatch as u32)
+            );
+        }
+    }
 
-    #[account(address = SKR_MINT @ SeekError::InvalidMint)]
-    pub skr_mint: Box<Account<'info, Mint>>,
-}
+    #[test]
+    fn loss_streaks_tighten_jackpot_odds_up_to_the_cap() {
+        let authority = Pubkey::new_unique();
+        let mut global_state = open_global_state();
+        global_state.authority = authority;
+        assert_eq!(global_state.jackpot_odds(u16::MAX).unwrap(), SINGULARITY_ODDS, "off by default");
 
-#[derive(Accounts)]
-pub struct CloseBounty<'info> {
-    /// Player who owns the bounty (receives rent refund)
-    #[account(mut)]
-    pub player: Signer<'info>,
+        let mut accounts = UpdateConfig {
+            authority: signer(authority),
+            global_state: leaked(&global_state),
+        };
+        let mut set = |per_loss, max| {
+            let ctx = Context::new(&crate::ID, &mut accounts, &[], UpdateConfigBumps::default());
+            seek_protocol::set_loss_streak_boost(ctx, per_loss, max)
+        };
+        let invalid = Some(6000 + SeekError::InvalidConfig as u32);
+        assert_eq!(error_code(set(100, MAX_LOSS_STREAK_BOOST_BPS + 1)), invalid);
+        assert_eq!(error_code(set(600, 500)), invalid);
+        set(1000, 3000).unwrap();
+
+        let odds = |streak| accounts.global_state.jackpot_odds(streak).unwrap();
+        assert_eq!(odds(0), SINGULARITY_ODDS);
+        assert_eq!(odds(1), SINGULARITY_ODDS * 9 / 10);
+        assert_eq!(odds(3), SINGULARITY_ODDS * 7 / 10);
+        assert_eq!(odds(50), SINGULARITY_ODDS * 7 / 10, "capped at max_bps");
+
+        let mut stats: PlayerStats = zeroed(PlayerStats::SIZE);
+        stats.record_result(false, 0).unwrap();
+        stats.record_result(false, 0).unwrap();
+        assert_eq!(stats.loss_streak, 2);
+        stats.record_result(true, 3_000).unwrap();
+        assert_eq!(stats.loss_streak, 0, "a win resets the streak");
+    }
+
+    #[test]
+    fn entry_amounts_scale_with_the_recorded_mint_decimals() {
+        let mut global_state = open_global_state();
+        let six = global_state.decimals_multiplier();
+        assert_eq!(validate_entry_amount(2_000_000_000, six).unwrap(), 2);
+        assert_eq!(
+            error_code(validate_entry_amount(2_000_000_000_000, six)),
+            Some(6000 + SeekError::InvalidEntryAmount as u32),
+            "a 9-decimal entry is 1000x off"
+        );
+        assert_eq!(global_state.whole_skr(3_000_000_999), 3_000);
+
+        global_state.skr_decimals = 9;
+        let nine = global_state.decimals_multiplier();
+        assert_eq!(validate_entry_amount(2_000_000_000_000, nine).unwrap(), 2);
+        assert_eq!(get_tier_entry(3, nine).unwrap(), 3_000 * 1_000_000_000);
+
+        global_state.skr_decimals = MAX_SKR_DECIMALS;
+        let widest = global_state.decimals_multiplier();
+        assert!(get_tier_entry(3, widest).is_ok(), "the largest entry fits at MAX_SKR_DECIMALS");
+    }
+
+    #[test]
+    fn low_house_wins_top_up_from_a_delegated_treasury() {
+        use anchor_lang::solana_program::{program_option::COption, program_pack::Pack};
+        use anchor_spl::token::spl_token::state::{Account as SplAccount, AccountState};
+
+        install_stubs();
+
+        // The treasury as the global_state PDA's delegate for `allowance`
+        let treasury = |delegate: Pubkey, allowance: u64| {
+            let mut data = vec![0; SplAccount::LEN];
+            SplAccount {
+                mint: SKR_MINT,
+                owner: Pubkey::new_unique(),
+                amount: 100_000,
+                delegate: COption::Some(delegate),
+                delegated_amount: allowance,
+                state: AccountState::Initialized,
+                ..SplAccount::default()
+            }
+            .pack_into_slice(&mut data);
+            let info = leak(Slot::at(Pubkey::new_unique(), token::ID, data));
+            Box::new(Account::<TokenAccount>::try_from(pin(info)).unwrap())
+        };
+        let settle_win = |allowance: u64| {
+            let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+            global_state.house_fund_balance = 10_000;
+            global_state.auto_fund_house = true;
+            global_state.auto_fund_threshold = 50_000;
+            global_state.auto_fund_amount = 20_000;
+            let global_state = leaked(&global_state);
+            let bounty = proposed_win(&global_state);
+            let mut accounts = finalize_accounts(global_state, bounty);
+            accounts.protocol_treasury = treasury(accounts.global_state.key(), allowance);
+            let transfers_before = transfers().len();
+            finalize(&mut accounts).unwrap();
+            let moved = transfers()[transfers_before..].to_vec();
+            (moved, accounts)
+        };
+
+        let (moved, accounts) = settle_win(20_000);
+        assert_eq!(moved[0], (accounts.house_vault.key(), 20_000), "top-up before the payout");
+        assert_eq!(accounts.global_state.house_fund_balance, 10_000 + 20_000 - 2_000);
+        let funded = emitted::<HouseAutoFunded>().pop().unwrap();
+        assert_eq!((funded.amount, funded.new_balance), (20_000, 30_000));
+
+        // Allowance short of auto_fund_amount: the win still pays, unfunded
+        let (moved, accounts) = settle_win(19_999);
+        assert_eq!(moved.len(), 1, "payout transfer only");
+        assert_eq!(accounts.global_state.house_fund_balance, 10_000 - 2_000);
+
+        // Above the threshold, or turned off, nothing is read or moved
+        let mut global_state = open_global_state();
+        global_state.auto_fund_amount = 20_000;
+        global_state.auto_fund_threshold = global_state.house_fund_balance;
+        global_state.auto_fund_house = true;
+        let info = leak(Slot::new(crate::ID, Vec::new()));
+        assert_eq!(auto_fund_house(&mut global_state, &vaults(&info)).unwrap(), 0);
+        global_state.auto_fund_threshold = u64::MAX;
+        global_state.auto_fund_house = false;
+        assert_eq!(auto_fund_house(&mut global_state, &vaults(&info)).unwrap(), 0);
+    }
+
+    #[test]
+    fn verify_win_reports_only_settled_real_wins() {
+        install_stubs();
+
+        let verify = |global_state: &Account<'static, GlobalState>, bounty: &Bounty| {
+            let mut accounts = VerifyWin {
+                global_state: global_state.clone(),
+                bounty: *leaked(bounty),
+            };
+            seek_protocol::verify_win(Context::new(
+                &crate::ID,
+                &mut accounts,
+                &[],
+                VerifyWinBumps::default(),
+            ))
+        };
+
+        let global_state = leaked(&open_global_state());
+        let mut accounts = finalize_accounts(global_state.clone(), proposed_win(&global_state));
+        assert_eq!(
+            error_code(verify(&global_state, &accounts.bounty)),
+            Some(6000 + SeekError::BountyNotFinalized as u32),
+            "still in its challenge period"
+        );
+        finalize(&mut accounts).unwrap();
+        let proof = verify(&global_state, &accounts.bounty).unwrap();
+        assert_eq!(
+            proof,
+            WinProof { is_won: true, payout: 2_000, singularity_won: false, finalized_at: NOW }
+        );
+
+        let mut practice: Bounty = (**accounts.bounty).clone();
+        practice.practice = true;
+        assert!(!verify(&global_state, &practice).unwrap().is_won, "practice wins don't count");
+
+        let mut lost = practice;
+        lost.practice = false;
+        lost.status = BountyStatus::Lost;
+        assert_eq!(verify(&global_state, &lost).unwrap().payout, 0);
+    }
+
+    #[test]
+    fn accepts_stop_at_the_protocol_wide_active_cap() {
+        let mut global_state = open_global_state();
+        global_state.max_active_global = 2;
+        global_state.active_bounties = 2;
+
+        let (result, _) = accept(&global_state, TIER_1_ENTRY);
+        assert_eq!(error_code(result), Some(6000 + SeekError::ProtocolAtCapacity as u32));
+        assert_eq!(
+            can_accept_tier(&global_state, 1).reason_code,
+            AcceptBlockReason::ProtocolAtCapacity as u8
+        );
+
+        // A settle frees a slot
+        global_state.active_bounties = 1;
+        let (result, accounts) = accept(&global_state, TIER_1_ENTRY);
+        result.unwrap();
+        assert!(accounts.global_state.at_capacity(), "the accept took the last slot");
+
+        global_state.max_active_global = 0;
+        global_state.active_bounties = u64::MAX - 1;
+        assert!(!global_state.at_capacity(), "0 = uncapped");
+    }
+
+    #[test]
+    fn closes_refund_rent_to_whoever_paid_it_at_accept() {
+        install_stubs();
+
+        let (result, accepted) = accept(&open_global_state(), TIER_1_ENTRY);
+        result.unwrap();
+        assert_eq!(accepted.bounty.rent_payer, accepted.rent_payer.key());
+
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = Pubkey::new_unique();
+        bounty.status = BountyStatus::Lost;
+        bounty.created_at = NOW - CLOSE_COOLDOWN;
+        assert_eq!(bounty.rent_recipient(), bounty.player, "bounties from before rent_payer");
+        let relayer = Pubkey::new_unique();
+        bounty.rent_payer = relayer;
+        assert_eq!(bounty.rent_recipient(), relayer);
+
+        let wallet = |key: Pubkey| leak(Slot::at(key, system_program::ID, Vec::new()));
+        let close = |to: Pubkey| -> Result<&'static [AccountInfo<'static>]> {
+            let mut player = signer(bounty.player).to_account_info();
+            player.is_writable = true;
+            let infos = vec![player, wallet(to), leak(Slot::of(&bounty))];
+            let infos: &'static [AccountInfo<'static>] = infos.leak();
+            let mut accounts = CloseBounty::try_accounts(
+                &crate::ID,
+                &mut &infos[..],
+                &[],
+                &mut CloseBountyBumps::default(),
+                &mut std::collections::BTreeSet::new(),
+            )?;
+            seek_protocol::close_bounty(Context::new(
+                &crate::ID,
+                &mut accounts,
+                &[],
+                CloseBountyBumps::default(),
+            ))?;
+            accounts.exit(&crate::ID)?;
+            Ok(infos)
+        };
+        let unauthorized = Some(6000 + SeekError::Unauthorized as u32);
+        assert_eq!(error_code(close(bounty.player)), unauthorized, "the player didn't pay");
+        let infos = close(relayer).unwrap();
+        assert_eq!((infos[1].lamports(), infos[2].lamports()), (2_000_000, 0));
+
+        // The batch pins each pair's second account the same way
+        let batch = |to: Pubkey| {
+            let items: &'static [AccountInfo<'static>] =
+                vec![leak(Slot::of(&bounty)), wallet(to)].leak();
+            let mut accounts = CloseBountiesBatch { caller: signer(Pubkey::new_unique()) };
+            let result = seek_protocol::close_bounties_batch(Context::new(
+                &crate::ID,
+                &mut accounts,
+                items,
+                CloseBountiesBatchBumps::default(),
+            ));
+            (result, items)
+        };
+        assert_eq!(error_code(batch(bounty.player).0), unauthorized);
+        let (result, items) = batch(relayer);
+        result.unwrap();
+        assert_eq!(items[1].lamports(), 2_000_000);
+    }
+
+    #[test]
+    fn clock_skew_grace_widens_the_hunt_timer_and_pulls_in_finalize() {
+        install_stubs();
+
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.expires_at = NOW;
+        assert!(bounty.timer_running(NOW + CLOCK_SKEW_GRACE));
+        assert!(!bounty.timer_running(NOW + CLOCK_SKEW_GRACE + 1));
+
+        let mut global_state = open_global_state();
+        global_state.finalize_grace = 60;
+        bounty.challenge_ends_at = NOW;
+        bounty.status = BountyStatus::ChallengeWon;
+        assert_eq!(finalize_deadline(&bounty, &global_state).unwrap(), NOW - CLOCK_SKEW_GRACE);
+        bounty.status = BountyStatus::ChallengeLost;
+        bounty.resolved_at = NOW - CHALLENGE_PERIOD;
+        assert_eq!(finalize_deadline(&bounty, &global_state).unwrap(), NOW + 60 - CLOCK_SKEW_GRACE);
+        bounty.resolved_at = NOW + 60;
+        assert_eq!(
+            finalize_deadline(&bounty, &global_state).unwrap(),
+            NOW + 60 + MIN_CHALLENGE_PERIOD,
+            "the floor keeps its full length"
+        );
+
+        // A win whose challenge period ends within the grace finalizes now
+        let global_state = leaked(&global_state);
+        let mut win = proposed_win(&global_state);
+        win.challenge_ends_at = NOW + CLOCK_SKEW_GRACE;
+        finalize(&mut finalize_accounts(global_state.clone(), win.clone())).unwrap();
+        win.challenge_ends_at += 1;
+        assert_eq!(
+            error_code(finalize(&mut finalize_accounts(global_state, win))),
+            Some(6000 + SeekError::ChallengePeriodActive as u32)
+        );
+    }
+
+    #[test]
+    fn advance_epoch_reports_the_closing_epochs_own_totals() {
+        let mut global_state = open_global_state();
+        global_state.epoch_started_at = NOW - EPOCH_DURATION;
+        global_state.total_bounties_created = 10;
+        global_state.total_bounties_won = 3;
+        global_state.total_burned = 500;
+        global_state.epoch_opening_totals = EpochTotals {
+            bounties_created: 4,
+            bounties_won: 1,
+            burned: 200,
+            ..EpochTotals::default()
+        };
+
+        let (result, accounts) = advance_epoch(&global_state);
+        result.unwrap();
+        let advanced = emitted::<EpochAdvanced>().pop().unwrap();
+        assert_eq!(
+            advanced.last_epoch,
+            EpochTotals { bounties_created: 6, bounties_won: 2, burned: 300, ..EpochTotals::default() }
+        );
+        assert_eq!(
+            accounts.global_state.epoch_opening_totals,
+            global_state.lifetime_totals(),
+            "the next epoch opens at the lifetime counters"
+        );
+
+        // Opening totals ahead of the lifetime ones can only be corruption
+        global_state.epoch_opening_totals.burned = 501;
+        assert_eq!(
+            error_code(advance_epoch(&global_state).0),
+            Some(6000 + SeekError::MathOverflow as u32)
+        );
+    }
+
+    #[test]
+    fn a_tier_dispute_stake_overrides_the_default_share() {
+        install_stubs();
+
+        let authority = Pubkey::new_unique();
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.authority = authority;
+        global_state.house_fund_balance = 1_000_000;
+        let mut accounts = UpdateConfig {
+            authority: signer(authority),
+            global_state: leaked(&global_state),
+        };
+        let mut set = |tier, stake_bps| {
+            let ctx = Context::new(&crate::ID, &mut accounts, &[], UpdateConfigBumps::default());
+            seek_protocol::set_tier_dispute_stake(ctx, tier, stake_bps)
+        };
+        let invalid = Some(6000 + SeekError::InvalidConfig as u32);
+        assert_eq!(error_code(set(4, 2_000)), invalid);
+        assert_eq!(error_code(set(1, 10_001)), invalid);
+        set(1, 2_000).unwrap();
+        assert_eq!(accounts.global_state.dispute_stake_bps(1).unwrap(), 2_000);
+        assert_eq!(accounts.global_state.dispute_stake_bps(2).unwrap(), DISPUTE_STAKE_BPS);
+
+        // proposed_loss is a tier 1 bounty with a 1_000 entry
+        let staked = |global_state: &GlobalState| {
+            let before = transfers().len();
+            let (result, _) = dispute(leaked(global_state), leaked(&proposed_loss(60)));
+            result.unwrap();
+            transfers()[before].1
+        };
+        assert_eq!(staked(&accounts.global_state), 200);
+        assert_eq!(staked(&global_state), 500, "unset tiers stake DISPUTE_STAKE_BPS");
+    }
+
+    #[test]
+    fn accepts_draw_each_pooled_commitment_once() {
+        install_stubs();
+
+        let hot_authority = Pubkey::new_unique();
+        let mut global_state = open_global_state();
+        global_state.hot_authority = hot_authority;
+        let publish = |commitments: Vec<[u8; 32]>| {
+            let mut accounts = PublishCommitmentPool {
+                hot_authority: signer(hot_authority),
+                global_state: leaked(&global_state),
+                commitment_pool: leaked(&zeroed::<CommitmentPool>(CommitmentPool::SIZE)),
+                system_program: system_program(),
+            };
+            let result = seek_protocol::publish_commitment_pool(
+                Context::new(
+                    &crate::ID,
+                    &mut accounts,
+                    &[],
+                    PublishCommitmentPoolBumps::default(),
+                ),
+                7,
+                commitments,
+            );
+            (result, accounts.commitment_pool)
+        };
+        let invalid = Some(6000 + SeekError::InvalidCommitmentPool as u32);
+        assert_eq!(error_code(publish(Vec::new()).0), invalid);
+        assert_eq!(error_code(publish(vec![[0u8; 32]; MAX_POOL_COMMITMENTS + 1]).0), invalid);
+        let (result, pool) = publish(vec![[9u8; 32], [1u8; 32]]);
+        result.unwrap();
+        assert_eq!((pool.pool_id, pool.published_at, pool.consumed), (7, NOW, 0));
+
+        // accept_drawing commits to [1u8; 32], the pool's second entry
+        let (result, accepted) = accept_drawing(&global_state, TIER_1_ENTRY, Some(pool));
+        result.unwrap();
+        let pool = accepted.commitment_pool.unwrap();
+        assert_eq!(pool.consumed, 0b10);
+        let drawn = emitted::<CommitmentDrawn>().pop().unwrap();
+        assert_eq!((drawn.bounty, drawn.index), (accepted.bounty.key(), 1));
+
+        let (result, _) = accept_drawing(&global_state, TIER_1_ENTRY, Some(pool));
+        assert_eq!(error_code(result), Some(6000 + SeekError::CommitmentAlreadyDrawn as u32));
+        let (_, elsewhere) = publish(vec![[9u8; 32]]);
+        let (result, _) = accept_drawing(&global_state, TIER_1_ENTRY, Some(elsewhere));
+        assert_eq!(error_code(result), Some(6000 + SeekError::CommitmentNotInPool as u32));
+    }
+
+    #[test]
+    fn finalize_rejects_vaults_of_another_deployment() {
+        let pda = |seed: &[u8]| Pubkey::find_program_address(&[seed], &crate::ID);
+        let (key, _) = pda(b"global_state");
+        let (house_key, house_bump) = pda(b"house_vault");
+        let (singularity_key, singularity_bump) = pda(b"singularity_vault");
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_vault = house_key;
+        global_state.house_vault_bump = house_bump;
+        global_state.singularity_vault = singularity_key;
+        global_state.singularity_vault_bump = singularity_bump;
+        global_state.protocol_treasury = Pubkey::new_unique();
+
+        let player = Pubkey::new_unique();
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = player;
+        bounty.global_state = key;
+        let at = |key: Pubkey, owner: Pubkey| {
+            let info = token_account(key, owner, 1_000_000);
+            Box::new(Account::<TokenAccount>::try_from(pin(info)).unwrap())
+        };
+        let mut accounts = finalize_accounts(leaked(&global_state), leaked(&bounty));
+        accounts.player_token_account =
+            Some(at(get_associated_token_address(&player, &SKR_MINT), player));
+        accounts.house_vault = at(house_key, key);
+        accounts.singularity_vault = at(singularity_key, key);
+        accounts.protocol_treasury = at(global_state.protocol_treasury, Pubkey::new_unique());
+        check_finalize_accounts(&accounts).unwrap();
+
+        let invalid = Some(6000 + SeekError::InvalidVault as u32);
+        let foreign = Pubkey::new_unique();
+        let house = std::mem::replace(&mut accounts.house_vault, at(house_key, foreign));
+        assert_eq!(error_code(check_finalize_accounts(&accounts)), invalid, "foreign-owned house");
+        accounts.house_vault = house;
+        accounts.singularity_vault = at(singularity_key, foreign);
+        assert_eq!(error_code(check_finalize_accounts(&accounts)), invalid);
+        accounts.singularity_vault = at(singularity_key, key);
+        accounts.protocol_treasury = at(Pubkey::new_unique(), key);
+        assert_eq!(error_code(check_finalize_accounts(&accounts)), invalid, "unconfigured treasury");
+    }
+
+    #[test]
+    fn a_pool_below_the_roll_minimum_skips_the_roll() {
+        install_stubs();
+
+        let authority = Pubkey::new_unique();
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.authority = authority;
+        global_state.singularity_balance = 9_999;
+        let mut accounts = UpdateConfig {
+            authority: signer(authority),
+            global_state: leaked(&global_state),
+        };
+        seek_protocol::set_min_jackpot_roll_balance(
+            Context::new(&crate::ID, &mut accounts, &[], UpdateConfigBumps::default()),
+            10_000,
+        )
+        .unwrap();
+
+        let mut global_state = (**accounts.global_state).clone();
+        let rolls_before = emitted::<SingularityRoll>().len();
+        assert_eq!(roll_winning_jackpot(&mut global_state, false).unwrap(), 0);
+        assert_eq!(emitted::<SingularityRoll>().len(), rolls_before, "not even rolled");
+        assert_eq!(global_state.singularity_balance, 9_999);
+
+        global_state.singularity_balance = 10_000;
+        assert_eq!(roll_winning_jackpot(&mut global_state, false).unwrap(), 10_000);
+    }
+
+    #[test]
+    fn max_duration_and_commitment_ttl_hold_each_other_in_either_order() {
+        let authority = Pubkey::new_unique();
+        let mut global_state = open_global_state();
+        global_state.authority = authority;
+        global_state.commitment_ttl = COMMITMENT_TTL;
+        let mut accounts = UpdateConfig {
+            authority: signer(authority),
+            global_state: leaked(&global_state),
+        };
+        fn ctx<'a>(
+            accounts: &'a mut UpdateConfig<'static>,
+        ) -> Context<'a, 'a, 'a, 'static, UpdateConfig<'static>> {
+            Context::new(&crate::ID, accounts, &[], UpdateConfigBumps::default())
+        }
+        let invalid = Some(6000 + SeekError::InvalidConfig as u32);
+
+        assert_eq!(
+            error_code(seek_protocol::set_max_duration(ctx(&mut accounts), COMMITMENT_TTL + 1)),
+            invalid
+        );
+        seek_protocol::set_max_duration(ctx(&mut accounts), COMMITMENT_TTL).unwrap();
+        assert_eq!(
+            error_code(seek_protocol::set_commitment_ttl(ctx(&mut accounts), COMMITMENT_TTL - 1)),
+            invalid
+        );
+        assert_eq!(accounts.global_state.max_duration, COMMITMENT_TTL);
+    }
+
+    #[test]
+    fn close_bounty_refuses_bounties_that_are_not_terminal() {
+        for (status, closable) in [
+            (BountyStatus::Pending, false),
+            (BountyStatus::Disputed, false),
+            (BountyStatus::ChallengeLost, false),
+            (BountyStatus::Cancelled, true),
+        ] {
+            let mut bounty: Bounty = zeroed(Bounty::SIZE);
+            bounty.player = Pubkey::new_unique();
+            bounty.status = status;
+            let mut player = signer(bounty.player).to_account_info();
+            player.is_writable = true;
+            let infos: &'static [AccountInfo<'static>] = vec![
+                player,
+                leak(Slot::at(bounty.player, system_program::ID, Vec::new())),
+                leak(Slot::of(&bounty)),
+            ]
+            .leak();
+            let result = CloseBounty::try_accounts(
+                &crate::ID,
+                &mut &infos[..],
+                &[],
+                &mut CloseBountyBumps::default(),
+                &mut std::collections::BTreeSet::new(),
+            );
+            let expected = (!closable).then_some(6000 + SeekError::ExpectedTerminal as u32);
+            assert_eq!(error_code(result), expected);
+        }
+    }
+
+    #[test]
+    fn dispute_wins_count_only_the_bonus_against_the_epoch() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 1_000_000;
+        global_state.dispute_win_bonus_bps = 500;
+        global_state.house_net_this_epoch = -4_000;
+        let win = DisputeRuling { player_wins: true, reason_code: 0 };
+
+        // Entry 1_000 and stake 500 back, plus a 5% bonus the house pays
+        resolve(&mut global_state, win).0.unwrap();
+        assert_eq!(global_state.house_net_this_epoch, -4_050);
+
+        global_state.dispute_win_bonus_bps = 10_000;
+        global_state.max_epoch_house_loss = 5_000;
+        resolve(&mut global_state, win).0.unwrap();
+        assert!(global_state.epoch_loss_cap_reached(), "a full-entry bonus trips the breaker");
+    }
+
+    #[test]
+    fn sweep_action_leaves_wins_to_the_player_while_claims_are_required() {
+        let now = NOW;
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.finalize_grace = 60;
+
+        let proposed = |status, proposed_win| {
+            let mut b: Bounty = zeroed(Bounty::SIZE);
+            b.status = status;
+            b.proposed_win = proposed_win;
+            b.resolved_at = now - 1_000;
+            b.challenge_ends_at = now - 500;
+            b
+        };
+        let pending = |expires_at| {
+            let mut b: Bounty = zeroed(Bounty::SIZE);
+            b.status = BountyStatus::Pending;
+            b.expires_at = expires_at;
+            b
+        };
+        let mut disputed = proposed(BountyStatus::Disputed, false);
+        disputed.is_disputed = true;
+        let mut in_period = proposed(BountyStatus::ChallengeLost, false);
+        in_period.challenge_ends_at = now;
+        let won = proposed(BountyStatus::Won, true);
+
+        let batch = [
+            proposed(BountyStatus::ChallengeWon, true),
+            proposed(BountyStatus::ChallengeLost, false),
+            pending(now - EXPIRED_REFUND_GRACE - 1),
+            pending(now - 10),
+            in_period,
+            disputed,
+            won,
+        ];
+        let route = |gs: &GlobalState| -> Vec<SweepAction> {
+            batch.iter().map(|b| sweep_action(b, gs, now).unwrap()).collect()
+        };
+
+        use SweepAction::*;
+        assert_eq!(route(&global_state), [Settle, Settle, Refund, Skip, Skip, Skip, Skip]);
+
+        // Wins wait for finalize_my_wins while claims are required; losses
+        // still settle
+        global_state.player_claim_required = true;
+        assert_eq!(route(&global_state), [Skip, Settle, Refund, Skip, Skip, Skip, Skip]);
+    }
+
+    /// A won dispute on a 1000 entry with a 500 stake: refund 1500.
+    fn settle_won_dispute(
+        global_state: &mut GlobalState,
+        prefunded: bool,
+        house_vault_amount: u64,
+    ) -> Result<()> {
+        install_stubs();
+
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.status = BountyStatus::Disputed;
+        bounty.is_disputed = true;
+        bounty.entry_amount = 1_000;
+        bounty.payout_amount = 3_000;
+        bounty.dispute_stake = 500;
+        bounty.prefunded = prefunded;
+        let mut stats: PlayerStats = zeroed(PlayerStats::SIZE);
+
+        let mut bounty_slot = Slot::of(&bounty);
+        let mut vault_slot = Slot::new(token::ID, Vec::new());
+        let vault = vault_slot.info();
+        let bounty_info = bounty_slot.info();
+        let mut bounty = Account::<Bounty>::try_from(&bounty_info).unwrap();
+
+        settle_dispute(
+            global_state,
+            &mut bounty,
+            DisputeRuling { player_wins: true, reason_code: 0 },
+            house_vault_amount,
+            &vaults(&vault),
+            &vault,
+            &mut stats,
+        )
+    }
+
+    #[test]
+    fn dispute_refund_leaves_other_reservations_alone() {
+        let insufficient = Some(6000 + SeekError::InsufficientHouseFunds as u32);
+
+        // Another bounty's 3000 payout and 200 of escrowed rebates are
+        // spoken for: 4500 in the vault leaves only 1300 free
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.outstanding_liability = 3_000;
+        global_state.loyalty_escrowed = 200;
+        global_state.house_fund_balance = 4_500;
+        let cpis_before = cpi_calls();
+        assert_eq!(error_code(settle_won_dispute(&mut global_state, false, 4_500)), insufficient);
+        assert_eq!(cpi_calls(), cpis_before, "nothing paid out");
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.outstanding_liability = 3_000;
+        global_state.loyalty_escrowed = 200;
+        global_state.house_fund_balance = 4_700;
+        settle_won_dispute(&mut global_state, false, 4_700).unwrap();
+        assert_eq!(global_state.house_fund_balance, 3_200);
+        assert_eq!(global_state.outstanding_liability, 3_000, "reservation intact");
+    }
+
+    #[test]
+    fn prefunded_dispute_refund_draws_on_its_own_reservation() {
+        // The only reservation is the disputed bounty's own, released on
+        // settlement, so the whole vault backs the refund
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.outstanding_liability = 3_000;
+        global_state.house_fund_balance = 3_000;
+        global_state.active_bounties = 1;
+        settle_won_dispute(&mut global_state, true, 3_000).unwrap();
+        assert_eq!(global_state.outstanding_liability, 0);
+        assert_eq!(global_state.house_fund_balance, 1_500);
+        assert_eq!(global_state.active_bounties, 0);
+    }
+
+    #[test]
+    fn unreserved_nets_out_reservations_and_escrow() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        assert_eq!(global_state.unreserved(5_000), 5_000);
+        global_state.outstanding_liability = 3_000;
+        global_state.loyalty_escrowed = 500;
+        assert_eq!(global_state.unreserved(5_000), 1_500);
+        assert_eq!(global_state.unreserved(3_200), 0, "saturates");
+    }
+
+    /// `[dispute_share, disputer_token_account, rent_to]` for one 300 SKR
+    /// co-dispute of `bounty_key`, followed by a vault account. Leaked, as
+    /// settle_co_disputers borrows its accounts for 'info.
+    fn co_disputer_accounts(
+        bounty_key: Pubkey,
+        disputer: Pubkey,
+        rent_to: Pubkey,
+    ) -> &'static [AccountInfo<'static>] {
+        let share = DisputeShare { bounty: bounty_key, disputer, stake: 300, bump: 255 };
+        let mut share_data = Vec::new();
+        share.try_serialize(&mut share_data).unwrap();
+
+        let slots: &'static mut [Slot] = Box::leak(Box::new([
+            Slot::at(Pubkey::new_unique(), crate::ID, share_data),
+            Slot::at(get_associated_token_address(&disputer, &SKR_MINT), token::ID, Vec::new()),
+            Slot::at(rent_to, system_program::ID, Vec::new()),
+            Slot::new(token::ID, Vec::new()),
+        ]));
+        Box::leak(slots.iter_mut().map(|s| s.info()).collect::<Vec<_>>().into_boxed_slice())
+    }
+
+    fn co_disputed_bounty() -> Bounty {
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.status = BountyStatus::Disputed;
+        bounty.entry_amount = 1_000;
+        bounty.dispute_stake = 500;
+        bounty.co_dispute_stake = 300;
+        bounty.co_disputers = 1;
+        bounty
+    }
+
+    #[test]
+    fn pause_reclaim_refunds_co_disputers_and_closes_their_shares() {
+        install_stubs();
+
+        let bounty = co_disputed_bounty();
+        let bounty_key = Pubkey::new_unique();
+        let disputer = Pubkey::new_unique();
+        let accounts = co_disputer_accounts(bounty_key, disputer, disputer);
+        let share_rent = accounts[0].lamports();
+        let disputer_lamports = accounts[2].lamports();
+
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 10_000;
+        let cpis_before = cpi_calls();
+
+        settle_co_disputers(
+            &mut global_state,
+            &bounty,
+            bounty_key,
+            CoDisputeOutcome::Refunded,
+            &vaults(&accounts[3]),
+            &accounts[..3],
+        )
+        .unwrap();
+
+        assert_eq!(cpi_calls(), cpis_before + 1, "one stake transfer");
+        assert_eq!(global_state.house_fund_balance, 9_700, "stake back in full");
+        assert_eq!(accounts[0].lamports(), 0);
+        assert_eq!(accounts[2].lamports(), disputer_lamports + share_rent, "rent back to the disputer");
+        assert!(accounts[0].data_is_empty() && accounts[0].owner == &system_program::ID);
+    }
+
+    #[test]
+    fn co_disputer_settlement_requires_every_share_with_its_disputer() {
+        install_stubs();
+
+        let bounty = co_disputed_bounty();
+        let bounty_key = Pubkey::new_unique();
+        let accounts =
+            co_disputer_accounts(bounty_key, Pubkey::new_unique(), Pubkey::new_unique());
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+
+        let mut settle = |co_disputers: &'static [AccountInfo<'static>]| {
+            settle_co_disputers(
+                &mut global_state,
+                &bounty,
+                bounty_key,
+                CoDisputeOutcome::Won,
+                &vaults(&accounts[3]),
+                co_disputers,
+            )
+        };
+        assert_eq!(
+            error_code(settle(&accounts[..2])),
+            Some(6000 + SeekError::CoDisputersMismatch as u32),
+            "the old [share, ata] pair is rejected"
+        );
+        assert_eq!(
+            error_code(settle(&accounts[..3])),
+            Some(6000 + SeekError::Unauthorized as u32),
+            "rent can't be redirected away from the disputer"
+        );
+    }
+    /// A never-initialized PDA at `seeds`: system-owned, empty, no lamports.
+    fn fresh(seeds: &[&[u8]]) -> (Pubkey, AccountInfo<'static>) {
+        let (key, _) = Pubkey::find_program_address(seeds, &crate::ID);
+        let mut slot = Slot::at(key, system_program::ID, Vec::new());
+        slot.lamports = 0;
+        (key, leak(slot))
+    }
+
+    #[test]
+    fn a_relayer_pays_for_the_players_first_accept_accounts() {
+        install_stubs();
+        let player = Pubkey::new_unique();
+        let relayer = Pubkey::new_unique();
+        let timestamp = NOW;
+
+        let (global_state_key, bump) = Pubkey::find_program_address(&[b"global_state"], &crate::ID);
+        let (house_key, house_bump) = Pubkey::find_program_address(&[b"house_vault"], &crate::ID);
+        let mut global_state = open_global_state();
+        global_state.bump = bump;
+        global_state.house_vault = house_key;
+        global_state.house_vault_bump = house_bump;
+        let mut data = Vec::new();
+        global_state.try_serialize(&mut data).unwrap();
+
+        let (bounty_key, bounty) = fresh(&[b"bounty", player.as_ref(), &timestamp.to_le_bytes()]);
+        let (stats_key, stats) = fresh(&[b"player_stats", player.as_ref()]);
+        let (escrow_key, escrow) = fresh(&[b"loyalty_escrow", player.as_ref()]);
+
+        let infos: &'static [AccountInfo<'static>] = vec![
+            signer(player).to_account_info(),
+            signer(relayer).to_account_info(),
+            leak(Slot::at(global_state_key, crate::ID, data)),
+            bounty,
+            stats,
+            escrow,
+            token_account(get_associated_token_address(&player, &SKR_MINT), player, TIER_1_ENTRY),
+            token_account(house_key, house_key, 1_000_000),
+            skr_mint().to_account_info(),
+            leak(Slot::at(
+                anchor_lang::solana_program::sysvar::slot_hashes::ID,
+                Pubkey::default(),
+                Vec::new(),
+            )),
+            system_program().to_account_info(),
+            token_program().to_account_info(),
+            // An absent optional account is passed as the program id
+            leak(Slot::at(crate::ID, Pubkey::default(), Vec::new())),
+        ]
+        .leak();
+        let mut ix_data = TIER_1_ENTRY.to_le_bytes().to_vec();
+        ix_data.extend(timestamp.to_le_bytes());
+
+        let before = created().len();
+        AcceptBounty::try_accounts(
+            &crate::ID,
+            &mut &infos[..],
+            &ix_data,
+            &mut AcceptBountyBumps::default(),
+            &mut std::collections::BTreeSet::new(),
+        )
+        .unwrap();
+        assert_eq!(
+            created()[before..],
+            [(relayer, bounty_key), (relayer, stats_key), (relayer, escrow_key)],
+            "the player, who signs, pays for nothing"
+        );
+    }
+
+    #[test]
+    fn wind_down_waits_for_shutdown_open_bounties_and_rebates() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.active_bounties = 1;
+        global_state.loyalty_escrowed = 50;
+        let code = |g: &GlobalState| error_code(require_wound_down(g));
+
+        assert_eq!(code(&global_state), Some(6000 + SeekError::ProtocolNotShutdown as u32));
+        global_state.shutdown = true;
+        assert_eq!(code(&global_state), Some(6000 + SeekError::BountiesStillOpen as u32));
+        global_state.active_bounties = 0;
+        assert_eq!(code(&global_state), Some(6000 + SeekError::LoyaltyStillEscrowed as u32));
+        global_state.loyalty_escrowed = 0;
+        assert!(require_wound_down(&global_state).is_ok());
+    }
+
+    #[test]
+    fn sweep_empties_and_closes_both_vaults() {
+        install_stubs();
+
+        let mut slot = Slot::new(token::ID, vec![0; TokenAccount::LEN]);
+        let info = slot.info();
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 4_000;
+        let cpis_before = cpi_calls();
+
+        // 5_000 live in the house vault against 4_000 tracked: the dust goes too
+        sweep_vaults_out(&mut global_state, &vaults(&info), 5_000, 0, &info, &info).unwrap();
+
+        assert_eq!(cpi_calls(), cpis_before + 3, "house transfer, then both closes");
+        assert_eq!((global_state.house_fund_balance, global_state.singularity_balance), (0, 0));
+    }
+
+    #[test]
+    fn close_protocol_waits_for_both_vaults_to_be_closed() {
+        let mut open = Slot::new(token::ID, vec![0; TokenAccount::LEN]);
+        let mut reassigned = Slot::new(system_program::ID, Vec::new());
+        let mut drained = Slot::new(token::ID, vec![0; TokenAccount::LEN]);
+        drained.lamports = 0;
+        let (open, reassigned, drained) = (open.info(), reassigned.info(), drained.info());
+
+        assert_eq!(
+            error_code(require_vaults_closed(&open, &reassigned)),
+            Some(6000 + SeekError::VaultsNotEmpty as u32),
+            "a live house vault blocks the close"
+        );
+        assert_eq!(
+            error_code(require_vaults_closed(&drained, &open)),
+            Some(6000 + SeekError::VaultsNotEmpty as u32),
+            "so does a live singularity vault"
+        );
+        assert!(require_vaults_closed(&reassigned, &drained).is_ok());
+    }
+    /// Run ProposeResolution's account checks with no player token account
+    /// passed, global_state and the vaults re-homed at their PDAs.
+    fn propose_accounts_without_ata(
+        global_state: &GlobalState,
+        bounty: &Bounty,
+    ) -> Result<ProposeResolution<'static>> {
+        let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &crate::ID);
+        let (key, bump) = pda(&[b"global_state"]);
+        let (house_key, house_bump) = pda(&[b"house_vault"]);
+        let (singularity_key, singularity_bump) = pda(&[b"singularity_vault"]);
+        let (stats_key, stats_bump) = pda(&[b"player_stats", bounty.player.as_ref()]);
+        let treasury_key = Pubkey::new_unique();
+
+        let mut global_state = global_state.clone();
+        global_state.bump = bump;
+        global_state.house_vault = house_key;
+        global_state.house_vault_bump = house_bump;
+        global_state.singularity_vault = singularity_key;
+        global_state.singularity_vault_bump = singularity_bump;
+        global_state.protocol_treasury = treasury_key;
+        let mut data = Vec::new();
+        global_state.try_serialize(&mut data).unwrap();
+        let mut bounty = bounty.clone();
+        bounty.global_state = key;
+        let mut player_stats: PlayerStats = zeroed(PlayerStats::SIZE);
+        player_stats.bump = stats_bump;
+        let mut stats_data = Vec::new();
+        player_stats.try_serialize(&mut stats_data).unwrap();
+
+        // An absent optional account is passed as the program id
+        let absent = || leak(Slot::at(crate::ID, Pubkey::default(), Vec::new()));
+        let infos: &'static [AccountInfo<'static>] = vec![
+            signer(global_state.hot_authority).to_account_info(),
+            leak(Slot::at(key, crate::ID, data)),
+            leak(Slot::of(&bounty)),
+            leak(Slot::at(stats_key, crate::ID, stats_data)),
+            absent(),
+            absent(),
+            absent(),
+            token_account(house_key, house_key, 1_000_000),
+            token_account(singularity_key, singularity_key, 0),
+            token_account(treasury_key, Pubkey::new_unique(), 0),
+            absent(),
+            skr_mint().to_account_info(),
+            token_program().to_account_info(),
+        ]
+        .leak();
+        ProposeResolution::try_accounts(
+            &crate::ID,
+            &mut &infos[..],
+            &[],
+            &mut ProposeResolutionBumps::default(),
+            &mut std::collections::BTreeSet::new(),
+        )
+    }
+
+    #[test]
+    fn practice_proposals_need_no_player_token_account() {
+        install_stubs();
+
+        let mut global_state = open_global_state();
+        global_state.fast_resolve_tiers = 0b001;
+        let mut bounty: Bounty = zeroed(Bounty::SIZE);
+        bounty.player = Pubkey::new_unique();
+        bounty.tier = 1;
+        bounty.status = BountyStatus::Submitted;
+        bounty.mission_revealed = true;
+        bounty.practice = true;
+        bounty.dispute_disabled = true;
+
+        let mut accounts = propose_accounts_without_ata(&global_state, &bounty).unwrap();
+        assert!(accounts.player_token_account.is_none());
+        let calls = cpi_calls();
+        seek_protocol::propose_resolution(
+            Context::new(&crate::ID, &mut accounts, &[], ProposeResolutionBumps::default()),
+            true,
+        )
+        .unwrap();
+        assert!(accounts.bounty.status == BountyStatus::Won, "settled at proposal");
+        assert_eq!(cpi_calls(), calls, "no transfer");
+
+        // A real fast-resolved win still needs somewhere to pay
+        bounty.practice = false;
+        bounty.dispute_disabled = false;
+        bounty.entry_amount = 1_000;
+        bounty.payout_amount = 3_000;
+        let mut accounts = propose_accounts_without_ata(&global_state, &bounty).unwrap();
+        let result = seek_protocol::propose_resolution(
+            Context::new(&crate::ID, &mut accounts, &[], ProposeResolutionBumps::default()),
+            true,
+        );
+        assert_eq!(error_code(result), Some(6000 + SeekError::PlayerTokenAccountRequired as u32));
+    }
+
+    #[test]
+    fn a_first_practice_hunt_creates_the_players_stats() {
+        install_stubs();
+        let player = Pubkey::new_unique();
+        let relayer = Pubkey::new_unique();
+        let timestamp = NOW;
+
+        let (global_state_key, bump) = Pubkey::find_program_address(&[b"global_state"], &crate::ID);
+        let mut global_state = open_global_state();
+        global_state.bump = bump;
+        let mut data = Vec::new();
+        global_state.try_serialize(&mut data).unwrap();
+        let (bounty_key, bounty) = fresh(&[b"bounty", player.as_ref(), &timestamp.to_le_bytes()]);
+        let (stats_key, stats) = fresh(&[b"player_stats", player.as_ref()]);
+
+        let infos: &'static [AccountInfo<'static>] = vec![
+            signer(player).to_account_info(),
+            signer(relayer).to_account_info(),
+            leak(Slot::at(global_state_key, crate::ID, data)),
+            bounty,
+            stats,
+            system_program().to_account_info(),
+        ]
+        .leak();
+        let mut ix_data = vec![1u8];
+        ix_data.extend(timestamp.to_le_bytes());
+
+        let before = created().len();
+        let mut bumps = AcceptPracticeBountyBumps::default();
+        let mut accounts = AcceptPracticeBounty::try_accounts(
+            &crate::ID,
+            &mut &infos[..],
+            &ix_data,
+            &mut bumps,
+            &mut std::collections::BTreeSet::new(),
+        )
+        .unwrap();
+        assert_eq!(created()[before..], [(relayer, bounty_key), (relayer, stats_key)]);
+
+        seek_protocol::accept_practice_bounty(
+            Context::new(&crate::ID, &mut accounts, &[], bumps),
+            1,
+            timestamp,
+            [1u8; 32],
+        )
+        .unwrap();
+        let (_, stats_bump) =
+            Pubkey::find_program_address(&[b"player_stats", player.as_ref()], &crate::ID);
+        let stats = &accounts.player_stats;
+        assert_eq!((stats.player, stats.bump), (player, stats_bump));
+    }
+
+    /// escheat_unclaimed_jackpot on a prefunded 2_000 win whose challenge
+    /// period ended `ended_ago` seconds back.
+    fn escheat(
+        global_state: &GlobalState,
+        ended_ago: i64,
+    ) -> (Result<()>, EscheatUnclaimedJackpot<'static>) {
+        install_stubs();
+
+        let global_state = leaked(global_state);
+        let mut bounty = (**proposed_win(&global_state)).clone();
+        bounty.prefunded = true;
+        bounty.challenge_ends_at = NOW - ended_ago + CLOCK_SKEW_GRACE;
+        let mut accounts = EscheatUnclaimedJackpot {
+            authority: signer(global_state.authority),
+            global_state,
+            bounty: leaked(&bounty),
+            house_vault: vault(10_000),
+            singularity_vault: vault(0),
+            protocol_treasury: vault(0),
+            token_program: token_program(),
+        };
+        let result = seek_protocol::escheat_unclaimed_jackpot(Context::new(
+            &crate::ID,
+            &mut accounts,
+            &[],
+            EscheatUnclaimedJackpotBumps::default(),
+        ));
+        (result, accounts)
+    }
+
+    #[test]
+    fn unclaimed_wins_escheat_after_the_timeout() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.house_fund_balance = 10_000;
+        global_state.outstanding_liability = 2_000;
+        global_state.active_bounties = 1;
+        let code = |e: SeekError| Some(6000 + e as u32);
+
+        let (result, _) = escheat(&global_state, ESCHEAT_TIMEOUT);
+        assert_eq!(error_code(result), code(SeekError::ClaimNotRequired), "anyone may finalize");
+
+        global_state.player_claim_required = true;
+        let (result, _) = escheat(&global_state, ESCHEAT_TIMEOUT - 1);
+        assert_eq!(error_code(result), code(SeekError::EscheatTooEarly));
+
+        let transfers_before = transfers().len();
+        let (result, pooled) = escheat(&global_state, ESCHEAT_TIMEOUT);
+        result.unwrap();
+        assert!(pooled.bounty.status == BountyStatus::Lost);
+        assert_eq!(pooled.bounty.finalized_at, NOW);
+        let g = &pooled.global_state;
+        assert_eq!((g.outstanding_liability, g.active_bounties), (0, 0), "reservation released");
+        assert_eq!((g.house_fund_balance, g.singularity_balance), (8_000, 2_000));
+        assert_eq!(transfers()[transfers_before..], [(pooled.singularity_vault.key(), 2_000)]);
+
+        global_state.escheat_policy = EscheatPolicy::SendToTreasury as u8;
+        let transfers_before = transfers().len();
+        let (result, sent) = escheat(&global_state, ESCHEAT_TIMEOUT);
+        result.unwrap();
+        let g = &sent.global_state;
+        assert_eq!((g.house_fund_balance, g.singularity_balance), (8_000, 0));
+        assert_eq!(transfers()[transfers_before..], [(sent.protocol_treasury.key(), 2_000)]);
+        assert!(emitted::<UnclaimedJackpotEscheated>()
+            .iter()
+            .any(|e| e.bounty == sent.bounty.key() && e.policy == 1 && e.amount == 2_000));
+
+        global_state.escheat_policy = 2;
+        let (result, _) = escheat(&global_state, ESCHEAT_TIMEOUT);
+        assert_eq!(error_code(result), code(SeekError::InvalidConfig));
+    }
+
+    #[test]
+    fn the_rate_limit_uses_fixed_windows_so_a_boundary_burst_fits_twice_the_limit() {
+        let mut global_state: GlobalState = zeroed(GlobalState::SIZE);
+        global_state.global_rate_limit = 3;
+        global_state.rate_limit_window = 60;
+        global_state.record_accept_for_rate_limit(NOW).unwrap();
+        global_state.rate_window_count = 0;
+
+        // A full burst in the window's last second, then another right after it rolls
+        for _ in 0..3 {
+            global_state.record_accept_for_rate_limit(NOW + 59).unwrap();
+        }
+        for _ in 0..3 {
+            global_state.record_accept_for_rate_limit(NOW + 60).unwrap();
+        }
+        assert_eq!(
+            error_code(global_state.record_accept_for_rate_limit(NOW + 61)),
+            Some(6000 + SeekError::GlobalRateLimited as u32)
+        );
+    }
+
+    #[test]
+    fn the_default_multiplier_matches_the_documented_payout() {
+        assert_eq!(u64::from(DEFAULT_PAYOUT_MULTIPLIER_BPS), PAYOUT_MULTIPLIER * 10000);
+        assert_eq!(
+            compute_payout(TIER_1_ENTRY, DEFAULT_PAYOUT_MULTIPLIER_BPS).unwrap(),
+            TIER_1_ENTRY * PAYOUT_MULTIPLIER
+        );
+    }
 
-    /// The bounty to close (must be terminal: Won/Lost/Cancelled)
-    #[account(
-        mut,
-        close = player,
-        constraint = bounty.player == player.key() @ SeekError::Unauthorized,
-        constraint = bounty.status == BountyStatus::Won
-            || bounty.status == BountyStatus::Lost
-            || bounty.status == BountyStatus::Cancelled
-            @ SeekError::BountyNotPending
-    )]
-    pub bounty: Box<Account<'info, Bounty>>,
 }
@@ -7,6 +7,11 @@
  *   - Commit-reveal hashing (break = reveal_mission fails on-chain)
  *   - IDL integrity (break = Anchor clients can't serialize ix)
  *
+ * Program logic that runs without a validator (settlement, account
+ * validation, the bounty state machine) is tested against the real program
+ * code by the Rust unit tests at the bottom of
+ * programs/seek-protocol/src/lib.rs (`cargo test`).
+ *
  * Full on-chain integration tests (accept → reveal → propose → finalize,
  * dispute flow, two-step auth transfer, hot-authority rotation) require a
  * local validator + a pre-minted SKR test mint. The SKR_MINT const in the
@@ -78,6 +83,40 @@ describe("seek-protocol (client-side invariants)", () => {
       assert.ok(pda);
     });
 
+    it("a non-canonical vault bump does not derive the stored vault address", () => {
+      // Vault constraints pin bump = global_state.house_vault_bump, so any
+      // other bump must resolve to a different (or invalid) address.
+      const [vault, bump] = PublicKey.findProgramAddressSync(
+        [Buffer.from("house_vault")],
+        PROGRAM_ID
+      );
+      for (let wrong = bump - 1; wrong >= Math.max(0, bump - 8); wrong--) {
+        let derived: PublicKey | null = null;
+        try {
+          derived = PublicKey.createProgramAddressSync(
+            [Buffer.from("house_vault"), Buffer.from([wrong])],
+            PROGRAM_ID
+          );
+        } catch {
+          continue; // on-curve: not a valid PDA at all
+        }
+        assert.notEqual(derived.toBase58(), vault.toBase58());
+      }
+    });
+
+    it("derives mission_usage PDA from the 32-byte mission_id", () => {
+      const missionId = createHash("sha256").update("t1-fire-hydrant").digest();
+      const [pdaA] = PublicKey.findProgramAddressSync(
+        [Buffer.from("mission_usage"), missionId],
+        PROGRAM_ID
+      );
+      const [pdaB] = PublicKey.findProgramAddressSync(
+        [Buffer.from("mission_usage"), createHash("sha256").update("t1-mailbox").digest()],
+        PROGRAM_ID
+      );
+      assert.notEqual(pdaA.toBase58(), pdaB.toBase58(), "one counter per mission");
+    });
+
     it("derives bounty PDA deterministically from (player, timestamp)", () => {
       const player = new PublicKey("11111111111111111111111111111111");
       const timestamp = 1_700_000_000n;
@@ -119,10 +158,10 @@ describe("seek-protocol (client-side invariants)", () => {
   });
 
   describe("Entry-amount decimals math", () => {
-    // The contract's TIER_*_ENTRY constants are `N × DECIMALS_MULTIPLIER`
-    // with DECIMALS_MULTIPLIER = 10^6 on mainnet (SKR is 6 decimals) and
-    // 10^9 on devnet (test mint is 9 decimals). A regression here = every
-    // tier entry misbehaves by 1000× on mainnet.
+    // The contract's tier entries are `TIER_*_ENTRY_SKR × 10^skr_decimals`,
+    // with skr_decimals read from the mint at init: 6 on mainnet, 9 on the
+    // devnet test mint. A regression here = every tier entry misbehaves by
+    // 1000× on mainnet.
 
     it("mainnet tier entries match 6-decimal base units", () => {
       const mult = 1_000_000n; // 10^6
@@ -153,6 +192,40 @@ describe("seek-protocol (client-side invariants)", () => {
     });
   });
 
+  describe("Accept receipt", () => {
+    // accept_bounty returns AcceptReceipt as return data (borsh):
+    // bounty (32) | expires_at (i64 LE) | tier (u8) | payout_amount (u64 LE).
+    const decodeAcceptReceipt = (data: Buffer) => ({
+      bounty: new PublicKey(data.subarray(0, 32)),
+      expiresAt: data.readBigInt64LE(32),
+      tier: data.readUInt8(40),
+      payoutAmount: data.readBigUInt64LE(41),
+    });
+
+    it("decodes the return data of an accept", () => {
+      const [bounty] = PublicKey.findProgramAddressSync(
+        [Buffer.from("bounty"), PublicKey.default.toBuffer(), Buffer.alloc(8)],
+        PROGRAM_ID
+      );
+      const data = Buffer.alloc(49);
+      bounty.toBuffer().copy(data, 0);
+      data.writeBigInt64LE(1_700_000_600n, 32);
+      data.writeUInt8(2, 40);
+      data.writeBigUInt64LE(4_000_000_000n, 41);
+
+      // The transaction log carries it as "Program return: <id> <base64>"
+      const log = `Program return: ${PROGRAM_ID.toBase58()} ${data.toString("base64")}`;
+      const [, programId, encoded] = log.match(/^Program return: (\S+) (\S+)$/)!;
+      assert.equal(programId, PROGRAM_ID.toBase58());
+
+      const receipt = decodeAcceptReceipt(Buffer.from(encoded, "base64"));
+      assert.isTrue(receipt.bounty.equals(bounty));
+      assert.equal(receipt.expiresAt, 1_700_000_600n);
+      assert.equal(receipt.tier, 2);
+      assert.equal(receipt.payoutAmount, 4_000_000_000n);
+    });
+  });
+
   describe("Commit-reveal hash function", () => {
     // Must match the contract's `solana_program::hash::hash` invocation on
     // concat(mission_id, salt). If the hash function changes or the byte
@@ -202,6 +275,12 @@ describe("seek-protocol (client-side invariants)", () => {
       assert.notEqual(a.commitment.toString("hex"), b.commitment.toString("hex"));
     });
 
+    it("random salts are never all zeros (reveal_mission rejects zero salts)", () => {
+      const salt = Buffer.alloc(32);
+      randomFillSync(salt);
+      assert.isFalse(salt.equals(Buffer.alloc(32)));
+    });
+
     it("commitment is exactly 32 bytes (SHA-256 digest)", () => {
       const salt = Buffer.alloc(32);
       randomFillSync(salt);
@@ -248,6 +327,29 @@ describe("seek-protocol (client-side invariants)", () => {
       assert.include(fieldNames, "pending_authority");
     });
 
+    it("MissionRevealed carries player and tier for standalone indexing", () => {
+      const types = (idl as any).types as Array<{ name: string; type: any }>;
+      const event = types.find((t) => t.name === "MissionRevealed");
+      assert.ok(event, "MissionRevealed type must be present in IDL");
+      const fields = event.type.fields as Array<{ name: string; type: any }>;
+      assert.deepInclude(fields, { name: "player", type: "pubkey" });
+      assert.deepInclude(fields, { name: "tier", type: "u8" });
+    });
+
+    it("matches the program's instructions and events (regenerate, don't hand-edit)", () => {
+      const source = readFileSync(
+        resolve(contractsRoot, "programs", "seek-protocol", "src", "lib.rs"),
+        "utf8"
+      );
+      const start = source.indexOf("#[program]");
+      const programModule = source.slice(start, source.indexOf("\n}\n", start));
+      const handlers = [...programModule.matchAll(/^    pub fn (\w+)/gm)].map((m) => m[1]);
+      const events = [...source.matchAll(/#\[event\]\npub struct (\w+)/g)].map((m) => m[1]);
+
+      assert.sameMembers(instructions.map((i) => i.name), handlers);
+      assert.sameMembers(((idl as any).events as Array<{ name: string }>).map((e) => e.name), events);
+    });
+
     it("reveal_mission + propose_resolution use hot_authority signer", () => {
       const reveal = instructions.find((i) => i.name === "reveal_mission");
       const propose = instructions.find((i) => i.name === "propose_resolution");
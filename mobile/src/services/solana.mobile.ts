@@ -10,6 +10,7 @@ import {
   Transaction,
   TransactionInstruction,
   SystemProgram,
+  SYSVAR_SLOT_HASHES_PUBKEY,
 } from '@solana/web3.js';
 import { Buffer } from 'buffer';
 import { PROGRAM_ID, TOKEN } from '../config';
@@ -49,6 +50,26 @@ export function deriveHouseVaultPda(): [PublicKey, number] {
   );
 }
 
+/**
+ * Derive a player's PlayerStats PDA (created by their first accept_bounty)
+ */
+export function derivePlayerStatsPda(player: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('player_stats'), player.toBuffer()],
+    SEEK_PROGRAM_ID
+  );
+}
+
+/**
+ * Derive a player's LoyaltyEscrow PDA (created by their first accept_bounty)
+ */
+export function deriveLoyaltyEscrowPda(player: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('loyalty_escrow'), player.toBuffer()],
+    SEEK_PROGRAM_ID
+  );
+}
+
 /**
  * Derive Associated Token Address (SPL Token)
  */
@@ -65,14 +86,16 @@ export function getAssociatedTokenAddress(
 
 /**
  * Serialize accept_bounty instruction data
- * Layout: [8 bytes discriminator] [8 bytes u64 entry_amount] [8 bytes i64 timestamp] [32 bytes commitment]
+ * Layout: [8 bytes discriminator] [8 bytes u64 entry_amount] [8 bytes i64 timestamp]
+ *         [32 bytes commitment] [32 bytes geo_commitment]
  */
 function serializeAcceptBountyData(
   entryAmount: bigint,
   timestamp: bigint,
-  commitment: number[]
+  commitment: number[],
+  geoCommitment: number[]
 ): Buffer {
-  const data = Buffer.alloc(8 + 8 + 8 + 32); // 56 bytes total
+  const data = Buffer.alloc(8 + 8 + 8 + 32 + 32); // 88 bytes total
 
   // Discriminator (8 bytes)
   ACCEPT_BOUNTY_DISCRIMINATOR.copy(data, 0);
@@ -86,6 +109,9 @@ function serializeAcceptBountyData(
   // mission_commitment (32 bytes)
   Buffer.from(commitment).copy(data, 24);
 
+  // geo_commitment (32 bytes, all zero = no geofence)
+  Buffer.from(geoCommitment).copy(data, 56);
+
   return data;
 }
 
@@ -98,6 +124,7 @@ function serializeAcceptBountyData(
  * @param timestamp - Solana timestamp from /prepare endpoint
  * @param commitment - 32-byte mission commitment from /prepare endpoint
  * @param bountyPda - Pre-computed bounty PDA from /prepare endpoint
+ * @param geoCommitment - Optional 32-byte geofence commitment (zeros = none)
  * @returns Serialized transaction ready for MWA signAndSendTransaction
  */
 export async function buildAcceptBountyTransaction(
@@ -106,11 +133,14 @@ export async function buildAcceptBountyTransaction(
   entryAmount: bigint,
   timestamp: bigint,
   commitment: number[],
-  bountyPda: PublicKey
+  bountyPda: PublicKey,
+  geoCommitment: number[] = new Array(32).fill(0)
 ): Promise<Transaction> {
   // Derive PDAs
   const [globalStatePda] = deriveGlobalStatePda();
   const [houseVaultPda] = deriveHouseVaultPda();
+  const [playerStatsPda] = derivePlayerStatsPda(playerPubkey);
+  const [loyaltyEscrowPda] = deriveLoyaltyEscrowPda(playerPubkey);
 
   // Get player's associated token account for SKR
   const playerTokenAccount = getAssociatedTokenAddress(SKR_MINT, playerPubkey);
@@ -119,7 +149,8 @@ export async function buildAcceptBountyTransaction(
   const instructionData = serializeAcceptBountyData(
     entryAmount,
     timestamp,
-    commitment
+    commitment,
+    geoCommitment
   );
 
   // Build instruction with accounts in exact IDL order
@@ -127,11 +158,15 @@ export async function buildAcceptBountyTransaction(
     programId: SEEK_PROGRAM_ID,
     keys: [
       { pubkey: playerPubkey, isSigner: true, isWritable: true },           // player
+      { pubkey: playerPubkey, isSigner: true, isWritable: true },           // rent_payer (player pays its own rent)
       { pubkey: globalStatePda, isSigner: false, isWritable: true },        // global_state
       { pubkey: bountyPda, isSigner: false, isWritable: true },             // bounty
+      { pubkey: playerStatsPda, isSigner: false, isWritable: true },        // player_stats
+      { pubkey: loyaltyEscrowPda, isSigner: false, isWritable: true },      // loyalty_escrow
       { pubkey: playerTokenAccount, isSigner: false, isWritable: true },    // player_token_account
       { pubkey: houseVaultPda, isSigner: false, isWritable: true },         // house_vault
       { pubkey: SKR_MINT, isSigner: false, isWritable: false },             // skr_mint
+      { pubkey: SYSVAR_SLOT_HASHES_PUBKEY, isSigner: false, isWritable: false }, // slot_hashes
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false }, // system_program
       { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },     // token_program
     ],